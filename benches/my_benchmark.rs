@@ -1,4 +1,5 @@
-use geometrid::tile_set::{TileSet128, TileSet64};
+use geometrid::prelude::*;
+use geometrid::tile_set::{TileSet128, TileSet16, TileSet32, TileSet64};
 use iai_callgrind::{library_benchmark, library_benchmark_group, main};
 use std::hint::black_box;
 
@@ -48,9 +49,112 @@ fn bench_tile_set_64_nth() -> u64 {
         .sum()
 }
 
+#[library_benchmark]
+fn bench_tile_set_128_nth() -> u64 {
+    let set = black_box(TileSet128::<11, 11, 121>::ALL);
+
+    (0..121)
+        .filter_map(|n| set.iter_true_tiles().nth(n))
+        .map(|x| x.inner() as u64)
+        .sum()
+}
+
+#[library_benchmark]
+fn bench_tile_set_64_shifts() -> u32 {
+    let mut set = black_box(TileSet64::<8, 8, 64>::from_fn(|t| t.inner() % 3 == 0));
+    let mut count = 0;
+    for _ in 0..16 {
+        set = set.shift_north(1).shift_east().shift_south(1).shift_west();
+        count += set.count();
+    }
+    count
+}
+
+macro_rules! bench_set_operations {
+    ($name:ident, $set:ty) => {
+        #[library_benchmark]
+        fn $name() -> u32 {
+            let a = black_box(<$set>::from_fn(|t| t.inner() % 2 == 0));
+            let b = black_box(<$set>::from_fn(|t| t.inner() % 3 == 0));
+            let mut count = 0;
+            for _ in 0..16 {
+                count += a.union(&b).count();
+                count += a.intersect(&b).count();
+            }
+            count
+        }
+    };
+}
+
+bench_set_operations!(bench_tile_set_16_set_operations, TileSet16<4, 4, 16>);
+bench_set_operations!(bench_tile_set_32_set_operations, TileSet32<8, 4, 32>);
+bench_set_operations!(bench_tile_set_64_set_operations, TileSet64<8, 8, 64>);
+bench_set_operations!(bench_tile_set_128_set_operations, TileSet128<11, 11, 121>);
+
+#[library_benchmark]
+fn bench_tile_map_rotate() -> u32 {
+    let mut map = black_box(TileMap::<u32, 15, 15, 225>::from_fn(|t| t.inner() as u32));
+    for quarter_turns in [QuarterTurns::One, QuarterTurns::Two, QuarterTurns::Three] {
+        map.rotate(quarter_turns);
+    }
+    map[Tile::NORTH_WEST]
+}
+
+#[library_benchmark]
+fn bench_line_of_sight() -> usize {
+    let from = black_box(Tile::<15, 15>::NORTH_WEST);
+    Tile::<15, 15>::iter_by_row()
+        .map(|to| iter_line_of_sight_tiles(&from, &to).count())
+        .sum()
+}
+
+#[library_benchmark]
+fn bench_polyomino_outline() -> usize {
+    black_box(Polyomino::ALL_PENTOMINOS)
+        .iter()
+        .map(|shape| shape.draw_outline().count())
+        .sum()
+}
+
+fn neighbour_sum(map: &TileMap<u32, 15, 15, 225>, tile: Tile<15, 15>) -> u32 {
+    tile.iter_adjacent().map(|t| map[t]).sum::<u32>() + map[tile]
+}
+
+#[library_benchmark]
+fn bench_neighbours_row_order() -> u32 {
+    let map = black_box(TileMap::<u32, 15, 15, 225>::from_fn(|t| t.inner() as u32));
+
+    Tile::<15, 15>::iter_by_row()
+        .map(|tile| neighbour_sum(&map, tile))
+        .sum()
+}
+
+#[library_benchmark]
+fn bench_neighbours_morton_order() -> u32 {
+    let map = black_box(TileMap::<u32, 15, 15, 225>::from_fn(|t| t.inner() as u32));
+
+    MortonTile::<15, 15>::iter_by_morton()
+        .map(|tile| neighbour_sum(&map, tile.to_tile()))
+        .sum()
+}
+
+library_benchmark_group!(
+    name = bench_tile_map;
+    benchmarks = bench_tile_map_rotate, bench_neighbours_row_order, bench_neighbours_morton_order
+);
+
+library_benchmark_group!(
+    name = bench_shapes;
+    benchmarks = bench_line_of_sight, bench_polyomino_outline
+);
+
 library_benchmark_group!(
     name = bench_tile_set;
-    benchmarks = bench_tile_set_64_iter, bench_tile_set_128_iter, bench_tile_set_64_iter_back, bench_tile_set_128_iter_back, bench_tile_set_64_nth
+    benchmarks = bench_tile_set_64_iter, bench_tile_set_128_iter, bench_tile_set_64_iter_back, bench_tile_set_128_iter_back, bench_tile_set_64_nth, bench_tile_set_128_nth, bench_tile_set_64_shifts, bench_tile_set_16_set_operations, bench_tile_set_32_set_operations, bench_tile_set_64_set_operations, bench_tile_set_128_set_operations
 );
 
-main!(library_benchmark_groups = bench_tile_set);
+main!(
+    library_benchmark_groups = bench_tile_set,
+    bench_tile_map,
+    bench_shapes
+);
@@ -0,0 +1,207 @@
+use crate::prelude::*;
+
+/// A boxed iterator, used by `AnyShape` so that every kind of shape has the same iterator types
+pub type BoxedIter<'a, T> = Box<dyn Iterator<Item = T> + 'a>;
+
+/// A shape of any kind.
+/// `Shape` has associated iterator types, so different shapes cannot be stored together as trait objects.
+/// Wrap them in `AnyShape` instead, which implements `Shape` by reference using boxed iterators.
+/// Requires `std`
+#[must_use]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum AnyShape {
+    Rectangle(Rectangle),
+    /// A polyomino with any number of tiles, normalized so that the minimum x and y are zero
+    Polyomino(DynamicPolyomino),
+    /// Tiles at their actual positions, for example the true tiles of a tile set.
+    /// The tiles must be distinct and orthogonally connected to draw an outline.
+    Tiles(Vec<DynamicTile>),
+}
+
+impl AnyShape {
+    /// A shape made of the true tiles of a tile set, at their positions in the grid.
+    /// Checks that the tiles are connected, so that the outline of the shape is drawn correctly.
+    /// # Errors
+    /// If the set is empty or its tiles are not orthogonally connected
+    pub fn from_tile_set<const W: u8, const H: u8, S: TileSetTrait<W, H>>(
+        set: &S,
+    ) -> Result<Self, ShapeError> {
+        let tiles: Vec<DynamicTile> = set.iter_true_tiles().map(DynamicTile::from).collect();
+        crate::polyomino::validate_shape_tiles(
+            &mut tiles.clone(),
+            &mut vec![false; tiles.len()],
+            &mut vec![0; tiles.len()],
+        )?;
+        Ok(Self::Tiles(tiles))
+    }
+
+    /// Iterate through the tiles of this shape
+    #[must_use]
+    pub fn iter(&self) -> BoxedIter<'_, DynamicTile> {
+        match self {
+            AnyShape::Rectangle(rectangle) => Box::new(rectangle.into_iter()),
+            AnyShape::Polyomino(polyomino) => Box::new(polyomino.iter()),
+            AnyShape::Tiles(tiles) => Box::new(tiles.iter().copied()),
+        }
+    }
+
+    /// Draw the outline of this shape, in the same winding order as the underlying shape.
+    /// May panic if the shape is made of tiles which are not connected.
+    #[must_use]
+    pub fn draw_outline(&self) -> BoxedIter<'_, DynamicVertex> {
+        match self {
+            AnyShape::Rectangle(rectangle) => Box::new(rectangle.draw_outline()),
+            AnyShape::Polyomino(polyomino) => Box::new(polyomino.draw_outline()),
+            AnyShape::Tiles(tiles) => Box::new(TileSlice(tiles).draw_outline()),
+        }
+    }
+
+    /// Deconstruct this shape into rectangles
+    #[must_use]
+    pub fn deconstruct_into_rectangles(&self) -> BoxedIter<'_, Rectangle> {
+        match self {
+            AnyShape::Rectangle(rectangle) => Box::new(rectangle.deconstruct_into_rectangles()),
+            AnyShape::Polyomino(polyomino) => Box::new(polyomino.deconstruct_into_rectangles()),
+            AnyShape::Tiles(tiles) => Box::new(TileSlice(tiles).deconstruct_into_rectangles()),
+        }
+    }
+
+    /// Whether this point is within one of the tiles of the shape, when each tile has sides of length `scale`
+    #[cfg(any(test, feature = "glam"))]
+    #[must_use]
+    pub fn contains_point(&self, point: glam::f32::Vec2, scale: f32) -> bool {
+        match self {
+            AnyShape::Rectangle(rectangle) => rectangle.contains_point(point, scale),
+            AnyShape::Polyomino(polyomino) => (&polyomino).contains_point(point, scale),
+            AnyShape::Tiles(tiles) => TileSlice(tiles).contains_point(point, scale),
+        }
+    }
+}
+
+impl From<Rectangle> for AnyShape {
+    fn from(value: Rectangle) -> Self {
+        Self::Rectangle(value)
+    }
+}
+
+impl From<DynamicPolyomino> for AnyShape {
+    fn from(value: DynamicPolyomino) -> Self {
+        Self::Polyomino(value)
+    }
+}
+
+impl<const P: usize> From<Polyomino<P>> for AnyShape {
+    fn from(value: Polyomino<P>) -> Self {
+        Self::Polyomino(value.into())
+    }
+}
+
+impl<'a> IntoIterator for &'a AnyShape {
+    type Item = DynamicTile;
+    type IntoIter = BoxedIter<'a, DynamicTile>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> Shape for &'a AnyShape {
+    type OutlineIter = BoxedIter<'a, DynamicVertex>;
+
+    type RectangleIter = BoxedIter<'a, Rectangle>;
+
+    fn draw_outline(&self) -> Self::OutlineIter {
+        AnyShape::draw_outline(self)
+    }
+
+    fn deconstruct_into_rectangles(&self) -> Self::RectangleIter {
+        AnyShape::deconstruct_into_rectangles(self)
+    }
+
+    #[cfg(any(test, feature = "glam"))]
+    fn contains_point(&self, point: glam::f32::Vec2, scale: f32) -> bool {
+        AnyShape::contains_point(self, point, scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_heterogeneous_shapes() {
+        fn total_area<S: Shape>(shape: &S) -> usize {
+            shape.deconstruct_into_rectangles().map(|r| r.area()).sum()
+        }
+
+        let rectangle = Rectangle::new(Vector::NORTH_EAST.into(), 2, 3);
+        let mut set = TileSet16::<4, 4, 16>::EMPTY;
+        set.set_bit(&Tile::new_const::<1, 1>(), true);
+        set.set_bit(&Tile::new_const::<2, 1>(), true);
+        set.set_bit(&Tile::new_const::<2, 2>(), true);
+
+        let shapes = [
+            AnyShape::from(rectangle),
+            AnyShape::from(Polyomino::T_TETROMINO),
+            AnyShape::from_tile_set(&set).unwrap(),
+        ];
+
+        assert!(shapes[0].draw_outline().eq(rectangle.draw_outline()));
+        assert!(shapes[1]
+            .draw_outline()
+            .eq(Polyomino::T_TETROMINO.draw_outline()));
+        assert_eq!(
+            shapes[2].draw_outline().join(";"),
+            "(1,1);(3,1);(3,3);(2,3);(2,2);(1,2)"
+        );
+
+        assert_eq!(
+            shapes
+                .iter()
+                .map(|s| s
+                    .deconstruct_into_rectangles()
+                    .map(|r| r.area())
+                    .sum::<usize>())
+                .collect_vec(),
+            [6, 4, 3]
+        );
+        assert_eq!(shapes[2].iter().join(";"), "(1,1);(2,1);(2,2)");
+        assert!(shapes[1]
+            .iter()
+            .eq(Polyomino::T_TETROMINO.tiles().iter().copied()));
+
+        let point = glam::f32::Vec2::new(2.5, 1.5);
+        assert_eq!(
+            shapes
+                .iter()
+                .map(|s| s.contains_point(point, 1.0))
+                .collect_vec(),
+            [true, false, true]
+        );
+
+        assert_eq!(
+            shapes.iter().map(|s| total_area(&s)).collect_vec(),
+            [6, 4, 3]
+        );
+    }
+
+    #[test]
+    fn test_from_tile_set() {
+        let mut set = TileSet16::<4, 4, 16>::EMPTY;
+        assert_eq!(AnyShape::from_tile_set(&set), Err(ShapeError::Empty));
+
+        set.set_bit(&Tile::new_const::<0, 0>(), true);
+        set.set_bit(&Tile::new_const::<2, 0>(), true);
+        assert_eq!(AnyShape::from_tile_set(&set), Err(ShapeError::Disconnected));
+
+        set.set_bit(&Tile::new_const::<1, 0>(), true);
+        assert_eq!(
+            AnyShape::from_tile_set(&set)
+                .unwrap()
+                .draw_outline()
+                .join(";"),
+            "(0,0);(3,0);(3,1);(0,1)"
+        );
+    }
+}
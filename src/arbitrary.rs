@@ -0,0 +1,154 @@
+//! Strategies for generating grid types in property tests.
+//! Requires `proptest`
+
+use proptest::prelude::*;
+use proptest::strategy::BoxedStrategy;
+
+use crate::prelude::*;
+
+impl<const WIDTH: u8, const HEIGHT: u8> Arbitrary for Tile<WIDTH, HEIGHT> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        (0..Self::COUNT)
+            .prop_map(|index| Self::try_from_usize(index).expect("Index should be in range"))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Vector {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<(i8, i8)>().prop_map(|(x, y)| Self::new(x, y)).boxed()
+    }
+}
+
+impl<T: Arbitrary + 'static, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Arbitrary
+    for TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(parameters: Self::Parameters) -> Self::Strategy {
+        tile_map(any_with::<T>(parameters))
+    }
+}
+
+/// Tile maps whose values are drawn from `values`
+pub fn tile_map<S: Strategy + 'static, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>(
+    values: S,
+) -> BoxedStrategy<TileMap<S::Value, WIDTH, HEIGHT, SIZE>> {
+    proptest::array::uniform::<S, SIZE>(values)
+        .prop_map(TileMap::from_inner)
+        .boxed()
+}
+
+macro_rules! arbitrary_tile_set {
+    ($name:ident, $dimension:ty) => {
+        impl<const WIDTH: $dimension, const HEIGHT: $dimension, const SIZE: usize> Arbitrary
+            for $name<WIDTH, HEIGHT, SIZE>
+        {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                any::<[bool; SIZE]>()
+                    .prop_map(|bits| Self::from_fn(|tile| bits[usize::from(tile.inner())]))
+                    .boxed()
+            }
+        }
+    };
+}
+
+arbitrary_tile_set!(TileSet8, u8);
+arbitrary_tile_set!(TileSet16, u8);
+arbitrary_tile_set!(TileSet32, u8);
+arbitrary_tile_set!(TileSet64, u8);
+arbitrary_tile_set!(TileSet128, u8);
+#[cfg(any(test, feature = "u256"))]
+arbitrary_tile_set!(TileSet256, u8);
+arbitrary_tile_set!(TileSet512, u16);
+arbitrary_tile_set!(TileSet1024, u16);
+
+impl<const P: usize> Arbitrary for Polyomino<P> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Connected polyominos, grown one tile at a time from a single square.
+    /// `P` must be less than 128 so that the tiles fit in a `Vector`.
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<[usize; P]>().prop_map(grow_polyomino).boxed()
+    }
+}
+
+/// Each choice picks one of the empty squares next to the tiles so far
+fn grow_polyomino<const P: usize>(choices: [usize; P]) -> Polyomino<P> {
+    let mut vectors = [Vector::ZERO; P];
+    for index in 1..P {
+        let mut candidates: Vec<Vector> = vectors[..index]
+            .iter()
+            .flat_map(|vector| Vector::CARDINALS.map(|direction| vector.const_add(direction)))
+            .filter(|candidate| !vectors[..index].contains(candidate))
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+        vectors[index] = candidates[choices[index] % candidates.len()];
+    }
+    Polyomino::new(vectors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use strum::IntoEnumIterator;
+
+    proptest! {
+        #[test]
+        fn tiles_are_in_range(tile in any::<Tile<3, 5>>()) {
+            prop_assert!(tile.x() < 3 && tile.y() < 5);
+        }
+
+        #[test]
+        fn tile_sets_are_in_range(set in any::<TileSet64<7, 7, 49>>(), wide in any::<TileSet1024<20, 20, 400>>()) {
+            prop_assert!(set.is_subset(&TileSet64::ALL));
+            prop_assert!(wide.is_subset(&TileSet1024::ALL));
+        }
+
+        #[test]
+        fn rotating_a_tile_map_four_times_is_identity(map in any::<TileMap<u64, 5, 5, 25>>()) {
+            for quarter_turns in QuarterTurns::iter() {
+                let rotated = (0..4).fold(map, |m, _| m.with_rotate(quarter_turns));
+                prop_assert_eq!(rotated, map);
+            }
+            for axes in FlipAxes::iter() {
+                prop_assert_eq!(map.with_flip(axes).with_flip(axes), map);
+            }
+        }
+
+        #[test]
+        #[allow(clippy::iter_skip_next)]
+        fn nth_is_consistent_with_skip(set in any::<TileSet64<8, 8, 64>>(), wide in any::<TileSet128<11, 11, 121>>()) {
+            for n in 0..=set.count() as usize {
+                prop_assert_eq!(set.iter_true_tiles().nth(n), set.iter_true_tiles().skip(n).next());
+                prop_assert_eq!(set.iter_true_tiles().nth_back(n), set.iter_true_tiles().rev().skip(n).next());
+            }
+            for n in 0..=wide.count() as usize {
+                prop_assert_eq!(wide.iter_true_tiles().nth(n), wide.iter_true_tiles().skip(n).next());
+                prop_assert_eq!(wide.iter_true_tiles().nth_back(n), wide.iter_true_tiles().rev().skip(n).next());
+            }
+        }
+
+        #[test]
+        fn polyominos_are_connected(shape in any::<Polyomino<9>>()) {
+            prop_assert!(shape.try_draw_outline().is_ok(), "{}", shape);
+            let outline = shape.draw_outline().collect_vec();
+            for (a, b) in outline.iter().circular_tuple_windows() {
+                prop_assert!(a.0.x == b.0.x || a.0.y == b.0.y, "{}: {} -> {}", shape, a, b);
+            }
+        }
+    }
+}
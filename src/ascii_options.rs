@@ -0,0 +1,183 @@
+use crate::prelude::*;
+
+/// Options for parsing shapes from ascii.
+/// By default tiles are `#` and empty spaces are `.`, matching `Polyomino::try_new_from_ascii`.
+/// Newlines start a new row. Other whitespace, including trailing whitespace, is ignored unless it is one of the glyphs.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AsciiOptions {
+    tile: u8,
+    space: u8,
+    any_non_space_is_tile: bool,
+    pub(crate) flip: FlipAxes,
+}
+
+impl Default for AsciiOptions {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// What a character in an ascii shape represents
+pub(crate) enum AsciiGlyph {
+    NewLine,
+    Space,
+    Tile,
+    Ignored,
+}
+
+impl AsciiOptions {
+    pub const DEFAULT: Self = Self {
+        tile: b'#',
+        space: b'.',
+        any_non_space_is_tile: false,
+        flip: FlipAxes::None,
+    };
+
+    /// Tiles are `*` and empty spaces are `_`, matching how tile sets are displayed
+    pub const TILE_SET: Self = Self::DEFAULT.with_glyphs(b'*', b'_');
+
+    /// Use `tile` for tiles and `space` for empty spaces
+    pub const fn with_glyphs(mut self, tile: u8, space: u8) -> Self {
+        self.tile = tile;
+        self.space = space;
+        self
+    }
+
+    /// Treat every character which is not the space glyph or whitespace as a tile
+    pub const fn with_any_non_space_is_tile(mut self, any_non_space_is_tile: bool) -> Self {
+        self.any_non_space_is_tile = any_non_space_is_tile;
+        self
+    }
+
+    /// Flip the shape after parsing it, for formats which are mirrored relative to this one
+    pub const fn with_flip(mut self, flip: FlipAxes) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    pub(crate) const fn classify(self, byte: u8) -> Option<AsciiGlyph> {
+        if byte == b'\n' {
+            Some(AsciiGlyph::NewLine)
+        } else if byte == self.space {
+            Some(AsciiGlyph::Space)
+        } else if byte == self.tile {
+            Some(AsciiGlyph::Tile)
+        } else if byte.is_ascii_whitespace() {
+            Some(AsciiGlyph::Ignored)
+        } else if self.any_non_space_is_tile {
+            Some(AsciiGlyph::Tile)
+        } else {
+            None
+        }
+    }
+
+    /// Count the tiles in an ascii shape.
+    /// Use this to find the size of polyomino a string represents before parsing it.
+    ///
+    /// # Errors
+    /// If there are unexpected characters.
+    pub const fn count_tiles(self, s: &str) -> Result<usize, ParseShapeError> {
+        let bytes = s.as_bytes();
+        let mut count = 0;
+        let mut index = 0;
+        while index < bytes.len() {
+            match self.classify(bytes[index]) {
+                Some(AsciiGlyph::Tile) => count += 1,
+                Some(_) => {}
+                None => {
+                    return Err(ParseShapeError::UnexpectedCharacter {
+                        byte: bytes[index],
+                        index,
+                    })
+                }
+            }
+            index += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// Reads the tiles of an ascii grid one at a time, keeping their positions.
+/// Used to parse tile sets in const contexts.
+pub(crate) struct AsciiGridReader<'a> {
+    bytes: &'a [u8],
+    options: AsciiOptions,
+    index: usize,
+    x: usize,
+    y: usize,
+}
+
+impl<'a> AsciiGridReader<'a> {
+    pub(crate) const fn new(s: &'a str, options: AsciiOptions) -> Self {
+        Self {
+            bytes: s.as_bytes(),
+            options,
+            index: 0,
+            x: 0,
+            y: 0,
+        }
+    }
+
+    /// The next tile in the grid, or `None` if there are no more.
+    /// Tiles and empty spaces outside the grid are errors.
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn next_tile<const WIDTH: u8, const HEIGHT: u8>(
+        &mut self,
+    ) -> Result<Option<Tile<WIDTH, HEIGHT>>, ParseShapeError> {
+        while self.index < self.bytes.len() {
+            let byte = self.bytes[self.index];
+            let index = self.index;
+            self.index += 1;
+
+            let is_tile = match self.options.classify(byte) {
+                Some(AsciiGlyph::NewLine) => {
+                    self.x = 0;
+                    self.y += 1;
+                    continue;
+                }
+                Some(AsciiGlyph::Ignored) => continue,
+                Some(AsciiGlyph::Space) => false,
+                Some(AsciiGlyph::Tile) => true,
+                None => return Err(ParseShapeError::UnexpectedCharacter { byte, index }),
+            };
+
+            if self.x >= WIDTH as usize || self.y >= HEIGHT as usize {
+                return Err(ParseShapeError::OutOfBounds {
+                    x: self.x,
+                    y: self.y,
+                });
+            }
+            let tile = Tile::<WIDTH, HEIGHT>::new_unchecked(self.x as u8, self.y as u8);
+            self.x += 1;
+            if is_tile {
+                return Ok(Some(tile.flip(self.options.flip)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_tiles() {
+        let options = AsciiOptions::DEFAULT;
+        assert_eq!(options.count_tiles("##.\n.#  \n"), Ok(3));
+        assert_eq!(
+            options.count_tiles("#x"),
+            Err(ParseShapeError::UnexpectedCharacter {
+                byte: b'x',
+                index: 1
+            })
+        );
+        assert_eq!(
+            options
+                .with_any_non_space_is_tile(true)
+                .count_tiles("#x\nAB"),
+            Ok(4)
+        );
+    }
+}
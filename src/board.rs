@@ -0,0 +1,255 @@
+use core::ops::{Index, IndexMut};
+
+use crate::prelude::*;
+
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+/// A `TileMap` of terrain combined with a number of tile set layers of flags.
+/// Layers are identified by index. An enum of layer names which converts to `usize` works well.
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+#[cfg_attr(
+    any(test, feature = "serde"),
+    serde(bound(
+        serialize = "T: Serialize, S: Serialize",
+        deserialize = "T: Deserialize<'de>, S: Deserialize<'de>"
+    ))
+)]
+pub struct Board<
+    T,
+    S: TileSetTrait<WIDTH, HEIGHT>,
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    const SIZE: usize,
+    const LAYERS: usize,
+> {
+    pub terrain: TileMap<T, WIDTH, HEIGHT, SIZE>,
+    #[cfg_attr(any(test, feature = "serde"), serde(with = "serde_arrays"))]
+    layers: [S; LAYERS],
+}
+
+impl<
+        T,
+        S: TileSetTrait<WIDTH, HEIGHT>,
+        const WIDTH: u8,
+        const HEIGHT: u8,
+        const SIZE: usize,
+        const LAYERS: usize,
+    > Board<T, S, WIDTH, HEIGHT, SIZE, LAYERS>
+{
+    /// Create a board with the given terrain. All layers will be empty.
+    pub fn new(terrain: TileMap<T, WIDTH, HEIGHT, SIZE>) -> Self {
+        Self {
+            terrain,
+            layers: [S::EMPTY; LAYERS],
+        }
+    }
+
+    /// The tiles flagged in a layer
+    /// # Panics
+    /// If the layer index is out of range
+    pub fn layer(&self, layer: impl Into<usize>) -> &S {
+        &self.layers[layer.into()]
+    }
+
+    /// The tiles flagged in a layer
+    /// # Panics
+    /// If the layer index is out of range
+    pub fn layer_mut(&mut self, layer: impl Into<usize>) -> &mut S {
+        &mut self.layers[layer.into()]
+    }
+
+    /// Whether a tile is flagged in a layer
+    /// # Panics
+    /// If the layer index is out of range
+    #[must_use]
+    pub fn get_flag(&self, layer: impl Into<usize>, tile: Tile<WIDTH, HEIGHT>) -> bool {
+        self.layer(layer).get_bit(&tile)
+    }
+
+    /// Set whether a tile is flagged in a layer
+    /// # Panics
+    /// If the layer index is out of range
+    pub fn set_flag(&mut self, layer: impl Into<usize>, tile: Tile<WIDTH, HEIGHT>, flag: bool) {
+        self.layer_mut(layer).set_bit(&tile, flag);
+    }
+
+    /// The tiles which are flagged in all of the `required` layers and none of the `excluded` layers
+    /// # Panics
+    /// If any layer index is out of range
+    pub fn matching<L: Into<usize>>(
+        &self,
+        required: impl IntoIterator<Item = L>,
+        excluded: impl IntoIterator<Item = L>,
+    ) -> S {
+        let mut result = S::all();
+        for layer in required {
+            result = result.intersect(self.layer(layer));
+        }
+        for layer in excluded {
+            result = result.except(self.layer(layer));
+        }
+        result
+    }
+
+    /// Iterate through the tiles which are flagged in all of the `required` layers and none of the `excluded` layers, along with their terrain
+    /// # Panics
+    /// If any layer index is out of range
+    pub fn iter_matching<L: Into<usize>>(
+        &self,
+        required: impl IntoIterator<Item = L>,
+        excluded: impl IntoIterator<Item = L>,
+    ) -> impl Iterator<Item = (Tile<WIDTH, HEIGHT>, &T)> + '_ {
+        self.matching(required, excluded)
+            .iter_true_tiles()
+            .map(|tile| (tile, &self.terrain[tile]))
+    }
+}
+
+impl<
+        T,
+        S: TileSetTrait<WIDTH, HEIGHT>,
+        const WIDTH: u8,
+        const HEIGHT: u8,
+        const SIZE: usize,
+        const LAYERS: usize,
+    > From<TileMap<T, WIDTH, HEIGHT, SIZE>> for Board<T, S, WIDTH, HEIGHT, SIZE, LAYERS>
+{
+    fn from(terrain: TileMap<T, WIDTH, HEIGHT, SIZE>) -> Self {
+        Self::new(terrain)
+    }
+}
+
+impl<
+        T: Default + Copy,
+        S: TileSetTrait<WIDTH, HEIGHT>,
+        const WIDTH: u8,
+        const HEIGHT: u8,
+        const SIZE: usize,
+        const LAYERS: usize,
+    > Default for Board<T, S, WIDTH, HEIGHT, SIZE, LAYERS>
+{
+    fn default() -> Self {
+        Self::new(TileMap::default())
+    }
+}
+
+impl<
+        T,
+        S: TileSetTrait<WIDTH, HEIGHT>,
+        const WIDTH: u8,
+        const HEIGHT: u8,
+        const SIZE: usize,
+        const LAYERS: usize,
+    > Index<Tile<WIDTH, HEIGHT>> for Board<T, S, WIDTH, HEIGHT, SIZE, LAYERS>
+{
+    type Output = T;
+
+    fn index(&self, index: Tile<WIDTH, HEIGHT>) -> &Self::Output {
+        &self.terrain[index]
+    }
+}
+
+impl<
+        T,
+        S: TileSetTrait<WIDTH, HEIGHT>,
+        const WIDTH: u8,
+        const HEIGHT: u8,
+        const SIZE: usize,
+        const LAYERS: usize,
+    > IndexMut<Tile<WIDTH, HEIGHT>> for Board<T, S, WIDTH, HEIGHT, SIZE, LAYERS>
+{
+    fn index_mut(&mut self, index: Tile<WIDTH, HEIGHT>) -> &mut Self::Output {
+        &mut self.terrain[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[derive(Debug, Clone, Copy)]
+    enum Layer {
+        Visible,
+        Occupied,
+    }
+
+    impl From<Layer> for usize {
+        fn from(value: Layer) -> Self {
+            value as usize
+        }
+    }
+
+    type TestBoard = Board<char, TileSet16<3, 3, 9>, 3, 3, 9, 2>;
+
+    #[test]
+    fn test_layers() {
+        let mut board: TestBoard =
+            TileMap::from_fn(|tile| if tile.x() == 0 { '#' } else { '.' }).into();
+
+        for tile in Tile::<3, 3>::iter_by_row().filter(|tile| tile.y() < 2) {
+            board.set_flag(Layer::Visible, tile, true);
+        }
+        board.set_flag(Layer::Occupied, Tile::CENTER, true);
+        board[Tile::CENTER] = '@';
+
+        assert!(board.get_flag(Layer::Visible, Tile::NORTH_WEST));
+        assert!(!board.get_flag(Layer::Occupied, Tile::NORTH_WEST));
+        assert_eq!(board.layer(Layer::Visible).count(), 6);
+
+        assert_eq!(
+            board
+                .iter_matching([Layer::Visible], [Layer::Occupied])
+                .map(|(tile, terrain)| format!("{tile}{terrain}"))
+                .join("|"),
+            "(0,0)#|(1,0).|(2,0).|(0,1)#|(2,1)."
+        );
+        assert_eq!(
+            board.matching([Layer::Visible, Layer::Occupied], []),
+            TileSet16::from_fn(|tile| tile == Tile::CENTER)
+        );
+        assert_eq!(board.matching::<usize>([], []), TileSet16::ALL);
+
+        board
+            .layer_mut(Layer::Visible)
+            .set_bit(&Tile::CENTER, false);
+        assert_eq!(
+            board
+                .matching([Layer::Visible, Layer::Occupied], [])
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        let mut board: Board<u8, TileSet8<2, 1, 2>, 2, 1, 2, 1> = Board::default();
+        board.set_flag(0usize, Tile::NORTH_EAST, true);
+        board[Tile::NORTH_WEST] = 3;
+
+        serde_test::assert_tokens(
+            &board,
+            &[
+                serde_test::Token::Struct {
+                    name: "Board",
+                    len: 2,
+                },
+                serde_test::Token::Str("terrain"),
+                serde_test::Token::NewtypeStruct { name: "TileMap" },
+                serde_test::Token::Tuple { len: 2 },
+                serde_test::Token::U8(3),
+                serde_test::Token::U8(0),
+                serde_test::Token::TupleEnd,
+                serde_test::Token::Str("layers"),
+                serde_test::Token::Tuple { len: 1 },
+                serde_test::Token::NewtypeStruct { name: "TileSet8" },
+                serde_test::Token::U8(2),
+                serde_test::Token::TupleEnd,
+                serde_test::Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use std::collections::BTreeMap;
+
+use crate::prelude::*;
+
+/// A chunk of a `ChunkedWorld`, with a flag recording whether it has been mutably accessed
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Chunk<T, const CW: u8, const CH: u8, const SIZE: usize> {
+    map: TileMap<T, CW, CH, SIZE>,
+    dirty: bool,
+}
+
+/// A world made of `TileMap` chunks, each `CW` by `CH` tiles.
+/// Chunks are addressed by chunk coordinates and created when they are first written to, filled with default values.
+/// Tiles are addressed by their global coordinates, which may be negative.
+/// Any mutable access to a chunk marks it as dirty, whether or not a value actually changes.
+/// Requires `std`
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ChunkedWorld<T, const CW: u8, const CH: u8, const SIZE: usize> {
+    chunks: BTreeMap<DynamicTile, Chunk<T, CW, CH, SIZE>>,
+}
+
+impl<T, const CW: u8, const CH: u8, const SIZE: usize> Default for ChunkedWorld<T, CW, CH, SIZE> {
+    fn default() -> Self {
+        Self {
+            chunks: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T, const CW: u8, const CH: u8, const SIZE: usize> ChunkedWorld<T, CW, CH, SIZE> {
+    /// Create a world with no chunks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The coordinates of the chunk containing `tile`, and the position of `tile` within that chunk
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn split(tile: DynamicTile) -> (DynamicTile, Tile<CW, CH>) {
+        let (x, y) = (i16::from(tile.x), i16::from(tile.y));
+        let (w, h) = (i16::from(CW), i16::from(CH));
+        let chunk = DynamicTile(Vector::new(x.div_euclid(w) as i8, y.div_euclid(h) as i8));
+        let local = Tile::new_unchecked(x.rem_euclid(w) as u8, y.rem_euclid(h) as u8);
+        (chunk, local)
+    }
+
+    /// The global coordinates of the tile at `local` within the chunk at `chunk`.
+    /// Returns `None` if the global coordinates are out of range.
+    #[must_use]
+    pub fn join(chunk: DynamicTile, local: Tile<CW, CH>) -> Option<DynamicTile> {
+        let x = i16::from(chunk.x) * i16::from(CW) + i16::from(local.x());
+        let y = i16::from(chunk.y) * i16::from(CH) + i16::from(local.y());
+        Some(DynamicTile(Vector::new(
+            i8::try_from(x).ok()?,
+            i8::try_from(y).ok()?,
+        )))
+    }
+
+    /// The number of chunks which have been created
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// The chunk at chunk coordinates `chunk`, if it has been created
+    #[must_use]
+    pub fn chunk(&self, chunk: DynamicTile) -> Option<&TileMap<T, CW, CH, SIZE>> {
+        self.chunks.get(&chunk).map(|c| &c.map)
+    }
+
+    /// Iterate through the chunks which have been created, in order of their coordinates
+    pub fn iter_chunks(
+        &self,
+    ) -> impl Iterator<Item = (DynamicTile, &TileMap<T, CW, CH, SIZE>)> + '_ {
+        self.chunks.iter().map(|(coords, c)| (*coords, &c.map))
+    }
+
+    /// Remove the chunk at chunk coordinates `chunk`, returning it if it had been created
+    pub fn remove_chunk(&mut self, chunk: DynamicTile) -> Option<TileMap<T, CW, CH, SIZE>> {
+        self.chunks.remove(&chunk).map(|c| c.map)
+    }
+
+    /// The value at a global tile, if its chunk has been created
+    #[must_use]
+    pub fn get(&self, tile: DynamicTile) -> Option<&T> {
+        let (chunk, local) = Self::split(tile);
+        self.chunk(chunk).map(|map| &map[local])
+    }
+
+    /// The value at a global tile, if its chunk has been created.
+    /// Marks the chunk as dirty.
+    #[must_use]
+    pub fn get_mut(&mut self, tile: DynamicTile) -> Option<&mut T> {
+        let (chunk, local) = Self::split(tile);
+        let chunk = self.chunks.get_mut(&chunk)?;
+        chunk.dirty = true;
+        Some(&mut chunk.map[local])
+    }
+
+    /// Whether the chunk has been mutably accessed since the dirty chunks were last taken
+    #[must_use]
+    pub fn is_dirty(&self, chunk: DynamicTile) -> bool {
+        self.chunks.get(&chunk).is_some_and(|c| c.dirty)
+    }
+
+    /// Iterate through the coordinates of the dirty chunks, without clearing them
+    pub fn iter_dirty(&self) -> impl Iterator<Item = DynamicTile> + '_ {
+        self.chunks
+            .iter()
+            .filter(|(_, c)| c.dirty)
+            .map(|(coords, _)| *coords)
+    }
+
+    /// Get the coordinates of the chunks which have been mutably accessed since this was last called, and mark all chunks as clean
+    pub fn take_dirty<S: FromIterator<DynamicTile>>(&mut self) -> S {
+        let result = self.iter_dirty().collect();
+        for chunk in self.chunks.values_mut() {
+            chunk.dirty = false;
+        }
+        result
+    }
+}
+
+impl<T: Default, const CW: u8, const CH: u8, const SIZE: usize> ChunkedWorld<T, CW, CH, SIZE> {
+    /// The chunk at chunk coordinates `chunk`, creating it if necessary.
+    /// Marks the chunk as dirty.
+    pub fn chunk_mut(&mut self, chunk: DynamicTile) -> &mut TileMap<T, CW, CH, SIZE> {
+        let chunk = self.chunks.entry(chunk).or_insert_with(|| Chunk {
+            map: TileMap::from_fn(|_| T::default()),
+            dirty: false,
+        });
+        chunk.dirty = true;
+        &mut chunk.map
+    }
+
+    /// Set the value at a global tile, creating its chunk if necessary and marking it as dirty.
+    /// Returns the previous value.
+    pub fn set(&mut self, tile: DynamicTile, value: T) -> T {
+        let (chunk, local) = Self::split(tile);
+        core::mem::replace(&mut self.chunk_mut(chunk)[local], value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    type World = ChunkedWorld<u8, 4, 3, 12>;
+
+    fn tile(x: i8, y: i8) -> DynamicTile {
+        DynamicTile(Vector::new(x, y))
+    }
+
+    #[test]
+    fn test_split_and_join() {
+        assert_eq!(
+            World::split(tile(5, 2)),
+            (tile(1, 0), Tile::new_const::<1, 2>())
+        );
+        assert_eq!(
+            World::split(tile(-1, -4)),
+            (tile(-1, -2), Tile::new_const::<3, 2>())
+        );
+        for x in -128..=127 {
+            let t = tile(x, x / 2);
+            let (chunk, local) = World::split(t);
+            assert_eq!(World::join(chunk, local), Some(t));
+        }
+        assert_eq!(World::join(tile(40, 0), Tile::NORTH_WEST), None);
+    }
+
+    #[test]
+    fn test_lazy_chunks() {
+        let mut world = World::new();
+        assert_eq!(world.get(tile(0, 0)), None);
+        assert_eq!(world.get_mut(tile(0, 0)), None);
+
+        assert_eq!(world.set(tile(-3, 7), 5), 0);
+        assert_eq!(world.set(tile(-3, 7), 6), 5);
+        assert_eq!(world.get(tile(-3, 7)), Some(&6));
+        assert_eq!(world.get(tile(-4, 7)), Some(&0));
+        assert_eq!(world.chunk_count(), 1);
+        assert_eq!(world.iter_chunks().map(|x| x.0).join("|"), "(-1,2)");
+
+        assert_eq!(
+            world
+                .remove_chunk(tile(-1, 2))
+                .map(|map| map.iter().sum::<u8>()),
+            Some(6)
+        );
+        assert_eq!(world.chunk_count(), 0);
+    }
+
+    #[test]
+    fn test_dirty() {
+        let mut world = World::new();
+        world.set(tile(0, 0), 1);
+        world.set(tile(9, 0), 1);
+        assert!(world.is_dirty(tile(0, 0)));
+        assert_eq!(world.take_dirty::<Vec<_>>(), vec![tile(0, 0), tile(2, 0)]);
+        assert!(!world.is_dirty(tile(0, 0)));
+
+        assert_eq!(world.get(tile(9, 0)), Some(&1));
+        assert_eq!(world.iter_dirty().count(), 0);
+
+        *world.get_mut(tile(9, 0)).unwrap() += 1;
+        assert_eq!(world.take_dirty::<Vec<_>>(), vec![tile(2, 0)]);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::prelude::*;
+
+/// The unit steps along each heading, clockwise from east
+const HEADINGS: [Vector; 4] = [Vector::EAST, Vector::SOUTH, Vector::WEST, Vector::NORTH];
+
+/// A closed loop of vertices along the edges of tiles, separating a region from the tiles around it.
+/// Tiles which only touch diagonally are in separate regions.
+/// Requires `std`
+#[must_use]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Contour {
+    /// The corners of the contour, without repeating the first.
+    /// Outer boundaries go clockwise as displayed (y increasing southwards), like polyomino outlines.
+    /// Holes go anticlockwise, so the region is always on the right.
+    pub vertices: Vec<DynamicVertex>,
+}
+
+impl Contour {
+    /// The contours of the regions of tiles for which `inside` returns true, found by marching squares.
+    /// Each region has an outer boundary and a contour for each of its holes.
+    /// Contours are ordered by where they are first found in row order, so outer boundaries come before their holes.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn trace<const W: u8, const H: u8>(
+        mut inside: impl FnMut(Tile<W, H>) -> bool,
+    ) -> Vec<Self> {
+        let mask: Vec<bool> = Tile::<W, H>::iter_by_row().map(&mut inside).collect();
+        let index = |v: Vector| match (u8::try_from(v.x), u8::try_from(v.y)) {
+            (Ok(x), Ok(y)) if x < W && y < H => {
+                Some(usize::from(y) * usize::from(W) + usize::from(x))
+            }
+            _ => None,
+        };
+        let is_inside = |v: Vector| index(v).is_some_and(|i| mask[i]);
+        // The tiles to the right and left of the edge leaving `vertex` along `heading`
+        let sides = |vertex: Vector, heading: usize| match heading {
+            0 => (vertex, vertex.const_add(Vector::NORTH)),
+            1 => (vertex.const_add(Vector::WEST), vertex),
+            2 => (
+                vertex.const_add(Vector::NORTH_WEST),
+                vertex.const_add(Vector::WEST),
+            ),
+            _ => (
+                vertex.const_add(Vector::NORTH),
+                vertex.const_add(Vector::NORTH_WEST),
+            ),
+        };
+        let is_edge = |vertex: Vector, heading: usize| {
+            let (right, left) = sides(vertex, heading);
+            is_inside(right) && !is_inside(left)
+        };
+
+        // Each edge is identified by the tile on its right, and the heading along it
+        let mut visited = vec![false; mask.len() * 4];
+        let edge_index = |vertex: Vector, heading: usize| {
+            let (right, _) = sides(vertex, heading);
+            index(right).unwrap() * 4 + heading
+        };
+
+        let mut contours = Vec::new();
+        for tile in Tile::<W, H>::iter_by_row() {
+            let corner = DynamicTile::from(tile).0;
+            // The start of the edge along each side, clockwise from north
+            let starts = [
+                corner,
+                corner.const_add(Vector::EAST),
+                corner.const_add(Vector::SOUTH_EAST),
+                corner.const_add(Vector::SOUTH),
+            ];
+            for (heading, start) in starts.into_iter().enumerate() {
+                if !is_edge(start, heading) || visited[edge_index(start, heading)] {
+                    continue;
+                }
+
+                let mut steps = Vec::new();
+                let (mut vertex, mut heading) = (start, heading);
+                loop {
+                    visited[edge_index(vertex, heading)] = true;
+                    steps.push((vertex, heading));
+                    vertex = vertex.const_add(HEADINGS[heading]);
+                    // Turning right first keeps diagonally touching tiles apart
+                    heading = [(heading + 1) % 4, heading, (heading + 3) % 4]
+                        .into_iter()
+                        .find(|h| is_edge(vertex, *h))
+                        .expect("Every boundary vertex should have an edge leaving it");
+                    if (vertex, heading) == steps[0] {
+                        break;
+                    }
+                }
+
+                let vertices = (0..steps.len())
+                    .filter(|i| steps[(i + steps.len() - 1) % steps.len()].1 != steps[*i].1)
+                    .map(|i| DynamicVertex(steps[i].0))
+                    .collect();
+                contours.push(Self { vertices });
+            }
+        }
+        contours
+    }
+
+    /// The contours of the true tiles of a tile set
+    pub fn from_tile_set<const W: u8, const H: u8, S: TileSetTrait<W, H>>(set: &S) -> Vec<Self> {
+        Self::trace(|tile| set.get_bit(&tile))
+    }
+
+    /// The contours of the tiles whose values are at least `threshold`, such as the regions of a scalar field
+    pub fn from_tile_map<T: PartialOrd, const W: u8, const H: u8, const SIZE: usize>(
+        map: &TileMap<T, W, H, SIZE>,
+        threshold: &T,
+    ) -> Vec<Self> {
+        Self::trace(|tile| map[tile] >= *threshold)
+    }
+
+    /// The number of tiles enclosed by this contour.
+    /// Positive for outer boundaries and negative for holes, so the areas of all the contours sum to the number of tiles.
+    #[must_use]
+    pub fn signed_area(&self) -> i32 {
+        let twice_area: i32 = self
+            .vertices
+            .iter()
+            .zip(self.vertices.iter().cycle().skip(1))
+            .map(|(a, b)| i32::from(a.x) * i32::from(b.y) - i32::from(b.x) * i32::from(a.y))
+            .sum();
+        twice_area / 2
+    }
+
+    /// Whether this contour is the boundary of a hole, going anticlockwise
+    #[must_use]
+    pub fn is_hole(&self) -> bool {
+        self.signed_area() < 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    fn describe(contours: &[Contour]) -> String {
+        contours
+            .iter()
+            .map(|c| format!("{}={}", c.signed_area(), c.vertices.iter().join(";")))
+            .join(" | ")
+    }
+
+    #[test]
+    fn test_single_tile() {
+        let contours = Contour::from_tile_set(&crate::tiles!(8, 8; "", "__*"));
+        assert_eq!(describe(&contours), "1=(2,1);(3,1);(3,2);(2,2)");
+        assert!(!contours[0].is_hole());
+    }
+
+    #[test]
+    fn test_hole() {
+        let contours = Contour::from_tile_set(&crate::tiles!(8, 8; "***", "*_*", "***"));
+        assert_eq!(
+            describe(&contours),
+            "9=(0,0);(3,0);(3,3);(0,3) | -1=(2,1);(1,1);(1,2);(2,2)"
+        );
+        assert!(contours[1].is_hole());
+    }
+
+    #[test]
+    fn test_diagonal_tiles_are_separate() {
+        let contours = Contour::from_tile_set(&crate::tiles!(8, 8; "*_", "_*"));
+        assert_eq!(
+            describe(&contours),
+            "1=(0,0);(1,0);(1,1);(0,1) | 1=(1,1);(2,1);(2,2);(1,2)"
+        );
+
+        let contours = Contour::from_tile_set(&crate::tiles!(8, 8; "_*", "*_"));
+        assert_eq!(contours.len(), 2);
+    }
+
+    #[test]
+    fn test_edges_of_grid() {
+        let contours = Contour::from_tile_set(&crate::tiles!(3, 2; "***", "***"));
+        assert_eq!(describe(&contours), "6=(0,0);(3,0);(3,2);(0,2)");
+        assert!(Contour::from_tile_set(&TileSet8::<3, 2, 6>::EMPTY).is_empty());
+    }
+
+    #[test]
+    fn test_matches_polyomino_outlines() {
+        for polyomino in Polyomino::ALL_PENTOMINOS {
+            let set = TileSet32::<5, 5, 25>::from_fn(|tile| {
+                polyomino.tiles().contains(&DynamicTile::from(tile))
+            });
+            let contours = Contour::from_tile_set(&set);
+            assert_eq!(contours.len(), 1, "{polyomino}");
+
+            let outline = polyomino.draw_outline().collect_vec();
+            let vertices = &contours[0].vertices;
+            let offset = vertices.iter().position(|v| *v == outline[0]).unwrap();
+            assert!(
+                vertices
+                    .iter()
+                    .cycle()
+                    .skip(offset)
+                    .take(vertices.len())
+                    .eq(outline.iter()),
+                "{polyomino}"
+            );
+        }
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn test_areas_sum_to_count(set in proptest::prelude::any::<TileSet64<8, 8, 64>>()) {
+            let contours = Contour::from_tile_set(&set);
+            let total: i32 = contours.iter().map(Contour::signed_area).sum();
+            proptest::prop_assert_eq!(Ok(total), i32::try_from(set.count()), "{}", set);
+            proptest::prop_assert!(contours.iter().all(|c| c.vertices.len() >= 4));
+        }
+    }
+
+    #[test]
+    fn test_threshold() {
+        let map: TileMap<f32, 3, 2, 6> = TileMap::from_rows([
+            [0.1, 0.8, 0.9], //
+            [0.2, 0.5, 0.3],
+        ]);
+        let contours = Contour::from_tile_map(&map, &0.5);
+        assert_eq!(describe(&contours), "3=(1,0);(3,0);(3,1);(2,1);(2,2);(1,2)");
+    }
+}
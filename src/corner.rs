@@ -2,6 +2,8 @@
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumCount, EnumIs, EnumIter};
 
+use crate::prelude::*;
+
 /// The Corner of a tile
 #[derive(
     Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Display, EnumCount, EnumIter, EnumIs,
@@ -13,3 +15,155 @@ pub enum Corner {
     SouthWest,
     SouthEast,
 }
+
+impl Corner {
+    /// All corners, in declaration order
+    pub const ALL: [Self; 4] = [
+        Corner::NorthWest,
+        Corner::NorthEast,
+        Corner::SouthWest,
+        Corner::SouthEast,
+    ];
+
+    /// The corner diagonally opposite this one
+    #[must_use]
+    pub const fn opposite(&self) -> Self {
+        match self {
+            Corner::NorthWest => Corner::SouthEast,
+            Corner::NorthEast => Corner::SouthWest,
+            Corner::SouthWest => Corner::NorthEast,
+            Corner::SouthEast => Corner::NorthWest,
+        }
+    }
+
+    /// The two corners which share an edge with this one, clockwise first
+    #[must_use]
+    pub const fn adjacent_corners(&self) -> [Self; 2] {
+        match self {
+            Corner::NorthWest => [Corner::NorthEast, Corner::SouthWest],
+            Corner::NorthEast => [Corner::SouthEast, Corner::NorthWest],
+            Corner::SouthWest => [Corner::NorthWest, Corner::SouthEast],
+            Corner::SouthEast => [Corner::SouthWest, Corner::NorthEast],
+        }
+    }
+
+    /// The diagonal unit vector pointing from the center of a tile towards this corner
+    pub const fn offset(&self) -> Vector {
+        match self {
+            Corner::NorthWest => Vector::NORTH_WEST,
+            Corner::NorthEast => Vector::NORTH_EAST,
+            Corner::SouthWest => Vector::SOUTH_WEST,
+            Corner::SouthEast => Vector::SOUTH_EAST,
+        }
+    }
+
+    /// This corner rotated clockwise by the given number of quarter turns
+    #[must_use]
+    pub const fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        match quarter_turns {
+            QuarterTurns::Zero => *self,
+            QuarterTurns::One => self.clockwise(),
+            QuarterTurns::Two => self.opposite(),
+            QuarterTurns::Three => self.clockwise().opposite(),
+        }
+    }
+
+    /// This corner flipped in the given axes
+    #[must_use]
+    pub const fn flip(&self, axes: FlipAxes) -> Self {
+        match (axes, self) {
+            (FlipAxes::None, _) => *self,
+            (FlipAxes::Both, _) => self.opposite(),
+            (FlipAxes::Horizontal, Corner::NorthWest) | (FlipAxes::Vertical, Corner::SouthEast) => {
+                Corner::NorthEast
+            }
+            (FlipAxes::Horizontal, Corner::NorthEast) | (FlipAxes::Vertical, Corner::SouthWest) => {
+                Corner::NorthWest
+            }
+            (FlipAxes::Horizontal, Corner::SouthWest) | (FlipAxes::Vertical, Corner::NorthEast) => {
+                Corner::SouthEast
+            }
+            (FlipAxes::Horizontal, Corner::SouthEast) | (FlipAxes::Vertical, Corner::NorthWest) => {
+                Corner::SouthWest
+            }
+        }
+    }
+}
+
+impl Flip for Corner {
+    fn flip(&self, axes: FlipAxes) -> Self {
+        Corner::flip(self, axes)
+    }
+}
+
+impl Rotate for Corner {
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        Corner::rotate(self, quarter_turns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_all() {
+        assert!(Corner::ALL.into_iter().eq(Corner::iter()));
+    }
+
+    #[test]
+    fn test_opposite_and_adjacent() {
+        for corner in Corner::ALL {
+            assert_eq!(corner.opposite().opposite(), corner);
+            assert_eq!(corner.offset().const_neg(), corner.opposite().offset());
+
+            let [clockwise, anticlockwise] = corner.adjacent_corners();
+            assert_eq!(clockwise, corner.clockwise());
+            assert_eq!(anticlockwise, corner.anticlockwise());
+            assert_ne!(clockwise, corner.opposite());
+            assert_ne!(anticlockwise, corner.opposite());
+        }
+    }
+
+    #[test]
+    fn test_offset_matches_vertex() {
+        let tile = DynamicTile(Vector::new(3, 5));
+        for corner in Corner::ALL {
+            let offset = corner.offset();
+            let vertex = tile.get_vertex(&corner);
+            // The vertex is half a step from the tile center in the direction of the offset
+            assert_eq!(
+                Vector::new(vertex.x * 2 - 1, vertex.y * 2 - 1),
+                Vector::new(tile.x * 2, tile.y * 2).const_add(offset)
+            );
+        }
+    }
+
+    #[test]
+    fn test_rotate_and_flip_match_offset() {
+        for corner in Corner::ALL {
+            for quarter_turns in QuarterTurns::iter() {
+                assert_eq!(
+                    corner.rotate(quarter_turns).offset(),
+                    corner.offset().rotate(quarter_turns)
+                );
+            }
+            for axes in FlipAxes::iter() {
+                assert_eq!(corner.flip(axes).offset(), corner.offset().flip(axes));
+            }
+        }
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_tokens(
+            &Corner::SouthWest,
+            &[Token::UnitVariant {
+                name: "Corner",
+                variant: "SouthWest",
+            }],
+        );
+    }
+}
@@ -0,0 +1,202 @@
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// A symmetry of the square - one of the eight elements of the dihedral group D4.
+/// Applying a transform first flips horizontally (if `flipped`), then rotates clockwise by `quarter_turns`.
+#[must_use]
+#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+pub struct Dihedral {
+    pub quarter_turns: QuarterTurns,
+    pub flipped: bool,
+}
+
+impl Dihedral {
+    /// The transform which leaves everything unchanged
+    pub const IDENTITY: Self = Self::new(QuarterTurns::Zero, false);
+
+    /// All eight transforms
+    pub const ALL: [Self; 8] = [
+        Self::new(QuarterTurns::Zero, false),
+        Self::new(QuarterTurns::One, false),
+        Self::new(QuarterTurns::Two, false),
+        Self::new(QuarterTurns::Three, false),
+        Self::new(QuarterTurns::Zero, true),
+        Self::new(QuarterTurns::One, true),
+        Self::new(QuarterTurns::Two, true),
+        Self::new(QuarterTurns::Three, true),
+    ];
+
+    pub const fn new(quarter_turns: QuarterTurns, flipped: bool) -> Self {
+        Self {
+            quarter_turns,
+            flipped,
+        }
+    }
+
+    /// The transform equivalent to applying `self` and then `next`
+    pub const fn then(&self, next: &Self) -> Self {
+        let self_turns = turns_to_u8(self.quarter_turns);
+        let next_turns = turns_to_u8(next.quarter_turns);
+
+        // Flipping after a rotation is the same as flipping before the opposite rotation
+        let turns = if next.flipped {
+            next_turns + 4 - self_turns
+        } else {
+            next_turns + self_turns
+        };
+
+        Self::new(u8_to_turns(turns), self.flipped ^ next.flipped)
+    }
+
+    /// The transform which undoes this one
+    pub const fn inverse(&self) -> Self {
+        if self.flipped {
+            // Reflections are their own inverse
+            *self
+        } else {
+            Self::new(u8_to_turns(4 - turns_to_u8(self.quarter_turns)), false)
+        }
+    }
+
+    pub const fn apply_to_vector(&self, vector: &Vector) -> Vector {
+        let vector = if self.flipped {
+            vector.flip(FlipAxes::Horizontal)
+        } else {
+            *vector
+        };
+        vector.rotate(self.quarter_turns)
+    }
+
+    pub const fn apply_to_tile<const L: u8>(&self, tile: &Tile<L, L>) -> Tile<L, L> {
+        let tile = if self.flipped {
+            tile.flip(FlipAxes::Horizontal)
+        } else {
+            *tile
+        };
+        tile.rotate(self.quarter_turns)
+    }
+}
+
+impl From<QuarterTurns> for Dihedral {
+    fn from(quarter_turns: QuarterTurns) -> Self {
+        Self::new(quarter_turns, false)
+    }
+}
+
+impl From<FlipAxes> for Dihedral {
+    fn from(axes: FlipAxes) -> Self {
+        match axes {
+            FlipAxes::None => Self::IDENTITY,
+            FlipAxes::Horizontal => Self::new(QuarterTurns::Zero, true),
+            FlipAxes::Vertical => Self::new(QuarterTurns::Two, true),
+            FlipAxes::Both => Self::new(QuarterTurns::Two, false),
+        }
+    }
+}
+
+impl From<(QuarterTurns, FlipAxes)> for Dihedral {
+    /// The transform which flips by the axes and then rotates
+    fn from((quarter_turns, axes): (QuarterTurns, FlipAxes)) -> Self {
+        Self::from(axes).then(&Self::from(quarter_turns))
+    }
+}
+
+const fn turns_to_u8(quarter_turns: QuarterTurns) -> u8 {
+    match quarter_turns {
+        QuarterTurns::Zero => 0,
+        QuarterTurns::One => 1,
+        QuarterTurns::Two => 2,
+        QuarterTurns::Three => 3,
+    }
+}
+
+const fn u8_to_turns(turns: u8) -> QuarterTurns {
+    match turns % 4 {
+        0 => QuarterTurns::Zero,
+        1 => QuarterTurns::One,
+        2 => QuarterTurns::Two,
+        _ => QuarterTurns::Three,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    const VECTORS: [Vector; 3] = [Vector::new(1, 2), Vector::new(-3, 1), Vector::NORTH_EAST];
+
+    #[test]
+    fn test_then() {
+        for a in Dihedral::ALL {
+            for b in Dihedral::ALL {
+                let combined = a.then(&b);
+                for v in VECTORS {
+                    assert_eq!(
+                        combined.apply_to_vector(&v),
+                        b.apply_to_vector(&a.apply_to_vector(&v)),
+                        "{a:?} then {b:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        for transform in Dihedral::ALL {
+            assert_eq!(transform.then(&transform.inverse()), Dihedral::IDENTITY);
+            assert_eq!(transform.inverse().then(&transform), Dihedral::IDENTITY);
+        }
+    }
+
+    #[test]
+    fn test_all_distinct() {
+        for (i, a) in Dihedral::ALL.iter().enumerate() {
+            for b in Dihedral::ALL.iter().skip(i + 1) {
+                assert!(VECTORS
+                    .iter()
+                    .any(|v| a.apply_to_vector(v) != b.apply_to_vector(v)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_quarter_turns_and_flip_axes() {
+        for quarter_turns in QuarterTurns::iter() {
+            for axes in FlipAxes::iter() {
+                let transform = Dihedral::from((quarter_turns, axes));
+                for v in VECTORS {
+                    assert_eq!(
+                        transform.apply_to_vector(&v),
+                        v.flip(axes).rotate(quarter_turns)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_to_tile() {
+        for transform in Dihedral::ALL {
+            for tile in Tile::<4, 4>::iter_by_row() {
+                let expected = if transform.flipped {
+                    tile.flip(FlipAxes::Horizontal)
+                } else {
+                    tile
+                }
+                .rotate(transform.quarter_turns);
+                assert_eq!(transform.apply_to_tile(&tile), expected);
+                assert_eq!(
+                    transform
+                        .inverse()
+                        .apply_to_tile(&transform.apply_to_tile(&tile)),
+                    tile
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use core::fmt;
+
+/// An error from constructing a grid whose `SIZE` does not match its dimensions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DimensionError {
+    /// `SIZE` is not the number of elements in the grid
+    SizeMismatch { expected: usize, found: usize },
+    /// The grid has more elements than its storage can hold
+    TooLarge { size: usize, max: usize },
+}
+
+impl DimensionError {
+    /// A short description of the error, usable in const contexts
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            DimensionError::SizeMismatch { .. } => "SIZE does not match the grid dimensions",
+            DimensionError::TooLarge { .. } => "Grid is too large for its storage",
+        }
+    }
+
+    /// Check that a grid with `expected` elements has `SIZE` equal to `found` and fits in `max` elements
+    pub(crate) const fn check(expected: usize, found: usize, max: usize) -> Result<(), Self> {
+        if expected != found {
+            Err(DimensionError::SizeMismatch { expected, found })
+        } else if found > max {
+            Err(DimensionError::TooLarge { size: found, max })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Like `check` but panics, in release builds as well as debug builds
+    pub(crate) const fn assert(expected: usize, found: usize, max: usize) {
+        if let Err(e) = Self::check(expected, found, max) {
+            panic!("{}", e.message());
+        }
+    }
+}
+
+impl fmt::Display for DimensionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DimensionError::SizeMismatch { expected, found } => {
+                write!(f, "{}: expected {expected}, found {found}", self.message())
+            }
+            DimensionError::TooLarge { size, max } => {
+                write!(f, "{}: {size} is more than {max}", self.message())
+            }
+        }
+    }
+}
+
+impl core::error::Error for DimensionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(DimensionError::check(6, 6, 8), Ok(()));
+        assert_eq!(
+            DimensionError::check(6, 9, 16),
+            Err(DimensionError::SizeMismatch {
+                expected: 6,
+                found: 9
+            })
+        );
+        assert_eq!(
+            DimensionError::check(12, 12, 8).unwrap_err().to_string(),
+            "Grid is too large for its storage: 12 is more than 8"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "SIZE does not match the grid dimensions")]
+    fn test_assert() {
+        DimensionError::assert(4, 5, 8);
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIs, EnumIter};
+
+use crate::prelude::*;
+
+/// One of the eight directions from a tile to its neighbours
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Display, EnumCount, EnumIter, EnumIs,
+)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All directions, clockwise from north. Matches the order of `Vector::UNITS`
+    pub const ALL: [Self; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The four orthogonal directions, clockwise from north. Matches the order of `Vector::CARDINALS`
+    pub const CARDINALS: [Self; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// The unit vector in this direction
+    pub const fn vector(&self) -> Vector {
+        Vector::UNITS[*self as usize]
+    }
+
+    /// The direction of a unit vector.
+    /// Returns `None` if the vector is not a unit vector
+    #[must_use]
+    pub const fn try_from_vector(vector: Vector) -> Option<Self> {
+        let mut index = 0;
+        while index < Self::ALL.len() {
+            if Vector::UNITS[index].const_eq(vector) {
+                return Some(Self::ALL[index]);
+            }
+            index += 1;
+        }
+        None
+    }
+
+    /// The direction pointing the opposite way
+    #[must_use]
+    pub const fn opposite(&self) -> Self {
+        Self::ALL[(*self as usize + 4) % 8]
+    }
+
+    /// The next direction clockwise (an eighth turn)
+    #[must_use]
+    pub const fn clockwise(&self) -> Self {
+        Self::ALL[(*self as usize + 1) % 8]
+    }
+
+    /// The next direction anticlockwise (an eighth turn)
+    #[must_use]
+    pub const fn anticlockwise(&self) -> Self {
+        Self::ALL[(*self as usize + 7) % 8]
+    }
+
+    /// Whether this is one of north, east, south or west
+    #[must_use]
+    pub const fn is_cardinal(&self) -> bool {
+        (*self as usize) & 1 == 0
+    }
+
+    /// Whether this is one of the four diagonal directions
+    #[must_use]
+    pub const fn is_diagonal(&self) -> bool {
+        !self.is_cardinal()
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(value: Direction) -> Self {
+        value.vector()
+    }
+}
+
+impl TryFrom<Vector> for Direction {
+    type Error = NotADirectionError;
+
+    fn try_from(value: Vector) -> Result<Self, Self::Error> {
+        Self::try_from_vector(value).ok_or(NotADirectionError(value))
+    }
+}
+
+/// An error from converting a vector which is not one of the eight unit vectors into a `Direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NotADirectionError(pub Vector);
+
+impl core::fmt::Display for NotADirectionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "({},{}) is not the vector of a direction",
+            self.0.x, self.0.y
+        )
+    }
+}
+
+impl core::error::Error for NotADirectionError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_all() {
+        assert!(Direction::ALL.into_iter().eq(Direction::iter()));
+        assert!(Direction::ALL.map(Vector::from) == Vector::UNITS);
+        assert!(Direction::CARDINALS.map(Vector::from) == Vector::CARDINALS);
+    }
+
+    #[test]
+    fn test_try_from_vector() {
+        for direction in Direction::ALL {
+            assert_eq!(Direction::try_from(direction.vector()), Ok(direction));
+        }
+        assert_eq!(Direction::try_from_vector(Vector::ZERO), None);
+        assert_eq!(Direction::try_from_vector(Vector::new(2, 0)), None);
+        assert_eq!(
+            Direction::try_from(Vector::new(2, 0)).map_err(|e| e.to_string()),
+            Err("(2,0) is not the vector of a direction".to_string())
+        );
+    }
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.opposite().vector(),
+                direction.vector().const_neg()
+            );
+            assert_eq!(direction.clockwise().anticlockwise(), direction);
+            assert_eq!(direction.is_cardinal(), !direction.vector().is_diagonal());
+            assert_eq!(
+                direction.clockwise().clockwise().vector(),
+                direction.vector().rotate(QuarterTurns::One)
+            );
+        }
+        assert!(Direction::SouthWest.is_diagonal());
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_tokens(
+            &Direction::NorthEast,
+            &[Token::UnitVariant {
+                name: "Direction",
+                variant: "NorthEast",
+            }],
+        );
+    }
+}
@@ -0,0 +1,113 @@
+use core::fmt::{self, Write};
+
+use crate::prelude::*;
+
+/// Displays a grid, writing each tile with a callback.
+/// Use `display_with` on a tile set or tile map to create one.
+#[must_use]
+#[derive(Clone, Copy)]
+pub struct DisplayAdapter<F, const WIDTH: u8, const HEIGHT: u8> {
+    cell: F,
+    separator: Option<char>,
+    headers: bool,
+}
+
+impl<
+        F: Fn(Tile<WIDTH, HEIGHT>, &mut fmt::Formatter<'_>) -> fmt::Result,
+        const WIDTH: u8,
+        const HEIGHT: u8,
+    > DisplayAdapter<F, WIDTH, HEIGHT>
+{
+    /// Create an adapter which writes each tile using `cell`
+    pub const fn new(cell: F) -> Self {
+        Self {
+            cell,
+            separator: None,
+            headers: false,
+        }
+    }
+
+    /// Write `separator` between the columns of each row
+    pub const fn with_separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// Write the row and column indices before each row and above the first row.
+    /// Column indices are written modulo 10 so that they stay aligned with the columns.
+    pub const fn with_headers(mut self, headers: bool) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    fn write_row_header(&self, f: &mut fmt::Formatter<'_>, label: Option<u8>) -> fmt::Result {
+        let width = match HEIGHT {
+            0..=10 => 1,
+            11..=100 => 2,
+            _ => 3,
+        };
+        match label {
+            Some(y) => write!(f, "{y:>width$}")?,
+            None => write!(f, "{:>width$}", "")?,
+        }
+        if let Some(separator) = self.separator {
+            f.write_char(separator)?;
+        }
+        Ok(())
+    }
+}
+
+impl<
+        F: Fn(Tile<WIDTH, HEIGHT>, &mut fmt::Formatter<'_>) -> fmt::Result,
+        const WIDTH: u8,
+        const HEIGHT: u8,
+    > fmt::Display for DisplayAdapter<F, WIDTH, HEIGHT>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.headers {
+            self.write_row_header(f, None)?;
+            for x in 0..WIDTH {
+                if x > 0 {
+                    if let Some(separator) = self.separator {
+                        f.write_char(separator)?;
+                    }
+                }
+                write!(f, "{}", x % 10)?;
+            }
+            f.write_char('\n')?;
+        }
+
+        for tile in Tile::<WIDTH, HEIGHT>::iter_by_row() {
+            if tile.x() == 0 {
+                if tile.y() > 0 {
+                    f.write_char('\n')?;
+                }
+                if self.headers {
+                    self.write_row_header(f, Some(tile.y()))?;
+                }
+            } else if let Some(separator) = self.separator {
+                f.write_char(separator)?;
+            }
+            (self.cell)(tile, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_adapter() {
+        let adapter = DisplayAdapter::<_, 3, 2>::new(|tile, f| write!(f, "{}", tile.inner()));
+
+        assert_eq!(adapter.to_string(), "012\n345");
+        assert_eq!(adapter.with_separator(',').to_string(), "0,1,2\n3,4,5");
+        assert_eq!(
+            adapter.with_separator(' ').with_headers(true).to_string(),
+            "  0 1 2\n0 0 1 2\n1 3 4 5"
+        );
+    }
+}
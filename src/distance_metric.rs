@@ -0,0 +1,59 @@
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIs, EnumIter};
+
+use crate::prelude::*;
+
+/// A way of measuring the distance between two tiles
+#[derive(
+    Default,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Display,
+    EnumCount,
+    EnumIter,
+    EnumIs,
+)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+pub enum DistanceMetric {
+    /// The sum of the distances in the two axes, i.e. the number of orthogonal steps
+    #[default]
+    Manhattan,
+    /// The greater of the distances in the two axes, i.e. the number of king moves
+    Chebyshev,
+}
+
+impl DistanceMetric {
+    /// The distance between two tiles using this metric
+    #[must_use]
+    pub const fn distance<const WIDTH: u8, const HEIGHT: u8>(
+        &self,
+        a: &Tile<WIDTH, HEIGHT>,
+        b: &Tile<WIDTH, HEIGHT>,
+    ) -> u8 {
+        match self {
+            DistanceMetric::Manhattan => a.manhattan_distance(b),
+            DistanceMetric::Chebyshev => a.chebyshev_distance(b),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distance() {
+        let a = Tile::<5, 5>::new_const::<1, 4>();
+        let b = Tile::<5, 5>::new_const::<3, 1>();
+        assert_eq!(DistanceMetric::Manhattan.distance(&a, &b), 5);
+        assert_eq!(DistanceMetric::Chebyshev.distance(&a, &b), 3);
+        assert_eq!(DistanceMetric::Chebyshev.distance(&b, &b), 0);
+    }
+}
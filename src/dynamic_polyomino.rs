@@ -0,0 +1,352 @@
+use core::fmt::{self, Write};
+
+use crate::ascii_options::AsciiGlyph;
+use crate::prelude::*;
+
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+/// A polyomino whose number of tiles is only known at runtime.
+/// Like `Polyomino`, the tiles are normalized so that the minimum x and y are zero, and sorted.
+/// Requires `std`
+#[must_use]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize))]
+pub struct DynamicPolyomino(Vec<DynamicTile>);
+
+impl DynamicPolyomino {
+    /// Create a polyomino from its tiles, normalizing and sorting them
+    pub fn new(mut tiles: Vec<DynamicTile>) -> Self {
+        let min_x = tiles.iter().map(|t| t.x).min().unwrap_or_default();
+        let min_y = tiles.iter().map(|t| t.y).min().unwrap_or_default();
+        for tile in &mut tiles {
+            tile.0 = Vector::new(tile.x - min_x, tile.y - min_y);
+        }
+        tiles.sort();
+        Self(tiles)
+    }
+
+    /// Create a polyomino from its tiles, normalizing and sorting them
+    /// # Errors
+    /// If the same tile appears more than once
+    pub fn try_new(tiles: Vec<DynamicTile>) -> Result<Self, ShapeError> {
+        let shape = Self::new(tiles);
+        if shape.0.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(ShapeError::DuplicateTile);
+        }
+        Ok(shape)
+    }
+
+    /// The tiles of this polyomino
+    pub fn tiles(&self) -> &[DynamicTile] {
+        &self.0
+    }
+
+    /// Iterate through the tiles of this polyomino
+    pub fn iter(&self) -> core::iter::Copied<core::slice::Iter<'_, DynamicTile>> {
+        self.0.iter().copied()
+    }
+
+    /// The number of tiles in this polyomino
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Treat this polyomino as a `TileSlice`
+    pub fn as_tile_slice(&self) -> TileSlice<'_> {
+        TileSlice(&self.0)
+    }
+
+    /// Draw the outline of this polyomino.
+    /// May panic if the tiles are not connected; use `try_draw_outline` to check first.
+    #[must_use]
+    pub fn draw_outline(&self) -> SliceOutlineIter<'_> {
+        self.as_tile_slice().draw_outline()
+    }
+
+    /// Draw the outline of this polyomino, first checking that its tiles are distinct and connected.
+    /// # Errors
+    /// If the polyomino is empty, contains duplicate tiles or is not connected
+    pub fn try_draw_outline(&self) -> Result<SliceOutlineIter<'_>, ShapeError> {
+        self.as_tile_slice().try_draw_outline()
+    }
+
+    /// Deconstruct this polyomino into rectangles, in the same order as `Polyomino`
+    #[must_use]
+    pub fn deconstruct_into_rectangles(&self) -> SliceRectangleIter {
+        self.as_tile_slice().deconstruct_into_rectangles()
+    }
+
+    /// Construct a polyomino from a string of ascii, with any number of tiles.
+    /// Tiles are represented by `#`. Empty tiles by `.`.
+    ///
+    /// # Errors
+    /// If there are unexpected characters.
+    pub fn try_new_from_ascii(s: &str) -> Result<Self, ParseShapeError> {
+        Self::try_new_from_ascii_with(s, AsciiOptions::DEFAULT)
+    }
+
+    /// Construct a polyomino from a string of ascii, using `options` to interpret the characters
+    ///
+    /// # Errors
+    /// If there are unexpected characters.
+    pub fn try_new_from_ascii_with(
+        s: &str,
+        options: AsciiOptions,
+    ) -> Result<Self, ParseShapeError> {
+        let mut current = Vector::ZERO;
+        let mut tiles = Vec::new();
+
+        for (index, byte) in s.bytes().enumerate() {
+            match options.classify(byte) {
+                Some(AsciiGlyph::NewLine) => {
+                    current = Vector::new(0, current.y + 1);
+                }
+                Some(AsciiGlyph::Ignored) => {}
+                Some(AsciiGlyph::Space) => current = current.const_add(Vector::EAST),
+                Some(AsciiGlyph::Tile) => {
+                    tiles.push(DynamicTile(current.flip(options.flip)));
+                    current = current.const_add(Vector::EAST);
+                }
+                None => return Err(ParseShapeError::UnexpectedCharacter { byte, index }),
+            }
+        }
+
+        Ok(Self::new(tiles))
+    }
+
+    /// Write the polyomino as an ascii string
+    #[must_use]
+    pub fn to_ascii_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for DynamicPolyomino {
+    /// Writes the polyomino as ascii.
+    /// Tiles are represented by `#`. Empty tiles by `.`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(max_x) = self.0.iter().map(|t| t.x).max() else {
+            return Ok(());
+        };
+        let max_y = self.0.iter().map(|t| t.y).max().unwrap_or_default();
+
+        for y in 0..=max_y {
+            if y > 0 {
+                f.write_char('\n')?;
+            }
+            for x in 0..=max_x {
+                let c = if self
+                    .0
+                    .binary_search(&DynamicTile(Vector::new(x, y)))
+                    .is_ok()
+                {
+                    '#'
+                } else {
+                    '.'
+                };
+                f.write_char(c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for DynamicPolyomino {
+    type Err = ParseShapeError;
+
+    /// Parses ascii in the same format as `try_new_from_ascii`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_new_from_ascii(s)
+    }
+}
+
+impl<'a> IntoIterator for &'a DynamicPolyomino {
+    type Item = DynamicTile;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, DynamicTile>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> Shape for &'a DynamicPolyomino {
+    type OutlineIter = SliceOutlineIter<'a>;
+
+    type RectangleIter = SliceRectangleIter;
+
+    fn draw_outline(&self) -> Self::OutlineIter {
+        self.as_tile_slice().draw_outline()
+    }
+
+    fn deconstruct_into_rectangles(&self) -> Self::RectangleIter {
+        self.as_tile_slice().deconstruct_into_rectangles()
+    }
+
+    #[cfg(any(test, feature = "glam"))]
+    fn contains_point(&self, point: glam::f32::Vec2, scale: f32) -> bool {
+        self.as_tile_slice().contains_point(point, scale)
+    }
+}
+
+impl<const P: usize> From<Polyomino<P>> for DynamicPolyomino {
+    fn from(value: Polyomino<P>) -> Self {
+        // Polyomino tiles are already normalized and sorted
+        Self(value.tiles().to_vec())
+    }
+}
+
+impl<const P: usize> TryFrom<&DynamicPolyomino> for Polyomino<P> {
+    type Error = DimensionError;
+
+    /// # Errors
+    /// If the polyomino does not have `P` tiles
+    fn try_from(value: &DynamicPolyomino) -> Result<Self, Self::Error> {
+        let tiles = <[DynamicTile; P]>::try_from(value.tiles()).map_err(|_| {
+            DimensionError::SizeMismatch {
+                expected: P,
+                found: value.len(),
+            }
+        })?;
+        Ok(Polyomino::new(tiles.map(|t| t.0)))
+    }
+}
+
+#[cfg(any(test, feature = "serde"))]
+impl<'de> Deserialize<'de> for DynamicPolyomino {
+    /// Deserializes the tiles in the same format as they are serialized, normalizing and sorting them.
+    /// Fails if the same tile appears more than once.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let tiles: Vec<DynamicTile> =
+            crate::serde_newtype::deserialize_newtype(deserializer, "DynamicPolyomino")?;
+        Self::try_new(tiles).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_new_normalizes() {
+        let shape = DynamicPolyomino::new(vec![
+            DynamicTile(Vector::new(3, 2)),
+            DynamicTile(Vector::new(2, 2)),
+            DynamicTile(Vector::new(2, 3)),
+        ]);
+
+        assert_eq!(shape.tiles().iter().join("|"), "(0,0)|(0,1)|(1,0)");
+        assert_eq!(shape.to_string(), "##\n#.");
+    }
+
+    #[test]
+    fn test_try_new() {
+        let tiles = vec![
+            DynamicTile(Vector::new(3, 3)),
+            DynamicTile(Vector::new(0, 0)),
+            DynamicTile(Vector::new(0, 0)),
+        ];
+        assert_eq!(
+            DynamicPolyomino::try_new(tiles.clone()),
+            Err(ShapeError::DuplicateTile)
+        );
+        assert_eq!(
+            DynamicPolyomino::try_new(tiles[..2].to_vec()),
+            Ok(DynamicPolyomino::new(tiles[..2].to_vec()))
+        );
+    }
+
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+
+        fn tile_tokens(x: i8, y: i8) -> [Token; 6] {
+            [
+                Token::Struct {
+                    name: "Vector",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::I8(x),
+                Token::Str("y"),
+                Token::I8(y),
+                Token::StructEnd,
+            ]
+        }
+
+        let tokens = |tiles: &[(i8, i8)]| {
+            let mut tokens = vec![
+                Token::NewtypeStruct {
+                    name: "DynamicPolyomino",
+                },
+                Token::Seq {
+                    len: Some(tiles.len()),
+                },
+            ];
+            tokens.extend(tiles.iter().flat_map(|(x, y)| tile_tokens(*x, *y)));
+            tokens.push(Token::SeqEnd);
+            tokens
+        };
+
+        let shape = DynamicPolyomino::from(Polyomino::L_TETROMINO);
+        let shape_tokens = tokens(&shape.iter().map(|t| (t.x, t.y)).collect_vec());
+        assert_tokens(&shape, &shape_tokens);
+
+        let unsorted = DynamicPolyomino::new(vec![
+            DynamicTile(Vector::new(0, 0)),
+            DynamicTile(Vector::new(3, 3)),
+        ]);
+        assert_de_tokens(&unsorted, &tokens(&[(4, 4), (1, 1)]));
+        assert_eq!(unsorted.to_string(), "#...\n....\n....\n...#");
+
+        assert_de_tokens_error::<DynamicPolyomino>(
+            &tokens(&[(3, 3), (0, 0), (0, 0)]),
+            "Shape contains a duplicate tile",
+        );
+    }
+
+    #[test]
+    fn test_matches_polyomino() {
+        for shape in Polyomino::ALL_PENTOMINOS {
+            let dynamic = DynamicPolyomino::from(shape);
+
+            assert_eq!(dynamic.to_ascii_string(), shape.to_ascii_string());
+            assert_eq!(dynamic.to_string().parse(), Ok(dynamic.clone()));
+            assert_eq!(
+                dynamic.draw_outline().collect_vec(),
+                shape.draw_outline().collect_vec()
+            );
+            assert_eq!(
+                dynamic.deconstruct_into_rectangles().collect_vec(),
+                shape.deconstruct_into_rectangles().collect_vec()
+            );
+            assert_eq!(Polyomino::<5>::try_from(&dynamic), Ok(shape));
+            assert_eq!(
+                Polyomino::<4>::try_from(&dynamic),
+                Err(DimensionError::SizeMismatch {
+                    expected: 4,
+                    found: 5
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_ascii() {
+        let shape: DynamicPolyomino = "#######\n..#".parse().unwrap();
+        assert_eq!(shape.len(), 8);
+        assert!("#x".parse::<DynamicPolyomino>().is_err());
+
+        let empty: DynamicPolyomino = "...".parse().unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.to_string(), "");
+        assert_eq!(empty.try_draw_outline().err(), Some(ShapeError::Empty));
+    }
+}
@@ -57,6 +57,16 @@ impl fmt::Display for DynamicTile {
     }
 }
 
+impl core::str::FromStr for DynamicTile {
+    type Err = ParseCoordinatesError;
+
+    /// Parses tiles of the form `(x,y)` or `x,y`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = crate::parse_coordinates_error::parse_coordinates(s)?;
+        Ok(DynamicTile(Vector { x, y }))
+    }
+}
+
 impl DynamicTile {
     pub const fn flip(&self, axes: FlipAxes) -> Self {
         Self(self.0.flip(axes))
@@ -66,6 +76,16 @@ impl DynamicTile {
         Self(self.0.const_add(vector))
     }
 
+    /// The tile in the same row with column `x`
+    pub const fn with_x(&self, x: i8) -> Self {
+        Self(Vector { x, y: self.0.y })
+    }
+
+    /// The tile in the same column with row `y`
+    pub const fn with_y(&self, y: i8) -> Self {
+        Self(Vector { x: self.0.x, y })
+    }
+
     pub const fn get_vertex(&self, corner: &Corner) -> DynamicVertex {
         let (x, y) = match corner {
             Corner::NorthWest => (self.0.x, self.0.y),
@@ -77,8 +97,17 @@ impl DynamicTile {
         DynamicVertex(Vector { x, y })
     }
 
-    /// Gets the nearest tile to this center
+    /// The four vertices of this tile, in the order of `Corner::ALL`
+    pub const fn vertices(&self) -> [DynamicVertex; 4] {
+        [
+            self.get_vertex(&Corner::NorthWest),
+            self.get_vertex(&Corner::NorthEast),
+            self.get_vertex(&Corner::SouthWest),
+            self.get_vertex(&Corner::SouthEast),
+        ]
+    }
 
+    /// Gets the nearest tile to this center
     #[cfg(any(test, all(feature = "std", feature = "glam")))]
     #[allow(clippy::cast_possible_truncation)]
     pub fn from_center(center: &glam::f32::Vec2, scale: f32) -> Self {
@@ -103,7 +132,6 @@ impl<V: AsRef<Vector>> Add<V> for DynamicTile {
 
 #[cfg(any(test, feature = "glam"))]
 impl HasCenter for DynamicTile {
-    #[must_use]
     fn get_center(&self, scale: f32) -> glam::f32::Vec2 {
         let x = scale * (f32::from(self.0.x) + 0.5);
         let y = scale * (f32::from(self.0.y) + 0.5);
@@ -118,9 +146,121 @@ impl DynamicTile {
     }
 }
 
+impl Flip for DynamicTile {
+    fn flip(&self, axes: FlipAxes) -> Self {
+        DynamicTile::flip(self, axes)
+    }
+}
+
+impl Rotate for DynamicTile {
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        DynamicTile::rotate(self, quarter_turns)
+    }
+}
+
+/// Serialize and deserialize a dynamic tile as a struct named `DynamicTile` with `x` and `y` fields.
+/// Use with `#[serde(with = "geometrid::dynamic_tile::serde_dynamic_tile_xy")]`
+#[cfg(any(test, feature = "serde"))]
+pub mod serde_dynamic_tile_xy {
+    use super::DynamicTile;
+    use crate::vector::Vector;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "DynamicTile")]
+    struct DynamicTileXY {
+        x: i8,
+        y: i8,
+    }
+
+    /// Serialize a dynamic tile as a struct with `x` and `y` fields
+    ///
+    /// # Errors
+    /// If the serializer fails
+    pub fn serialize<S: Serializer>(tile: &DynamicTile, serializer: S) -> Result<S::Ok, S::Error> {
+        DynamicTileXY {
+            x: tile.x,
+            y: tile.y,
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a dynamic tile from a struct with `x` and `y` fields
+    ///
+    /// # Errors
+    /// If the fields are missing
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DynamicTile, D::Error> {
+        let DynamicTileXY { x, y } = DynamicTileXY::deserialize(deserializer)?;
+        Ok(DynamicTile(Vector { x, y }))
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl From<DynamicTile> for mint::Point2<i8> {
+    fn from(value: DynamicTile) -> Self {
+        Self {
+            x: value.0.x,
+            y: value.0.y,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl From<mint::Point2<i8>> for DynamicTile {
+    fn from(value: mint::Point2<i8>) -> Self {
+        Self(Vector::new(value.x, value.y))
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl mint::IntoMint for DynamicTile {
+    type MintType = mint::Point2<i8>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn test_with_x_and_y() {
+        let tile = DynamicTile(Vector::new(3, -1));
+
+        assert_eq!(tile.with_x(-5), DynamicTile(Vector::new(-5, -1)));
+        assert_eq!(tile.with_y(7), DynamicTile(Vector::new(3, 7)));
+    }
+
+    #[test]
+    fn test_from_str() {
+        let tile = DynamicTile(Vector::new(3, -1));
+        assert_eq!(tile.to_string().parse(), Ok(tile));
+        assert_eq!("( 3 , -1 )".parse(), Ok(tile));
+        assert!("3;-1".parse::<DynamicTile>().is_err());
+    }
+
+    #[test]
+    fn test_serde_dynamic_tile_xy() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "serde_dynamic_tile_xy")] DynamicTile);
+
+        assert_tokens(
+            &Wrapper(DynamicTile(Vector::new(-1, 2))),
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Struct {
+                    name: "DynamicTile",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::I8(-1),
+                Token::Str("y"),
+                Token::I8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
 
     #[test]
     pub fn test_rotate() {
@@ -141,7 +281,7 @@ mod tests {
     pub fn test_center() {
         let tile: DynamicTile = Vector::new(-2, 3).into();
 
-        assert_eq!(tile.get_center(3.0), glam::f32::Vec2::new(-4.5, 10.5))
+        assert_eq!(tile.get_center(3.0), glam::f32::Vec2::new(-4.5, 10.5));
     }
 
     #[test]
@@ -173,6 +313,15 @@ mod tests {
         );
     }
 
+    #[test]
+    pub fn test_vertices() {
+        let tile = DynamicTile(Vector::new(2, -1));
+        assert!(tile
+            .vertices()
+            .into_iter()
+            .eq(Corner::ALL.iter().map(|c| tile.get_vertex(c))));
+    }
+
     #[test]
     pub fn test_from_center() {
         fn t(x: f32, y: f32, scale: f32, expected_x: i8, expected_y: i8) {
@@ -183,7 +332,7 @@ mod tests {
                     y: expected_y
                 }),
                 actual
-            )
+            );
         }
 
         t(0., 0., 1.0, 0, 0);
@@ -193,4 +342,15 @@ mod tests {
         t(5., -4., 1., 5, -4);
         t(5., -4., 2., 2, -2);
     }
+
+    #[test]
+    fn test_mint() {
+        let tile = DynamicTile(Vector::new(1, 2));
+        let point: mint::Point2<i8> = tile.into();
+        assert_eq!(point, mint::Point2 { x: 1, y: 2 });
+        assert_eq!(DynamicTile::from(point), tile);
+
+        let center: mint::Point2<f32> = tile.get_center(2.0).into();
+        assert_eq!(center, mint::Point2 { x: 3.0, y: 5.0 });
+    }
 }
@@ -57,6 +57,16 @@ impl fmt::Display for DynamicVertex {
     }
 }
 
+impl core::str::FromStr for DynamicVertex {
+    type Err = ParseCoordinatesError;
+
+    /// Parses vertices of the form `(x,y)` or `x,y`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = crate::parse_coordinates_error::parse_coordinates(s)?;
+        Ok(DynamicVertex(Vector { x, y }))
+    }
+}
+
 impl DynamicVertex {
     pub const fn flip(&self, axes: FlipAxes) -> Self {
         Self(self.0.flip(axes))
@@ -95,7 +105,6 @@ impl DynamicVertex {
 
 #[cfg(any(test, feature = "glam"))]
 impl HasCenter for DynamicVertex {
-    #[must_use]
     fn get_center(&self, scale: f32) -> glam::f32::Vec2 {
         let x = scale * f32::from(self.0.x);
         let y = scale * f32::from(self.0.y);
@@ -118,10 +127,51 @@ impl DynamicVertex {
     }
 }
 
+impl Flip for DynamicVertex {
+    fn flip(&self, axes: FlipAxes) -> Self {
+        DynamicVertex::flip(self, axes)
+    }
+}
+
+impl Rotate for DynamicVertex {
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        DynamicVertex::rotate(self, quarter_turns)
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl From<DynamicVertex> for mint::Point2<i8> {
+    fn from(value: DynamicVertex) -> Self {
+        Self {
+            x: value.0.x,
+            y: value.0.y,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl From<mint::Point2<i8>> for DynamicVertex {
+    fn from(value: mint::Point2<i8>) -> Self {
+        Self(Vector::new(value.x, value.y))
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl mint::IntoMint for DynamicVertex {
+    type MintType = mint::Point2<i8>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_from_str() {
+        let vertex = DynamicVertex(Vector::new(-2, 7));
+        assert_eq!(vertex.to_string().parse(), Ok(vertex));
+        assert_eq!("-2,7".parse(), Ok(vertex));
+    }
+
     #[test]
     pub fn test_rotate() {
         let vertex: DynamicVertex = Vector::new(-2, 3).into();
@@ -138,7 +188,7 @@ mod tests {
     pub fn test_center() {
         let vertex: DynamicVertex = Vector::new(-2, 3).into();
 
-        assert_eq!(vertex.get_center(3.0), glam::f32::Vec2::new(-6.0, 9.0))
+        assert_eq!(vertex.get_center(3.0), glam::f32::Vec2::new(-6.0, 9.0));
     }
     #[test]
     pub fn test_add() {
@@ -179,7 +229,7 @@ mod tests {
                     y: expected_y
                 }),
                 actual
-            )
+            );
         }
 
         t(0., 0., 1.0, 0, 0);
@@ -189,4 +239,12 @@ mod tests {
         t(5., -4., 1., 5, -4);
         t(5., -4., 2., 3, -2);
     }
+
+    #[test]
+    fn test_mint() {
+        let vertex = DynamicVertex(Vector::new(-1, 4));
+        let point: mint::Point2<i8> = vertex.into();
+        assert_eq!(point, mint::Point2 { x: -1, y: 4 });
+        assert_eq!(DynamicVertex::from(point), vertex);
+    }
 }
@@ -0,0 +1,107 @@
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumCount, EnumIs, EnumIter};
+
+use crate::prelude::*;
+
+/// How to treat positions which are outside the grid
+#[derive(
+    Default,
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Display,
+    EnumCount,
+    EnumIter,
+    EnumIs,
+)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+pub enum EdgePolicy {
+    /// Positions outside the grid have no tile
+    #[default]
+    Zero,
+    /// Positions outside the grid use the nearest tile on the edge
+    Clamp,
+    /// Positions outside the grid wrap around to the other side
+    Wrap,
+}
+
+impl EdgePolicy {
+    /// The tile to use for the position `(x, y)`, which may be outside the grid.
+    /// Returns `None` if the position should be treated as empty.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn resolve<const WIDTH: u8, const HEIGHT: u8>(
+        &self,
+        x: i16,
+        y: i16,
+    ) -> Option<Tile<WIDTH, HEIGHT>> {
+        let width = WIDTH as i16;
+        let height = HEIGHT as i16;
+        let (x, y) = match self {
+            EdgePolicy::Zero => {
+                if x < 0 || y < 0 || x >= width || y >= height {
+                    return None;
+                }
+                (x, y)
+            }
+            EdgePolicy::Clamp => (
+                if x < 0 {
+                    0
+                } else if x >= width {
+                    width - 1
+                } else {
+                    x
+                },
+                if y < 0 {
+                    0
+                } else if y >= height {
+                    height - 1
+                } else {
+                    y
+                },
+            ),
+            EdgePolicy::Wrap => (x.rem_euclid(width), y.rem_euclid(height)),
+        };
+        Tile::try_new(x as u8, y as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve() {
+        type T = Tile<3, 2>;
+        assert_eq!(
+            EdgePolicy::Zero.resolve::<3, 2>(1, 1),
+            Some(T::new_const::<1, 1>())
+        );
+        assert_eq!(EdgePolicy::Zero.resolve::<3, 2>(-1, 1), None);
+        assert_eq!(EdgePolicy::Zero.resolve::<3, 2>(1, 2), None);
+
+        assert_eq!(
+            EdgePolicy::Clamp.resolve::<3, 2>(-1, 5),
+            Some(T::new_const::<0, 1>())
+        );
+        assert_eq!(
+            EdgePolicy::Clamp.resolve::<3, 2>(4, -2),
+            Some(T::new_const::<2, 0>())
+        );
+
+        assert_eq!(
+            EdgePolicy::Wrap.resolve::<3, 2>(-1, 2),
+            Some(T::new_const::<2, 0>())
+        );
+        assert_eq!(
+            EdgePolicy::Wrap.resolve::<3, 2>(7, -3),
+            Some(T::new_const::<1, 1>())
+        );
+    }
+}
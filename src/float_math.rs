@@ -0,0 +1,37 @@
+//! Floating point functions which are provided by `std` or, in `no_std` builds, by `libm`
+
+#[cfg(any(test, feature = "std"))]
+#[inline]
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    y.atan2(x)
+}
+
+#[cfg(any(test, feature = "std"))]
+#[inline]
+pub(crate) fn round(x: f32) -> f32 {
+    x.round()
+}
+
+#[cfg(any(test, feature = "std"))]
+#[inline]
+pub(crate) fn sqrt(x: f32) -> f32 {
+    x.sqrt()
+}
+
+#[cfg(not(any(test, feature = "std")))]
+#[inline]
+pub(crate) fn atan2(y: f32, x: f32) -> f32 {
+    libm::atan2f(y, x)
+}
+
+#[cfg(not(any(test, feature = "std")))]
+#[inline]
+pub(crate) fn round(x: f32) -> f32 {
+    libm::roundf(x)
+}
+
+#[cfg(not(any(test, feature = "std")))]
+#[inline]
+pub(crate) fn sqrt(x: f32) -> f32 {
+    libm::sqrtf(x)
+}
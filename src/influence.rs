@@ -0,0 +1,175 @@
+use tinyvec::ArrayVec;
+
+use crate::prelude::*;
+
+/// Spread influence from each source through the grid, moving orthogonally.
+/// The influence of a source at a tile is `decay(strength, distance)`, where `distance` is the number of steps from the source.
+/// The influences of all sources are summed, so negative strengths can be used for opposing sources.
+/// Influence does not enter or pass through `blocked` tiles, and sources on blocked tiles are ignored.
+/// Tiles which no source can reach have zero influence.
+pub fn propagate_influence<
+    S: TileSetTrait<WIDTH, HEIGHT>,
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    const SIZE: usize,
+>(
+    sources: &[(Tile<WIDTH, HEIGHT>, f32)],
+    decay: impl Fn(f32, u32) -> f32,
+    blocked: &S,
+) -> TileMap<f32, WIDTH, HEIGHT, SIZE> {
+    propagate_influence_with(sources, Neighbourhood::VON_NEUMANN, decay, blocked)
+}
+
+/// Like `propagate_influence`, but moving to the tiles in `neighbourhood` instead of orthogonally
+pub fn propagate_influence_with<
+    S: TileSetTrait<WIDTH, HEIGHT>,
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    const SIZE: usize,
+>(
+    sources: &[(Tile<WIDTH, HEIGHT>, f32)],
+    neighbourhood: Neighbourhood<'_>,
+    decay: impl Fn(f32, u32) -> f32,
+    blocked: &S,
+) -> TileMap<f32, WIDTH, HEIGHT, SIZE> {
+    let mut result: TileMap<f32, WIDTH, HEIGHT, SIZE> = TileMap::default();
+
+    for (source, strength) in sources {
+        if blocked.get_bit(source) {
+            continue;
+        }
+        let mut visited = *blocked;
+        visited.set_bit(source, true);
+        let mut queue: ArrayVec<[(Tile<WIDTH, HEIGHT>, u32); SIZE]> = ArrayVec::new();
+        queue.push((*source, 0));
+        let mut index = 0;
+
+        while let Some((tile, distance)) = queue.get(index).copied() {
+            index += 1;
+            result[tile] += decay(*strength, distance);
+            for neighbour in tile.iter_neighbours(neighbourhood) {
+                if !visited.get_bit(&neighbour) {
+                    visited.set_bit(&neighbour, true);
+                    queue.push((neighbour, distance + 1));
+                }
+            }
+        }
+    }
+
+    result
+}
+
+/// Spread influence by repeated relaxation, moving orthogonally.
+/// On each of the `iterations`, every tile takes the greater of its source strength and the strongest neighbouring influence multiplied by `decay`.
+/// Where sources overlap the strongest one wins, so strengths should be non-negative.
+/// Blocked tiles always have zero influence and so do not pass it on.
+pub fn relax_influence<
+    S: TileSetTrait<WIDTH, HEIGHT>,
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    const SIZE: usize,
+>(
+    sources: &[(Tile<WIDTH, HEIGHT>, f32)],
+    decay: f32,
+    iterations: usize,
+    blocked: &S,
+) -> TileMap<f32, WIDTH, HEIGHT, SIZE> {
+    relax_influence_with(
+        sources,
+        Neighbourhood::VON_NEUMANN,
+        decay,
+        iterations,
+        blocked,
+    )
+}
+
+/// Like `relax_influence`, but taking influence from the tiles in `neighbourhood` instead of orthogonally
+pub fn relax_influence_with<
+    S: TileSetTrait<WIDTH, HEIGHT>,
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    const SIZE: usize,
+>(
+    sources: &[(Tile<WIDTH, HEIGHT>, f32)],
+    neighbourhood: Neighbourhood<'_>,
+    decay: f32,
+    iterations: usize,
+    blocked: &S,
+) -> TileMap<f32, WIDTH, HEIGHT, SIZE> {
+    let mut seeds: TileMap<f32, WIDTH, HEIGHT, SIZE> = TileMap::default();
+    for (source, strength) in sources {
+        if !blocked.get_bit(source) {
+            seeds[*source] = seeds[*source].max(*strength);
+        }
+    }
+
+    let mut current = seeds;
+    for _ in 0..iterations {
+        let next = TileMap::from_fn(|tile| {
+            if blocked.get_bit(&tile) {
+                return 0.0;
+            }
+            tile.iter_neighbours(neighbourhood)
+                .map(|neighbour| current[neighbour] * decay)
+                .fold(seeds[tile], f32::max)
+        });
+        if next == current {
+            break;
+        }
+        current = next;
+    }
+
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    #[allow(clippy::cast_precision_loss)]
+    fn test_propagate_influence() {
+        let blocked: TileSet16<4, 3, 12> = [Tile::new_const::<1, 0>(), Tile::new_const::<1, 1>()]
+            .into_iter()
+            .collect();
+
+        let map: TileMap<f32, 4, 3, 12> = propagate_influence(
+            &[(Tile::NORTH_WEST, 10.0), (Tile::SOUTH_EAST, -1.0)],
+            |strength, distance| strength - 2.0 * distance as f32,
+            &blocked,
+        );
+
+        assert_eq!(map.iter().join("|"), "-1|0|-9|-9|-1|0|-5|-5|-1|-1|-1|-1");
+    }
+
+    #[test]
+    fn test_relax_influence() {
+        let blocked: TileSet16<3, 3, 9> = [Tile::CENTER].into_iter().collect();
+
+        let map: TileMap<f32, 3, 3, 9> =
+            relax_influence(&[(Tile::NORTH_WEST, 8.0)], 0.5, 10, &blocked);
+
+        assert_eq!(map.iter().join("|"), "8|4|2|4|0|1|2|1|0.5");
+
+        let limited: TileMap<f32, 3, 3, 9> =
+            relax_influence(&[(Tile::NORTH_WEST, 8.0)], 0.5, 1, &blocked);
+
+        assert_eq!(limited.iter().join("|"), "8|4|0|4|0|0|0|0|0");
+    }
+
+    #[test]
+    fn test_relax_influence_with_diagonals() {
+        let blocked: TileSet16<3, 3, 9> = [Tile::CENTER].into_iter().collect();
+
+        let map: TileMap<f32, 3, 3, 9> = relax_influence_with(
+            &[(Tile::NORTH_WEST, 8.0)],
+            Neighbourhood::MOORE,
+            0.5,
+            10,
+            &blocked,
+        );
+
+        assert_eq!(map.iter().join("|"), "8|4|2|4|0|2|2|2|1");
+    }
+}
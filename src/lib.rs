@@ -4,43 +4,135 @@
 #![deny(warnings, dead_code, unused_imports, unused_mut)]
 #![warn(clippy::pedantic)]
 
+#[cfg(any(test, feature = "std"))]
+pub mod any_shape;
+#[cfg(any(test, feature = "proptest"))]
+pub mod arbitrary;
+pub mod ascii_options;
+pub mod board;
+#[cfg(any(test, feature = "std"))]
+pub mod chunked;
+#[cfg(any(test, feature = "std"))]
+pub mod contour;
 pub mod corner;
+pub mod dihedral;
+pub mod dimension_error;
+pub mod direction;
+pub mod display_adapter;
+pub mod distance_metric;
+#[cfg(any(test, feature = "std"))]
+pub mod dynamic_polyomino;
 pub mod dynamic_tile;
 pub mod dynamic_vertex;
+pub mod edge_policy;
 pub mod flip_axes;
+#[cfg(any(test, feature = "std", feature = "libm"))]
+mod float_math;
 pub mod has_center;
+pub mod influence;
 pub mod line_finder;
 pub mod line_of_sight;
+mod moments;
+pub mod morton;
+pub mod neighbourhood;
+#[cfg(any(test, feature = "rayon"))]
+pub mod parallel;
+pub mod parse_coordinates_error;
+pub mod parse_rle_error;
+pub mod parse_shape_error;
+pub mod path_finding;
+pub mod piece_set;
 pub mod polyomino;
 pub mod quarter_turns;
 pub mod rectangle;
+pub mod segment;
+#[cfg(any(test, feature = "serde"))]
+mod serde_newtype;
 pub mod shape;
+pub mod shape_error;
+#[cfg(any(test, feature = "std"))]
+pub mod sparse_tile_map;
 pub mod tile;
+pub mod tile16;
 pub mod tile_map;
+pub mod tile_map16;
 pub mod tile_set;
 #[cfg(any(test, feature = "u256"))]
 pub mod tile_set256;
+pub mod tile_set_for;
+pub mod tile_set_trait;
+pub mod tile_set_words;
+#[cfg(any(test, feature = "std"))]
+pub mod tile_slice;
+pub mod tracked_tile_map;
+pub mod transform;
 pub mod vector;
 pub mod vertex;
+pub mod vertex_map;
+pub mod vertex_set;
+pub mod voronoi;
+#[cfg(any(test, feature = "zobrist"))]
+pub mod zobrist;
 
 pub mod prelude {
+    #[cfg(any(test, feature = "std"))]
+    pub use crate::any_shape::*;
+    pub use crate::ascii_options::*;
+    pub use crate::board::*;
+    #[cfg(any(test, feature = "std"))]
+    pub use crate::chunked::*;
+    #[cfg(any(test, feature = "std"))]
+    pub use crate::contour::*;
     pub use crate::corner::*;
+    pub use crate::dihedral::*;
+    pub use crate::dimension_error::*;
+    pub use crate::direction::*;
+    pub use crate::display_adapter::*;
+    pub use crate::distance_metric::*;
+    #[cfg(any(test, feature = "std"))]
+    pub use crate::dynamic_polyomino::*;
     pub use crate::dynamic_tile::*;
     pub use crate::dynamic_vertex::*;
+    pub use crate::edge_policy::*;
     pub use crate::flip_axes::*;
     #[cfg(any(test, feature = "glam"))]
     pub use crate::has_center::*;
+    pub use crate::influence::*;
     pub use crate::line_finder::*;
     pub use crate::line_of_sight::*;
+    pub use crate::morton::*;
+    pub use crate::neighbourhood::*;
+    pub use crate::parse_coordinates_error::*;
+    pub use crate::parse_rle_error::*;
+    pub use crate::parse_shape_error::*;
+    pub use crate::piece_set::*;
     pub use crate::polyomino::*;
     pub use crate::quarter_turns::*;
     pub use crate::rectangle::*;
+    pub use crate::segment::*;
     pub use crate::shape::*;
+    pub use crate::shape_error::*;
+    #[cfg(any(test, feature = "std"))]
+    pub use crate::sparse_tile_map::*;
     pub use crate::tile::*;
+    pub use crate::tile16::*;
     pub use crate::tile_map::*;
+    pub use crate::tile_map16::*;
     pub use crate::tile_set::*;
     #[cfg(any(test, feature = "u256"))]
     pub use crate::tile_set256::*;
+    pub use crate::tile_set_for::*;
+    pub use crate::tile_set_trait::*;
+    pub use crate::tile_set_words::*;
+    #[cfg(any(test, feature = "std"))]
+    pub use crate::tile_slice::*;
+    pub use crate::tracked_tile_map::*;
+    pub use crate::transform::*;
     pub use crate::vector::*;
     pub use crate::vertex::*;
+    pub use crate::vertex_map::*;
+    pub use crate::vertex_set::*;
+    pub use crate::voronoi::*;
+    #[cfg(any(test, feature = "zobrist"))]
+    pub use crate::zobrist::*;
 }
@@ -9,26 +9,76 @@ impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileMap<T, WIDTH,
         directions: &'a [Vector],
         check_item: F,
         min_length: usize,
-    ) -> impl Iterator<Item = Line<'a, T, WIDTH, HEIGHT>> {
-        LineFinder {
-            grid: self,
+    ) -> LineFinder<'a, T, WIDTH, HEIGHT, SIZE, F> {
+        LineFinder::new(self, directions, check_item, min_length)
+    }
+}
+
+/// Iterates through lines in a grid which meet particular conditions.
+/// By default, every line is returned, including lines which are part of longer lines.
+#[derive(Clone, Debug)]
+pub struct LineFinder<
+    'a,
+    T,
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    const SIZE: usize,
+    F: Fn(&T) -> bool,
+> {
+    grid: &'a TileMap<T, WIDTH, HEIGHT, SIZE>,
+    directions: &'a [Vector],
+    check_item: F,
+    position: Tile<WIDTH, HEIGHT>,
+    direction_index: usize,
+    min_length: usize,
+    maximal_only: bool,
+    mask: Option<TileMap<bool, WIDTH, HEIGHT, SIZE>>,
+}
+
+impl<'a, T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize, F: Fn(&T) -> bool>
+    LineFinder<'a, T, WIDTH, HEIGHT, SIZE, F>
+{
+    pub const fn new(
+        grid: &'a TileMap<T, WIDTH, HEIGHT, SIZE>,
+        directions: &'a [Vector],
+        check_item: F,
+        min_length: usize,
+    ) -> Self {
+        Self {
+            grid,
             directions,
+            check_item,
             position: Tile::NORTH_WEST,
             direction_index: 0,
-            check_item,
             min_length,
+            maximal_only: false,
+            mask: None,
         }
     }
-}
 
-#[derive(Clone, Debug)]
-struct LineFinder<'a, T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize, F: Fn(&T) -> bool> {
-    pub grid: &'a TileMap<T, WIDTH, HEIGHT, SIZE>,
-    pub directions: &'a [Vector],
-    pub check_item: F,
-    pub position: Tile<WIDTH, HEIGHT>,
-    pub direction_index: usize,
-    pub min_length: usize,
+    /// Only return lines which cannot be extended backwards or forwards.
+    /// Lines which are only part of a longer line in the same direction will be skipped.
+    #[must_use]
+    pub const fn maximal_only(mut self) -> Self {
+        self.maximal_only = true;
+        self
+    }
+
+    /// Only search within the given tiles.
+    /// Lines will not extend outside of the mask.
+    #[must_use]
+    pub fn with_mask(mut self, mask: impl IntoIterator<Item = Tile<WIDTH, HEIGHT>>) -> Self {
+        let mut map = TileMap::default();
+        for tile in mask {
+            map[tile] = true;
+        }
+        self.mask = Some(map);
+        self
+    }
+
+    fn is_included(&self, tile: Tile<WIDTH, HEIGHT>) -> bool {
+        self.mask.as_ref().is_none_or(|mask| mask[tile]) && (self.check_item)(&self.grid[tile])
+    }
 }
 
 /// A line in a grid
@@ -66,10 +116,19 @@ impl<'a, T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize, F: Fn(&T) -> b
     fn next(&mut self) -> Option<Self::Item> {
         'items: loop {
             let item = &self.grid[self.position];
-            if (self.check_item)(item) {
+            if self.is_included(self.position) {
                 while self.direction_index < self.directions.len() {
                     let direction = self.directions[self.direction_index];
                     self.direction_index += 1;
+
+                    if self.maximal_only {
+                        if let Some(previous) = self.position + direction.const_neg() {
+                            if self.is_included(previous) {
+                                continue;
+                            }
+                        }
+                    }
+
                     let mut length = 1;
                     let mut current = self.position;
                     'len: loop {
@@ -77,9 +136,8 @@ impl<'a, T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize, F: Fn(&T) -> b
                             break 'len;
                         };
                         current = next;
-                        let item2 = &self.grid[next];
 
-                        if (self.check_item)(item2) {
+                        if self.is_included(next) {
                             length += 1;
                         } else {
                             break 'len;
@@ -115,7 +173,7 @@ mod tests {
 
     #[test]
     pub fn test_line_finder_none() {
-        let mut map: TileMap<bool, 4, 4, 16> = Default::default();
+        let mut map: TileMap<bool, 4, 4, 16> = TileMap::default();
 
         map[Tile::new_const::<0, 1>()] = true;
         map[Tile::new_const::<1, 1>()] = true;
@@ -131,7 +189,7 @@ mod tests {
 
     #[test]
     pub fn test_line_finder_4() {
-        let mut map: TileMap<bool, 4, 4, 16> = Default::default();
+        let mut map: TileMap<bool, 4, 4, 16> = TileMap::default();
         map[Tile::new_const::<0, 0>()] = true;
         map[Tile::new_const::<1, 1>()] = true;
         map[Tile::new_const::<2, 2>()] = true;
@@ -159,4 +217,38 @@ mod tests {
         assert_eq!(line.origin, Tile::new_const::<0, 0>());
         assert_eq!(line.direction, Vector::SOUTH_EAST);
     }
+
+    #[test]
+    pub fn test_line_finder_maximal() {
+        let map: TileMap<bool, 4, 4, 16> = TileMap::from_fn(|tile| tile.y() == 1);
+
+        let all_lines = map.get_lines(&[Vector::EAST], |x| *x, 2).collect_vec();
+        assert_eq!(all_lines.len(), 3);
+
+        let lines = map
+            .get_lines(&[Vector::EAST], |x| *x, 2)
+            .maximal_only()
+            .collect_vec();
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].origin, Tile::new_const::<0, 1>());
+        assert_eq!(lines[0].length, 4);
+    }
+
+    #[test]
+    pub fn test_line_finder_mask() {
+        let map: TileMap<bool, 4, 4, 16> = TileMap::from_fn(|tile| tile.y() == 1);
+        let mask = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() > 0);
+
+        let lines = LineFinder::new(&map, &[Vector::EAST, Vector::SOUTH], |x| *x, 2)
+            .with_mask(mask)
+            .maximal_only()
+            .collect_vec();
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].origin, Tile::new_const::<1, 1>());
+        assert_eq!(lines[0].direction, Vector::EAST);
+        assert_eq!(lines[0].length, 3);
+        assert_eq!(lines[0].positions().join("|"), "(1,1)|(2,1)|(3,1)");
+    }
 }
@@ -13,6 +13,66 @@ pub const fn iter_line_of_sight_tiles<const WIDTH: u8, const HEIGHT: u8>(
     }
 }
 
+/// Whether there is a line of sight between `from` and `to`, i.e. none of the tiles between them are in `blocked`.
+/// The `from` and `to` tiles themselves are not checked.
+/// Stops at the first blocked tile.
+pub fn has_line_of_sight<const WIDTH: u8, const HEIGHT: u8, S: TileSetTrait<WIDTH, HEIGHT>>(
+    from: &Tile<WIDTH, HEIGHT>,
+    to: &Tile<WIDTH, HEIGHT>,
+    blocked: &S,
+) -> bool {
+    iter_line_of_sight_tiles(from, to)
+        .all(|tile| tile == *from || tile == *to || !blocked.get_bit(&tile))
+}
+
+/// Iterates tiles in a line from `from` to `to`, in order of distance from `from`, stopping before the first tile in `blocked`.
+/// The `from` and `to` tiles themselves are never treated as blocked.
+/// Where the line passes diagonally between two tiles, both are returned, the horizontal neighbour first.
+pub fn line_of_sight_until<
+    'a,
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    S: TileSetTrait<WIDTH, HEIGHT> + 'a,
+>(
+    from: &Tile<WIDTH, HEIGHT>,
+    to: &Tile<WIDTH, HEIGHT>,
+    blocked: &'a S,
+) -> impl Iterator<Item = Tile<WIDTH, HEIGHT>> + 'a {
+    let (from, to) = (*from, *to);
+    let line: S = iter_line_of_sight_tiles(&from, &to).collect();
+    let horizontal = if from.x() < to.x() {
+        Vector::EAST
+    } else {
+        Vector::WEST
+    };
+    let vertical = if from.y() < to.y() {
+        Vector::SOUTH
+    } else {
+        Vector::NORTH
+    };
+
+    // The tiles of the line at the same distance from `from`, of which there are at most two
+    let mut layer = [Some(from), None];
+    core::iter::from_fn(move || {
+        let current @ [Some(_), _] = layer else {
+            return None;
+        };
+        layer = [None, None];
+        for tile in current.into_iter().flatten() {
+            for next in [tile + horizontal, tile + vertical].into_iter().flatten() {
+                if line.get_bit(&next) && !layer.contains(&Some(next)) {
+                    if let Some(slot) = layer.iter_mut().find(|slot| slot.is_none()) {
+                        *slot = Some(next);
+                    }
+                }
+            }
+        }
+        Some(current)
+    })
+    .flat_map(|layer| layer.into_iter().flatten())
+    .take_while(move |tile| *tile == from || *tile == to || !blocked.get_bit(tile))
+}
+
 #[derive(Clone, Debug)]
 /// Iterates all tiles in a line between `from` and `to` in some order
 struct LineOfSightTileIter<const WIDTH: u8, const HEIGHT: u8> {
@@ -109,12 +169,12 @@ mod tests {
 
     #[test]
     fn south_east_diagonal() {
-        test_line_of_sight(Tile25::NORTH_WEST, Tile25::SOUTH_EAST, "(0,0); (1,0); (0,1); (1,1); (2,1); (1,2); (2,2); (3,2); (2,3); (3,3); (4,3); (3,4); (4,4)")
+        test_line_of_sight(Tile25::NORTH_WEST, Tile25::SOUTH_EAST, "(0,0); (1,0); (0,1); (1,1); (2,1); (1,2); (2,2); (3,2); (2,3); (3,3); (4,3); (3,4); (4,4)");
     }
 
     #[test]
     fn north_west_diagonal() {
-        test_line_of_sight(Tile25::SOUTH_EAST, Tile25::NORTH_WEST, "(0,0); (1,0); (0,1); (1,1); (2,1); (1,2); (2,2); (3,2); (2,3); (3,3); (4,3); (3,4); (4,4)")
+        test_line_of_sight(Tile25::SOUTH_EAST, Tile25::NORTH_WEST, "(0,0); (1,0); (0,1); (1,1); (2,1); (1,2); (2,2); (3,2); (2,3); (3,3); (4,3); (3,4); (4,4)");
     }
 
     #[test]
@@ -123,7 +183,7 @@ mod tests {
             Tile25::new_const::<2, 4>(),
             Tile25::new_const::<2, 0>(),
             "(2,0); (2,1); (2,2); (2,3); (2,4)",
-        )
+        );
     }
 
     #[test]
@@ -132,7 +192,7 @@ mod tests {
             Tile25::new_const::<4, 2>(),
             Tile25::new_const::<0, 2>(),
             "(0,2); (1,2); (2,2); (3,2); (4,2)",
-        )
+        );
     }
 
     #[test]
@@ -141,7 +201,7 @@ mod tests {
             Tile25::new_const::<2, 0>(),
             Tile25::new_const::<3, 4>(),
             "(2,0); (2,1); (2,2); (3,2); (3,3); (3,4)",
-        )
+        );
     }
 
     #[test]
@@ -150,13 +210,62 @@ mod tests {
             Tile25::new_const::<2, 0>(),
             Tile25::new_const::<3, 3>(),
             "(2,0); (2,1); (3,1); (2,2); (3,2); (3,3)",
-        )
+        );
+    }
+
+    #[test]
+    fn test_has_line_of_sight() {
+        let from = Tile25::new_const::<2, 0>();
+        let to = Tile25::new_const::<3, 4>();
+        let mut blocked = TileSet32::<5, 5, 25>::EMPTY;
+        blocked.set_bit(&from, true);
+        blocked.set_bit(&to, true);
+        blocked.set_bit(&Tile25::new_const::<3, 1>(), true);
+
+        assert!(has_line_of_sight(&from, &to, &blocked));
+        assert_eq!(
+            line_of_sight_until(&from, &to, &blocked).join("; "),
+            "(2,0); (2,1); (2,2); (3,2); (3,3); (3,4)"
+        );
+
+        blocked.set_bit(&Tile25::new_const::<2, 2>(), true);
+        assert!(!has_line_of_sight(&from, &to, &blocked));
+        assert!(!has_line_of_sight(&to, &from, &blocked));
+        assert_eq!(
+            line_of_sight_until(&from, &to, &blocked).join("; "),
+            "(2,0); (2,1)"
+        );
+
+        blocked.set_bit(&Tile25::new_const::<2, 1>(), true);
+        assert_eq!(
+            line_of_sight_until(&from, &to, &blocked).collect_vec(),
+            vec![from]
+        );
+        assert_eq!(
+            line_of_sight_until(&to, &from, &blocked).join("; "),
+            "(3,4); (3,3); (3,2)"
+        );
+    }
+
+    #[test]
+    fn test_line_of_sight_until_diagonal() {
+        let blocked = TileSet32::<5, 5, 25>::EMPTY;
+        assert_eq!(
+            line_of_sight_until(&Tile25::NORTH_WEST, &Tile25::new_const::<2, 2>(), &blocked)
+                .join("; "),
+            "(0,0); (1,0); (0,1); (1,1); (2,1); (1,2); (2,2)"
+        );
+        assert_eq!(
+            line_of_sight_until(&Tile25::SOUTH_EAST, &Tile25::new_const::<4, 1>(), &blocked)
+                .join("; "),
+            "(4,4); (4,3); (4,2); (4,1)"
+        );
     }
 
     fn test_line_of_sight(from: Tile25, to: Tile25, expected: &str) {
         let mut actual = iter_line_of_sight_tiles(&from, &to).collect_vec();
         actual.sort();
 
-        assert_eq!(actual.into_iter().join("; "), expected,)
+        assert_eq!(actual.into_iter().join("; "), expected,);
     }
 }
@@ -0,0 +1,67 @@
+//! Second moments of area of shapes made of unit tiles
+
+/// Sums of the coordinates of a collection of tiles, from which its moments can be computed exactly
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct TileSums {
+    count: i64,
+    x: i64,
+    y: i64,
+    xx: i64,
+    yy: i64,
+    xy: i64,
+}
+
+impl TileSums {
+    pub(crate) fn new(tiles: impl IntoIterator<Item = (i64, i64)>) -> Self {
+        tiles
+            .into_iter()
+            .fold(Self::default(), |sums, (x, y)| Self {
+                count: sums.count + 1,
+                x: sums.x + x,
+                y: sums.y + y,
+                xx: sums.xx + x * x,
+                yy: sums.yy + y * y,
+                xy: sums.xy + x * y,
+            })
+    }
+
+    /// The moments about the centroid, multiplied by the number of tiles so that they are integers
+    /// (ignoring the moment of each tile about its own center)
+    const fn scaled_moments(&self) -> (i64, i64, i64) {
+        (
+            self.count * self.yy - self.y * self.y,
+            self.count * self.xx - self.x * self.x,
+            self.count * self.xy - self.x * self.y,
+        )
+    }
+
+    /// `(Ixx, Iyy, Ixy)` about the centroid, where `Ixx` is the integral of `(y - ȳ)²` over the area,
+    /// `Iyy` is the integral of `(x - x̄)²` and `Ixy` is the integral of `(x - x̄)(y - ȳ)`
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn second_moments(&self) -> (f32, f32, f32) {
+        if self.count == 0 {
+            return (0.0, 0.0, 0.0);
+        }
+        let (xx, yy, xy) = self.scaled_moments();
+        let count = self.count as f32;
+        // Each unit tile also has a moment of 1/12 about its own center
+        let own = count / 12.0;
+        (
+            xx as f32 / count + own,
+            yy as f32 / count + own,
+            xy as f32 / count,
+        )
+    }
+
+    /// The angle in radians of the axis along which the tiles are most spread out.
+    /// `None` if every axis through the centroid has the same moment, as for a square.
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn principal_axis_angle(&self) -> Option<f32> {
+        let (xx, yy, xy) = self.scaled_moments();
+        if xx == yy && xy == 0 {
+            return None;
+        }
+        Some(0.5 * crate::float_math::atan2((2 * xy) as f32, (yy - xx) as f32))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::prelude::*;
+
+/// The knight moves, clockwise from north-north-east
+const KNIGHT_MOVES: [Vector; 8] = [
+    Vector::new(1, -2),
+    Vector::new(2, -1),
+    Vector::new(2, 1),
+    Vector::new(1, 2),
+    Vector::new(-1, 2),
+    Vector::new(-2, 1),
+    Vector::new(-2, -1),
+    Vector::new(-1, -2),
+];
+
+/// The offsets from a tile to the tiles which count as its neighbours.
+/// Use one of the constants, or `new` for a custom neighbourhood.
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Neighbourhood<'a>(&'a [Vector]);
+
+impl<'a> Neighbourhood<'a> {
+    /// The four orthogonal neighbours, clockwise from north. Matches `Vector::CARDINALS`
+    pub const VON_NEUMANN: Neighbourhood<'static> = Neighbourhood(&Vector::CARDINALS);
+
+    /// The eight orthogonal and diagonal neighbours, clockwise from north. Matches `Vector::UNITS`
+    pub const MOORE: Neighbourhood<'static> = Neighbourhood(&Vector::UNITS);
+
+    /// The eight tiles a chess knight can move to, clockwise from north-north-east
+    pub const KNIGHT: Neighbourhood<'static> = Neighbourhood(&KNIGHT_MOVES);
+
+    /// A custom neighbourhood with the given offsets
+    pub const fn new(offsets: &'a [Vector]) -> Self {
+        Self(offsets)
+    }
+
+    /// The offsets to the neighbours, in order
+    pub const fn offsets(&self) -> &'a [Vector] {
+        self.0
+    }
+
+    /// The number of neighbours a tile has when none of them are off the grid
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether `offset` is one of the offsets of this neighbourhood
+    #[must_use]
+    pub const fn contains(&self, offset: Vector) -> bool {
+        let mut index = 0;
+        while index < self.0.len() {
+            if self.0[index].const_eq(offset) {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+}
+
+impl<'a> From<&'a [Vector]> for Neighbourhood<'a> {
+    fn from(value: &'a [Vector]) -> Self {
+        Self(value)
+    }
+}
+
+impl<'a, const N: usize> From<&'a [Vector; N]> for Neighbourhood<'a> {
+    fn from(value: &'a [Vector; N]) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Neighbourhood::VON_NEUMANN.len(), 4);
+        assert_eq!(Neighbourhood::MOORE.len(), 8);
+        assert_eq!(Neighbourhood::KNIGHT.offsets().iter().unique().count(), 8);
+        assert!(Neighbourhood::KNIGHT
+            .offsets()
+            .iter()
+            .all(|v| v.x.abs() + v.y.abs() == 3 && v.x != 0 && v.y != 0));
+        assert!(Neighbourhood::MOORE.contains(Vector::SOUTH_WEST));
+        assert!(!Neighbourhood::VON_NEUMANN.contains(Vector::SOUTH_WEST));
+        assert!(Neighbourhood::new(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_iter_neighbours() {
+        let tile: Tile<5, 5> = Tile::new_const::<1, 0>();
+        assert_eq!(
+            tile.iter_neighbours(Neighbourhood::KNIGHT).join("|"),
+            "(3,1)|(2,2)|(0,2)"
+        );
+        assert!(tile
+            .iter_neighbours(Neighbourhood::MOORE)
+            .eq(tile.iter_adjacent()));
+
+        let custom = [Vector::EAST, Vector::new(0, 4)];
+        assert_eq!(
+            tile.iter_neighbours(Neighbourhood::from(&custom)).join("|"),
+            "(2,0)|(1,4)"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use rayon::prelude::*;
+
+use crate::prelude::*;
+
+impl<T: Sync, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Iterate through the values in parallel.
+    /// Requires `rayon`
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        self.as_ref().par_iter()
+    }
+
+    /// Iterate through the tiles and values in parallel.
+    /// Requires `rayon`
+    #[allow(clippy::missing_panics_doc)]
+    pub fn par_enumerate(
+        &self,
+    ) -> impl IndexedParallelIterator<Item = (Tile<WIDTH, HEIGHT>, &'_ T)> {
+        self.as_ref()
+            .par_iter()
+            .enumerate()
+            .map(|(inner, x)| (Tile::try_from_usize(inner).unwrap(), x))
+    }
+}
+
+impl<T: Send, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Mutably iterate through the values in parallel.
+    /// Requires `rayon`
+    pub fn par_iter_mut(&mut self) -> rayon::slice::IterMut<'_, T> {
+        self.as_mut().par_iter_mut()
+    }
+}
+
+macro_rules! par_tile_set {
+    ($name:ident) => {
+        impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> $name<WIDTH, HEIGHT, SIZE> {
+            /// Iterate through the tiles which are present in the set in parallel.
+            /// Requires `rayon`
+            pub fn par_iter_true_tiles(&self) -> impl ParallelIterator<Item = Tile<WIDTH, HEIGHT>> {
+                let set = *self;
+                (0..SIZE)
+                    .into_par_iter()
+                    .filter_map(Tile::try_from_usize)
+                    .filter(move |tile| set.get_bit(tile))
+            }
+        }
+    };
+}
+
+par_tile_set!(TileSet8);
+par_tile_set!(TileSet16);
+par_tile_set!(TileSet32);
+par_tile_set!(TileSet64);
+par_tile_set!(TileSet128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_iter() {
+        let mut map: TileMap<usize, 3, 3, 9> = TileMap::from_fn(usize::from);
+
+        assert_eq!(map.par_iter().sum::<usize>(), 36);
+
+        map.par_iter_mut().for_each(|x| *x *= 2);
+        assert_eq!(map.to_string(), "0|2|4\n6|8|10\n12|14|16");
+
+        let doubled_indices: Vec<_> = map
+            .par_enumerate()
+            .map(|(tile, x)| *x == usize::from(tile) * 2)
+            .collect();
+        assert!(doubled_indices.into_iter().all(|x| x));
+    }
+
+    #[test]
+    fn test_par_iter_true_tiles() {
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() == tile.y());
+
+        let mut tiles: Vec<_> = set.par_iter_true_tiles().collect();
+        tiles.sort();
+
+        assert_eq!(tiles, set.iter_true_tiles().collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,81 @@
+use core::{fmt, str::FromStr};
+
+/// An error from parsing a tile, vertex or vector from a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseCoordinatesError {
+    /// The string was not of the form `(x,y)` or `x,y`
+    InvalidFormat,
+    /// One of the coordinates was not a valid number
+    InvalidNumber,
+    /// The coordinates were outside the grid
+    OutOfBounds,
+}
+
+impl ParseCoordinatesError {
+    /// A short description of the error, usable in const contexts
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            ParseCoordinatesError::InvalidFormat => "Expected coordinates of the form (x,y)",
+            ParseCoordinatesError::InvalidNumber => "Invalid coordinate",
+            ParseCoordinatesError::OutOfBounds => "Coordinates are outside the grid",
+        }
+    }
+}
+
+impl fmt::Display for ParseCoordinatesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for ParseCoordinatesError {}
+
+/// Parse a pair of coordinates of the form `(x,y)` or `x,y`, ignoring whitespace
+pub(crate) fn parse_coordinates<T: FromStr>(s: &str) -> Result<(T, T), ParseCoordinatesError> {
+    let s = s.trim();
+    let inner = match (s.strip_prefix('('), s.strip_suffix(')')) {
+        (Some(_), Some(_)) => &s[1..s.len() - 1],
+        (None, None) => s,
+        _ => return Err(ParseCoordinatesError::InvalidFormat),
+    };
+    let (x, y) = inner
+        .split_once(',')
+        .ok_or(ParseCoordinatesError::InvalidFormat)?;
+    Ok((parse_coordinate(x)?, parse_coordinate(y)?))
+}
+
+/// Parse a single coordinate, ignoring whitespace
+pub(crate) fn parse_coordinate<T: FromStr>(s: &str) -> Result<T, ParseCoordinatesError> {
+    s.trim()
+        .parse::<T>()
+        .map_err(|_| ParseCoordinatesError::InvalidNumber)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_coordinates() {
+        assert_eq!(parse_coordinates::<u8>("(1,2)"), Ok((1, 2)));
+        assert_eq!(parse_coordinates::<i8>(" -3, 4 "), Ok((-3, 4)));
+        assert_eq!(parse_coordinates::<i8>("( 5 , -6 )"), Ok((5, -6)));
+        assert_eq!(
+            parse_coordinates::<u8>("(1,2"),
+            Err(ParseCoordinatesError::InvalidFormat)
+        );
+        assert_eq!(
+            parse_coordinates::<u8>("12"),
+            Err(ParseCoordinatesError::InvalidFormat)
+        );
+        assert_eq!(
+            parse_coordinates::<u8>("(1,-2)"),
+            Err(ParseCoordinatesError::InvalidNumber)
+        );
+        assert_eq!(
+            ParseCoordinatesError::OutOfBounds.to_string(),
+            "Coordinates are outside the grid"
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use core::fmt;
+
+/// An error from parsing a run-length encoded tile set or tile map
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseRleError {
+    /// The length of a run was not a valid number, or was zero where that is not allowed
+    InvalidRunLength { run: usize },
+    /// A run of a tile map was not of the form `count*value`
+    MissingSeparator { run: usize },
+    /// The value of a run of a tile map could not be parsed
+    InvalidValue { run: usize },
+    /// The runs contained more tiles than the grid
+    TooManyTiles,
+    /// The runs contained fewer tiles than the grid
+    NotEnoughTiles { expected: usize, found: usize },
+}
+
+impl ParseRleError {
+    /// A short description of the error, usable in const contexts
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            ParseRleError::InvalidRunLength { .. } => "Invalid run length",
+            ParseRleError::MissingSeparator { .. } => "Missing '*'",
+            ParseRleError::InvalidValue { .. } => "Invalid value",
+            ParseRleError::TooManyTiles => "Too many tiles",
+            ParseRleError::NotEnoughTiles { .. } => "Not enough tiles",
+        }
+    }
+}
+
+impl fmt::Display for ParseRleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRleError::InvalidRunLength { run }
+            | ParseRleError::MissingSeparator { run }
+            | ParseRleError::InvalidValue { run } => write!(f, "{} at run {run}", self.message()),
+            ParseRleError::TooManyTiles => f.write_str(self.message()),
+            ParseRleError::NotEnoughTiles { expected, found } => {
+                write!(f, "{}: expected {expected}, found {found}", self.message())
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseRleError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseRleError::InvalidRunLength { run: 2 }.to_string(),
+            "Invalid run length at run 2"
+        );
+        assert_eq!(
+            ParseRleError::MissingSeparator { run: 0 }.to_string(),
+            "Missing '*' at run 0"
+        );
+        assert_eq!(ParseRleError::TooManyTiles.to_string(), "Too many tiles");
+        assert_eq!(
+            ParseRleError::NotEnoughTiles {
+                expected: 16,
+                found: 8
+            }
+            .to_string(),
+            "Not enough tiles: expected 16, found 8"
+        );
+    }
+}
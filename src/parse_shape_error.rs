@@ -0,0 +1,77 @@
+use core::fmt;
+
+/// An error from parsing a shape from a string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseShapeError {
+    /// The string contained more tiles than the shape can hold
+    TooManyTiles,
+    /// The string contained fewer tiles than the shape requires
+    NotEnoughTiles { expected: usize, found: usize },
+    /// The string contained a character which does not represent a tile or an empty space
+    UnexpectedCharacter { byte: u8, index: usize },
+    /// The string contained a tile or an empty space outside the grid
+    OutOfBounds { x: usize, y: usize },
+}
+
+impl ParseShapeError {
+    /// A short description of the error, usable in const contexts
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            ParseShapeError::TooManyTiles => "Too many tiles",
+            ParseShapeError::NotEnoughTiles { .. } => "Not enough tiles",
+            ParseShapeError::UnexpectedCharacter { .. } => "Unexpected character",
+            ParseShapeError::OutOfBounds { .. } => "Outside the grid",
+        }
+    }
+}
+
+impl fmt::Display for ParseShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseShapeError::TooManyTiles => f.write_str(self.message()),
+            ParseShapeError::NotEnoughTiles { expected, found } => {
+                write!(f, "{}: expected {expected}, found {found}", self.message())
+            }
+            ParseShapeError::UnexpectedCharacter { byte, index } => write!(
+                f,
+                "{} '{}' at index {index}",
+                self.message(),
+                byte.escape_ascii()
+            ),
+            ParseShapeError::OutOfBounds { x, y } => write!(f, "{} at ({x},{y})", self.message()),
+        }
+    }
+}
+
+impl core::error::Error for ParseShapeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(ParseShapeError::TooManyTiles.to_string(), "Too many tiles");
+        assert_eq!(
+            ParseShapeError::NotEnoughTiles {
+                expected: 4,
+                found: 2
+            }
+            .to_string(),
+            "Not enough tiles: expected 4, found 2"
+        );
+        assert_eq!(
+            ParseShapeError::UnexpectedCharacter {
+                byte: b'x',
+                index: 3
+            }
+            .to_string(),
+            "Unexpected character 'x' at index 3"
+        );
+        assert_eq!(
+            ParseShapeError::OutOfBounds { x: 4, y: 1 }.to_string(),
+            "Outside the grid at (4,1)"
+        );
+    }
+}
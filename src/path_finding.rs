@@ -0,0 +1,172 @@
+use tinyvec::ArrayVec;
+
+use crate::prelude::*;
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileMap<T, WIDTH, HEIGHT, SIZE> {
+    /// Find the minimum total cost to reach every tile from `start`, moving orthogonally.
+    /// `passable` returns the cost of entering a tile, or `None` if the tile cannot be entered.
+    /// The start tile always has a cost of zero.
+    /// Tiles which cannot be reached are `None`.
+    pub fn dijkstra(
+        &self,
+        start: Tile<WIDTH, HEIGHT>,
+        passable: impl Fn(&T) -> Option<u32>,
+    ) -> TileMap<Option<u32>, WIDTH, HEIGHT, SIZE> {
+        self.dijkstra_with(start, Neighbourhood::VON_NEUMANN, passable)
+    }
+
+    /// Like `dijkstra`, but moving to the tiles in `neighbourhood` instead of orthogonally
+    pub fn dijkstra_with(
+        &self,
+        start: Tile<WIDTH, HEIGHT>,
+        neighbourhood: Neighbourhood<'_>,
+        passable: impl Fn(&T) -> Option<u32>,
+    ) -> TileMap<Option<u32>, WIDTH, HEIGHT, SIZE> {
+        let mut distances: TileMap<Option<u32>, WIDTH, HEIGHT, SIZE> = TileMap::default();
+        let mut visited = [false; SIZE];
+        distances[start] = Some(0);
+
+        loop {
+            let mut current: Option<(Tile<WIDTH, HEIGHT>, u32)> = None;
+            for (tile, distance) in distances.enumerate() {
+                if let Some(distance) = *distance {
+                    if !visited[usize::from(tile)]
+                        && current.is_none_or(|(_, best)| distance < best)
+                    {
+                        current = Some((tile, distance));
+                    }
+                }
+            }
+
+            let Some((tile, distance)) = current else {
+                break;
+            };
+            visited[usize::from(tile)] = true;
+
+            for neighbour in tile.iter_neighbours(neighbourhood) {
+                if visited[usize::from(neighbour)] {
+                    continue;
+                }
+                let Some(cost) = passable(&self[neighbour]) else {
+                    continue;
+                };
+                let new_distance = distance.saturating_add(cost);
+                if distances[neighbour].is_none_or(|d| new_distance < d) {
+                    distances[neighbour] = Some(new_distance);
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<Option<u32>, WIDTH, HEIGHT, SIZE>
+{
+    /// Reconstruct the cheapest path to `goal` from a map of distances produced by `dijkstra`.
+    /// The path starts at the tile with distance zero and ends at `goal`.
+    /// Returns `None` if the goal is unreachable.
+    /// Entry costs must be positive for the path to be reconstructed correctly.
+    #[must_use]
+    pub fn shortest_path_to(
+        &self,
+        goal: Tile<WIDTH, HEIGHT>,
+    ) -> Option<impl DoubleEndedIterator<Item = Tile<WIDTH, HEIGHT>> + ExactSizeIterator> {
+        self.shortest_path_to_with(goal, Neighbourhood::VON_NEUMANN)
+    }
+
+    /// Like `shortest_path_to`, for distances produced by `dijkstra_with` using the same `neighbourhood`
+    #[must_use]
+    pub fn shortest_path_to_with(
+        &self,
+        goal: Tile<WIDTH, HEIGHT>,
+        neighbourhood: Neighbourhood<'_>,
+    ) -> Option<impl DoubleEndedIterator<Item = Tile<WIDTH, HEIGHT>> + ExactSizeIterator> {
+        let mut distance = self[goal]?;
+        let mut path: ArrayVec<[Tile<WIDTH, HEIGHT>; SIZE]> = ArrayVec::new();
+        let mut current = goal;
+        path.push(current);
+
+        while distance > 0 {
+            let (previous, previous_distance) = current
+                .iter_neighbours(neighbourhood)
+                .filter_map(|tile| self[tile].map(|d| (tile, d)))
+                .min_by_key(|(_, d)| *d)?;
+            if previous_distance >= distance {
+                return None;
+            }
+            distance = previous_distance;
+            current = previous;
+            path.push(current);
+        }
+
+        path.reverse();
+        Some(path.into_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_dijkstra() {
+        let costs: TileMap<u32, 3, 3, 9> = TileMap::from_inner([1, 9, 1, 1, 9, 1, 1, 1, 1]);
+
+        let distances = costs.dijkstra(Tile::NORTH_WEST, |x| Some(*x));
+
+        assert_eq!(
+            distances.into_iter().map(Option::unwrap).join("|"),
+            "0|9|6|1|10|5|2|3|4"
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_impassable() {
+        let walls: TileMap<bool, 3, 3, 9> =
+            TileMap::from_inner([false, true, false, false, true, false, false, true, false]);
+
+        let distances = walls.dijkstra(Tile::NORTH_WEST, |wall| (!wall).then_some(1));
+
+        assert_eq!(
+            distances
+                .into_iter()
+                .map(|d| d.map_or("_".to_string(), |d| d.to_string()))
+                .join("|"),
+            "0|_|_|1|_|_|2|_|_"
+        );
+    }
+
+    #[test]
+    fn test_shortest_path_to() {
+        let costs: TileMap<u32, 3, 3, 9> = TileMap::from_inner([1, 9, 1, 1, 9, 1, 1, 1, 1]);
+
+        let distances = costs.dijkstra(Tile::NORTH_WEST, |x| Some(*x));
+
+        let mut path = distances.shortest_path_to(Tile::NORTH_EAST).unwrap();
+
+        assert_eq!(path.join(";"), "(0,0);(0,1);(0,2);(1,2);(2,2);(2,1);(2,0)");
+
+        let distances = costs.dijkstra(Tile::NORTH_WEST, |x| (*x == 1).then_some(1));
+        assert!(distances.shortest_path_to(Tile::CENTER).is_none());
+        assert_eq!(
+            distances.shortest_path_to(Tile::NORTH_WEST).unwrap().len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_dijkstra_with_diagonals() {
+        let costs: TileMap<u32, 3, 3, 9> = TileMap::from_inner([1, 9, 1, 1, 9, 1, 1, 1, 1]);
+
+        let distances = costs.dijkstra_with(Tile::NORTH_WEST, Neighbourhood::MOORE, |x| Some(*x));
+        assert_eq!(distances[Tile::NORTH_EAST], Some(4));
+
+        let mut path = distances
+            .shortest_path_to_with(Tile::NORTH_EAST, Neighbourhood::MOORE)
+            .unwrap();
+        assert_eq!(path.join(";"), "(0,0);(0,1);(1,2);(2,1);(2,0)");
+    }
+}
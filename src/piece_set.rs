@@ -0,0 +1,221 @@
+use crate::prelude::*;
+
+/// A set of named polyominos, such as the pieces of a puzzle.
+/// Keeps each piece together with its name, so that the two cannot get out of step.
+#[must_use]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PieceSet<const P: usize, const N: usize> {
+    pieces: [Polyomino<P>; N],
+    names: [&'static str; N],
+}
+
+impl PieceSet<4, 7> {
+    /// The seven one-sided tetrominos, where mirror images are distinct
+    pub const TETROMINOS: Self = Self::new(Polyomino::TETROMINOS, Polyomino::TETROMINO_NAMES);
+}
+
+impl PieceSet<4, 5> {
+    /// The five free tetrominos, where mirror images are the same piece
+    pub const FREE_TETROMINOS: Self =
+        Self::new(Polyomino::FREE_TETROMINOS, Polyomino::FREE_TETROMINO_NAMES);
+}
+
+impl PieceSet<5, 12> {
+    /// The twelve free pentominos, where mirror images are the same piece
+    pub const FREE_PENTOMINOS: Self =
+        Self::new(Polyomino::FREE_PENTOMINOS, Polyomino::FREE_PENTOMINO_NAMES);
+}
+
+impl PieceSet<5, 18> {
+    /// The eighteen one-sided pentominos, where mirror images are distinct
+    pub const ALL_PENTOMINOS: Self =
+        Self::new(Polyomino::ALL_PENTOMINOS, Polyomino::ALL_PENTOMINO_NAMES);
+}
+
+impl<const P: usize, const N: usize> PieceSet<P, N> {
+    /// Create a set from its pieces and their names, in the same order
+    pub const fn new(pieces: [Polyomino<P>; N], names: [&'static str; N]) -> Self {
+        Self { pieces, names }
+    }
+
+    #[must_use]
+    pub const fn pieces(&self) -> &[Polyomino<P>; N] {
+        &self.pieces
+    }
+
+    #[must_use]
+    pub const fn names(&self) -> &[&'static str; N] {
+        &self.names
+    }
+
+    /// The number of pieces in the set
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Iterate through the names and pieces, in order
+    #[must_use]
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (&'static str, Polyomino<P>)> + DoubleEndedIterator + Clone
+    {
+        self.names.into_iter().zip(self.pieces)
+    }
+
+    /// The index of the piece with the given name
+    #[must_use]
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| *n == name)
+    }
+
+    /// The piece with the given name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Polyomino<P>> {
+        self.position(name).map(|index| self.pieces[index])
+    }
+
+    /// The name of `piece`, if it is exactly one of the pieces in this set.
+    /// Use `find_congruent` to also match rotated and mirrored pieces.
+    #[must_use]
+    pub fn name_of(&self, piece: &Polyomino<P>) -> Option<&'static str> {
+        self.pieces
+            .iter()
+            .position(|p| p == piece)
+            .map(|index| self.names[index])
+    }
+
+    /// The index of the first piece which is congruent to `piece`, allowing rotation and mirroring
+    #[must_use]
+    pub fn find_congruent(&self, piece: &Polyomino<P>) -> Option<usize> {
+        self.pieces.iter().position(|p| p.is_congruent_to(piece))
+    }
+
+    /// Whether any piece is congruent to `piece`, allowing rotation and mirroring
+    #[must_use]
+    pub fn contains_congruent(&self, piece: &Polyomino<P>) -> bool {
+        self.find_congruent(piece).is_some()
+    }
+
+    /// The set without the pieces which are congruent to an earlier piece.
+    /// The first piece of each kind is kept, with its name.
+    /// # Errors
+    /// If `M` is not the number of pieces which remain
+    pub fn dedup_free<const M: usize>(&self) -> Result<PieceSet<P, M>, DimensionError> {
+        let mut kept = [0; N];
+        let mut count = 0;
+        for (index, piece) in self.pieces.iter().enumerate() {
+            if !kept[..count]
+                .iter()
+                .any(|k| self.pieces[*k].is_congruent_to(piece))
+            {
+                kept[count] = index;
+                count += 1;
+            }
+        }
+
+        DimensionError::check(M, count, N)?;
+        Ok(PieceSet {
+            pieces: core::array::from_fn(|i| self.pieces[kept[i]]),
+            names: core::array::from_fn(|i| self.names[kept[i]]),
+        })
+    }
+
+    /// The set with its pieces in a random order, keeping their names.
+    /// Requires `rand`
+    #[cfg(any(test, feature = "rand"))]
+    pub fn shuffled<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self {
+        use rand::seq::SliceRandom;
+
+        let mut order: [usize; N] = core::array::from_fn(|i| i);
+        order.shuffle(rng);
+        Self {
+            pieces: order.map(|i| self.pieces[i]),
+            names: order.map(|i| self.names[i]),
+        }
+    }
+
+    /// An endless sequence of bags, where each bag contains every piece once in a random order.
+    /// Requires `rand`
+    #[cfg(any(test, feature = "rand"))]
+    pub fn iter_bags<R: rand::Rng>(
+        self,
+        mut rng: R,
+    ) -> impl Iterator<Item = (&'static str, Polyomino<P>)> {
+        core::iter::repeat_with(move || self.shuffled(&mut rng)).flat_map(|bag| bag.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_names() {
+        let set = PieceSet::FREE_PENTOMINOS;
+        assert_eq!(set.len(), 12);
+        assert_eq!(set.get("X"), Some(Polyomino::X_PENTOMINO));
+        assert_eq!(set.get("Q"), None);
+        assert_eq!(set.name_of(&Polyomino::X_PENTOMINO), Some("X"));
+        assert_eq!(set.position(set.names()[3]), Some(3));
+        assert!(set.iter().all(|(name, piece)| set.get(name) == Some(piece)));
+        assert!(PieceSet::ALL_PENTOMINOS.names().iter().all_unique());
+    }
+
+    #[test]
+    fn test_congruent() {
+        let set = PieceSet::FREE_TETROMINOS;
+        let z = Polyomino::Z_TETROMINO;
+        assert_eq!(set.name_of(&z), None);
+        assert_eq!(set.find_congruent(&z).map(|i| set.names()[i]), Some("S"));
+        assert!(set.contains_congruent(&Rotate::rotate(&z, QuarterTurns::One)));
+        assert!(!PieceSet::new([Polyomino::I_TETROMINO], ["I"]).contains_congruent(&z));
+    }
+
+    #[test]
+    fn test_dedup_free() {
+        assert_eq!(
+            PieceSet::ALL_PENTOMINOS.dedup_free::<12>(),
+            Ok(PieceSet::FREE_PENTOMINOS)
+        );
+        assert_eq!(
+            PieceSet::TETROMINOS
+                .dedup_free::<5>()
+                .map(|set| set.names().join("")),
+            Ok("IOTJS".to_string())
+        );
+        assert_eq!(
+            PieceSet::TETROMINOS.dedup_free::<7>(),
+            Err(DimensionError::SizeMismatch {
+                expected: 7,
+                found: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_bags() {
+        let rng = rand::rngs::StdRng::seed_from_u64(7);
+        let set = PieceSet::TETROMINOS;
+
+        let pieces = set.iter_bags(rng).take(70).collect_vec();
+        for bag in pieces.chunks(7) {
+            assert_eq!(bag.iter().map(|x| x.0).sorted().join(""), "IJLOSTZ");
+            assert!(bag
+                .iter()
+                .all(|(name, piece)| set.get(name) == Some(*piece)));
+        }
+        assert_ne!(
+            pieces.iter().map(|x| x.0).join(""),
+            "IOTJLSZ".repeat(10),
+            "Bags should be shuffled"
+        );
+    }
+}
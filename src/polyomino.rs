@@ -1,3 +1,5 @@
+use crate::ascii_options::AsciiGlyph;
+use crate::moments::TileSums;
 use crate::prelude::*;
 use itertools::Itertools;
 
@@ -8,7 +10,7 @@ use tinyvec::ArrayVec;
 type V = Vector;
 
 /// A polyomino with a fixed number of points
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
 pub struct Polyomino<const TILES: usize>(
     #[cfg_attr(any(test, feature = "serde"), serde(with = "serde_arrays"))] [DynamicTile; TILES],
@@ -30,6 +32,11 @@ impl<const P: usize> Shape for Polyomino<P> {
     fn deconstruct_into_rectangles(&self) -> Self::RectangleIter {
         (*self).into()
     }
+
+    #[cfg(any(test, feature = "glam"))]
+    fn contains_point(&self, point: glam::f32::Vec2, scale: f32) -> bool {
+        contains_point(&self.0, point, scale)
+    }
 }
 
 #[cfg(any(test, feature = "glam"))]
@@ -51,6 +58,49 @@ impl<const P: usize> HasCenter for Polyomino<P> {
     }
 }
 
+impl<const P: usize> Flip for Polyomino<P> {
+    /// Flip the polyomino, normalizing the result
+    fn flip(&self, axes: FlipAxes) -> Self {
+        Self::new(self.0.map(|tile| tile.0.flip(axes)))
+    }
+}
+
+impl<const P: usize> Rotate for Polyomino<P> {
+    /// Rotate the polyomino, normalizing the result
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        Self::new(self.0.map(|tile| tile.0.rotate(quarter_turns)))
+    }
+}
+
+impl<const P: usize> TryFrom<Rectangle> for Polyomino<P> {
+    type Error = DimensionError;
+
+    /// Create a polyomino with the shape of the rectangle.
+    /// Fails if the area of the rectangle is not `P`, or either side is longer than `i8::MAX`
+    fn try_from(rectangle: Rectangle) -> Result<Self, Self::Error> {
+        DimensionError::check(P, rectangle.area(), P)?;
+        let (Ok(width), Ok(height)) = (
+            i8::try_from(rectangle.width),
+            i8::try_from(rectangle.height),
+        ) else {
+            return Err(DimensionError::TooLarge {
+                size: usize::from(rectangle.width.max(rectangle.height)),
+                max: i8::MAX as usize,
+            });
+        };
+        let mut arr = [V::ZERO; P];
+        let mut index = 0;
+        for y in 0..height {
+            for x in 0..width {
+                arr[index] = Vector::new(x, y);
+                index += 1;
+            }
+        }
+
+        Ok(Self::new(arr))
+    }
+}
+
 impl<const P: usize> IntoIterator for Polyomino<P> {
     type Item = DynamicTile;
 
@@ -61,6 +111,111 @@ impl<const P: usize> IntoIterator for Polyomino<P> {
     }
 }
 
+impl<const P: usize> Polyomino<P> {
+    /// Write the polyomino as ascii, writing `separator` between rows
+    fn write_ascii(&self, f: &mut core::fmt::Formatter<'_>, separator: char) -> core::fmt::Result {
+        use core::fmt::Write;
+        let Some((min_x, max_x)) = self.0.iter().map(|t| t.x).minmax().into_option() else {
+            return Ok(());
+        };
+        let Some((min_y, max_y)) = self.0.iter().map(|t| t.y).minmax().into_option() else {
+            return Ok(());
+        };
+
+        for y in min_y..=max_y {
+            if y > min_y {
+                f.write_char(separator)?;
+            }
+            for x in min_x..=max_x {
+                let c = if self.0.contains(&DynamicTile(Vector::new(x, y))) {
+                    Self::ASCII_TILE
+                } else {
+                    Self::ASCII_SPACE
+                };
+                f.write_char(char::from(c))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<const P: usize> core::fmt::Display for Polyomino<P> {
+    /// Writes the polyomino as ascii.
+    /// Tiles are represented by `#`. Empty tiles by `.`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_ascii(f, '\n')
+    }
+}
+
+impl<const P: usize> core::fmt::Debug for Polyomino<P> {
+    /// Writes the polyomino as ascii, with rows separated by `/`.
+    /// The alternate form puts each row on its own line.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            f.write_str("Polyomino(\n")?;
+            self.write_ascii(f, '\n')?;
+            f.write_str("\n)")
+        } else {
+            f.write_str("Polyomino(")?;
+            self.write_ascii(f, '/')?;
+            f.write_str(")")
+        }
+    }
+}
+
+impl<const P: usize> core::str::FromStr for Polyomino<P> {
+    type Err = ParseShapeError;
+
+    /// Parses ascii in the same format as `try_new_from_ascii`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_new_from_ascii(s)
+    }
+}
+
+/// Serialize and deserialize polyominos as ascii strings.
+/// Use with `#[serde(with = "geometrid::polyomino::serde_ascii")]`
+#[cfg(any(test, feature = "serde"))]
+pub mod serde_ascii {
+    use super::Polyomino;
+    use core::fmt;
+    use serde::{de, Deserializer, Serializer};
+
+    /// Serialize a polyomino as an ascii string
+    ///
+    /// # Errors
+    /// If the serializer fails
+    pub fn serialize<S: Serializer, const P: usize>(
+        polyomino: &Polyomino<P>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(polyomino)
+    }
+
+    /// Deserialize a polyomino from an ascii string
+    ///
+    /// # Errors
+    /// If the string is not a valid polyomino with the correct number of tiles
+    pub fn deserialize<'de, D: Deserializer<'de>, const P: usize>(
+        deserializer: D,
+    ) -> Result<Polyomino<P>, D::Error> {
+        deserializer.deserialize_str(AsciiVisitor::<P>)
+    }
+
+    struct AsciiVisitor<const P: usize>;
+
+    impl<const P: usize> de::Visitor<'_> for AsciiVisitor<P> {
+        type Value = Polyomino<P>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an ascii polyomino with {P} tiles")
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            Polyomino::try_new_from_ascii(v).map_err(E::custom)
+        }
+    }
+}
+
 const fn sort_vectors<const N: usize>(mut arr: [Vector; N]) -> [Vector; N] {
     let mut i = 1;
     while i < N {
@@ -126,7 +281,7 @@ impl<const T: usize> Polyomino<T> {
     const fn new_from_ascii(s: &str) -> Self {
         match Self::try_new_from_ascii(s) {
             Ok(p) => p,
-            Err(e) => const_panic::concat_panic!(e),
+            Err(e) => const_panic::concat_panic!(e.message()),
         }
     }
 
@@ -137,7 +292,19 @@ impl<const T: usize> Polyomino<T> {
     ///
     /// # Errors
     /// If there are too many tiles, too few tiles, or unexpected characters.
-    pub const fn try_new_from_ascii(s: &str) -> Result<Self, &'static str> {
+    pub const fn try_new_from_ascii(s: &str) -> Result<Self, ParseShapeError> {
+        Self::try_new_from_ascii_with(s, AsciiOptions::DEFAULT)
+    }
+
+    /// Construct a polyomino from a string of ascii, using `options` to interpret the characters.
+    /// Use `AsciiOptions::count_tiles` to find the number of tiles if it is not known in advance.
+    ///
+    /// # Errors
+    /// If there are too many tiles, too few tiles, or unexpected characters.
+    pub const fn try_new_from_ascii_with(
+        s: &str,
+        options: AsciiOptions,
+    ) -> Result<Self, ParseShapeError> {
         let mut current = V::ZERO;
         let mut arr: [Vector; T] = [V::ZERO; T];
         let mut index = 0;
@@ -147,29 +314,39 @@ impl<const T: usize> Polyomino<T> {
 
         while bytes_index < bytes.len() {
             let character = bytes[bytes_index];
-            if character == b'\n' {
-                current.x = 0;
-                current = current.const_add(V::SOUTH);
-            } else if character.is_ascii_whitespace() {
-                //Ignore other ascii whitespace
-            } else if character == Self::ASCII_SPACE {
-                current = current.const_add(V::EAST);
-            } else if character == Self::ASCII_TILE {
-                if index >= arr.len() {
-                    return Err("Too Many Tiles");
+            match options.classify(character) {
+                Some(AsciiGlyph::NewLine) => {
+                    current.x = 0;
+                    current = current.const_add(V::SOUTH);
+                }
+                Some(AsciiGlyph::Ignored) => {}
+                Some(AsciiGlyph::Space) => {
+                    current = current.const_add(V::EAST);
                 }
+                Some(AsciiGlyph::Tile) => {
+                    if index >= arr.len() {
+                        return Err(ParseShapeError::TooManyTiles);
+                    }
 
-                arr[index] = current;
-                index += 1;
-                current = current.const_add(V::EAST);
-            } else {
-                return Err("Unexpected Character");
+                    arr[index] = current.flip(options.flip);
+                    index += 1;
+                    current = current.const_add(V::EAST);
+                }
+                None => {
+                    return Err(ParseShapeError::UnexpectedCharacter {
+                        byte: character,
+                        index: bytes_index,
+                    });
+                }
             }
             bytes_index += 1;
         }
 
         if index != arr.len() {
-            return Err("Not enough tiles");
+            return Err(ParseShapeError::NotEnoughTiles {
+                expected: T,
+                found: index,
+            });
         }
 
         Ok(Self::new(arr))
@@ -216,6 +393,395 @@ impl<const T: usize> Polyomino<T> {
 
         String::from_utf8(bytes).unwrap()
     }
+
+    /// Scale this polyomino by an integer factor, so that each tile becomes an `n` by `n` block.
+    /// # Panics
+    /// If `Q` is not `T * n * n`, or if the inflated tiles do not fit in a `Vector`
+    #[must_use]
+    pub const fn inflate<const Q: usize>(&self, n: u8) -> Polyomino<Q> {
+        assert!(
+            Q == T * (n as usize) * (n as usize),
+            "The inflated polyomino must have n * n times as many tiles"
+        );
+        let mut arr = [V::ZERO; Q];
+        let mut index = 0;
+        let mut i = 0;
+        while i < T {
+            let tile = self.0[i].0;
+            let mut dy = 0;
+            while dy < n {
+                let mut dx = 0;
+                while dx < n {
+                    let (Some(x), Some(y)) = (
+                        scale_coordinate(tile.x, n, dx),
+                        scale_coordinate(tile.y, n, dy),
+                    ) else {
+                        panic!("The inflated polyomino does not fit in a Vector");
+                    };
+                    arr[index] = V::new(x, y);
+                    index += 1;
+                    dx += 1;
+                }
+                dy += 1;
+            }
+            i += 1;
+        }
+        Polyomino::new(arr)
+    }
+
+    /// The mirror image of this polyomino, flipped horizontally and normalized.
+    /// Chiral pieces such as the S and Z tetrominos are mirrors of each other, up to rotation.
+    #[must_use]
+    pub const fn mirror(&self) -> Self {
+        let mut arr = [V::ZERO; T];
+        let mut i = 0;
+        while i < T {
+            arr[i] = self.0[i].0.flip(FlipAxes::Horizontal);
+            i += 1;
+        }
+        Self::new(arr)
+    }
+
+    /// Whether `other` is this polyomino after some combination of rotating and mirroring
+    #[must_use]
+    pub fn is_congruent_to(&self, other: &Self) -> bool {
+        Dihedral::ALL.iter().any(|dihedral| {
+            let shape = if dihedral.flipped {
+                self.mirror()
+            } else {
+                *self
+            };
+            Rotate::rotate(&shape, dihedral.quarter_turns) == *other
+        })
+    }
+
+    /// The width and height of the bounding box of this polyomino, in tiles
+    #[allow(clippy::cast_sign_loss)]
+    const fn dimensions(&self) -> (u8, u8) {
+        let mut width = 0;
+        let mut height = 0;
+        let mut i = 0;
+        while i < T {
+            let tile = self.0[i].0;
+            // Tiles are normalized so the coordinates are never negative
+            if tile.x as u8 >= width {
+                width = tile.x as u8 + 1;
+            }
+            if tile.y as u8 >= height {
+                height = tile.y as u8 + 1;
+            }
+            i += 1;
+        }
+        (width, height)
+    }
+
+    /// Whether this polyomino fits in a rectangle with the given width and height, in any orientation.
+    /// This only considers the bounding box so is a cheap check before trying to place the polyomino.
+    #[must_use]
+    pub const fn fits_in_rectangle(&self, width: u8, height: u8) -> bool {
+        let (w, h) = self.dimensions();
+        (w <= width && h <= height) || (h <= width && w <= height)
+    }
+
+    /// The side length of the smallest square which this polyomino fits in
+    #[must_use]
+    pub const fn min_bounding_square(&self) -> u8 {
+        let (w, h) = self.dimensions();
+        if w > h {
+            w
+        } else {
+            h
+        }
+    }
+
+    /// The second moments of area of the polyomino about its centroid, as `(Ixx, Iyy, Ixy)`.
+    /// `Ixx` is the integral of `(y - ȳ)²` over the tiles, `Iyy` of `(x - x̄)²` and `Ixy` of `(x - x̄)(y - ȳ)`.
+    #[must_use]
+    pub fn second_moments(&self) -> (f32, f32, f32) {
+        self.tile_sums().second_moments()
+    }
+
+    /// The angle in radians of the principal axis through the centroid, along which the polyomino is most spread out.
+    /// Measured from east and, as y increases to the south, positive angles are clockwise. In the range (-π/2, π/2].
+    /// `None` if every axis has the same moment, as for the O tetromino and the X pentomino.
+    /// Requires `std` or `libm`
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn principal_axis_angle(&self) -> Option<f32> {
+        self.tile_sums().principal_axis_angle()
+    }
+
+    fn tile_sums(&self) -> TileSums {
+        TileSums::new(
+            self.0
+                .iter()
+                .map(|tile| (i64::from(tile.x), i64::from(tile.y))),
+        )
+    }
+
+    /// The tiles of this polyomino on a fixed grid, with the polyomino's origin at `origin`.
+    /// Returns `None` if any tile would be outside the grid.
+    #[must_use]
+    pub const fn tiles_at<const W: u8, const H: u8>(
+        &self,
+        origin: Tile<W, H>,
+    ) -> Option<[Tile<W, H>; T]> {
+        let mut arr = [origin; T];
+        let mut i = 0;
+        while i < T {
+            let Some(tile) = origin.const_add(&self.0[i].0) else {
+                return None;
+            };
+            arr[i] = tile;
+            i += 1;
+        }
+        Some(arr)
+    }
+
+    /// Iterate through the pairs of orthogonally adjacent tiles where the first is in this polyomino and the second is in `other` translated by `other_offset`.
+    /// The second tile of each pair is given after translation.
+    pub fn touching_tiles<'a, const Q: usize>(
+        &'a self,
+        other_offset: Vector,
+        other: &'a Polyomino<Q>,
+    ) -> impl Iterator<Item = (DynamicTile, DynamicTile)> + Clone + 'a {
+        self.0.iter().flat_map(move |a| {
+            other
+                .0
+                .iter()
+                .map(move |b| b.const_add(other_offset))
+                .filter(move |b| Vector::CARDINALS.contains(&b.0.const_sub(a.0)))
+                .map(move |b| (*a, b))
+        })
+    }
+
+    /// Whether any tile of this polyomino is also a tile of `other` translated by `other_offset`
+    #[must_use]
+    pub fn overlaps<const Q: usize>(&self, other_offset: Vector, other: &Polyomino<Q>) -> bool {
+        // Both tile arrays are sorted and translation preserves order, so they can be merged
+        let mut left = self.0.iter().peekable();
+        let mut right = other.0.iter().map(|b| b.const_add(other_offset)).peekable();
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            match (*a).cmp(b) {
+                core::cmp::Ordering::Less => {
+                    left.next();
+                }
+                core::cmp::Ordering::Equal => return true,
+                core::cmp::Ordering::Greater => {
+                    right.next();
+                }
+            }
+        }
+        false
+    }
+
+    /// The number of tiles of this polyomino which are also tiles of `other` translated by `other_offset`
+    #[must_use]
+    pub fn overlap_area<const Q: usize>(
+        &self,
+        other_offset: Vector,
+        other: &Polyomino<Q>,
+    ) -> usize {
+        // Both tile arrays are sorted and translation preserves order, so they can be merged
+        let mut left = self.0.iter().peekable();
+        let mut right = other.0.iter().map(|b| b.const_add(other_offset)).peekable();
+        let mut count = 0;
+
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            match (*a).cmp(b) {
+                core::cmp::Ordering::Less => {
+                    left.next();
+                }
+                core::cmp::Ordering::Equal => {
+                    count += 1;
+                    left.next();
+                    right.next();
+                }
+                core::cmp::Ordering::Greater => {
+                    right.next();
+                }
+            }
+        }
+        count
+    }
+
+    /// The translation of `other` which overlaps the most tiles of this polyomino, and the number of tiles it overlaps.
+    /// Every translation where the two shapes could overlap is checked.
+    /// Where several translations overlap the same number of tiles, the one closest to zero by Manhattan distance is returned, then the first in row order.
+    pub fn best_overlap_offset<const Q: usize>(&self, other: &Polyomino<Q>) -> (Vector, usize) {
+        let (Some((a_min_x, a_max_x)), Some((a_min_y, a_max_y))) = (
+            self.0.iter().map(|t| t.x).minmax().into_option(),
+            self.0.iter().map(|t| t.y).minmax().into_option(),
+        ) else {
+            return (Vector::ZERO, 0);
+        };
+        let (Some((b_min_x, b_max_x)), Some((b_min_y, b_max_y))) = (
+            other.0.iter().map(|t| t.x).minmax().into_option(),
+            other.0.iter().map(|t| t.y).minmax().into_option(),
+        ) else {
+            return (Vector::ZERO, 0);
+        };
+
+        let mut best = (Vector::ZERO, self.overlap_area(Vector::ZERO, other));
+        for y in (a_min_y - b_max_y)..=(a_max_y - b_min_y) {
+            for x in (a_min_x - b_max_x)..=(a_max_x - b_min_x) {
+                let offset = Vector::new(x, y);
+                let area = self.overlap_area(offset, other);
+                let is_closer = offset.x.unsigned_abs() + offset.y.unsigned_abs()
+                    < best.0.x.unsigned_abs() + best.0.y.unsigned_abs();
+                if area > best.1 || (area == best.1 && is_closer) {
+                    best = (offset, area);
+                }
+            }
+        }
+        best
+    }
+
+    /// Draw the outline of this polyomino, first checking that its tiles are distinct and connected.
+    /// `draw_outline` may panic for shapes which fail this check.
+    /// # Errors
+    /// If the polyomino is empty, contains duplicate tiles or is not connected
+    pub fn try_draw_outline(&self) -> Result<OutlineIter<T>, ShapeError> {
+        let mut tiles = self.0;
+        validate_shape_tiles(&mut tiles, &mut [false; T], &mut [0; T])?;
+        Ok(self.draw_outline())
+    }
+
+    /// The vertices of the outline where it turns clockwise, so the interior angle is 90 degrees.
+    /// Only the outer outline is considered, not the outlines of any holes.
+    pub fn convex_corners(&self) -> impl Iterator<Item = DynamicVertex> {
+        self.outline_turns()
+            .filter(|(_, turn)| *turn > 0)
+            .map(|(vertex, _)| vertex)
+    }
+
+    /// The vertices of the outline where it turns anticlockwise, so the interior angle is 270 degrees.
+    /// Only the outer outline is considered, not the outlines of any holes.
+    pub fn concave_corners(&self) -> impl Iterator<Item = DynamicVertex> {
+        self.outline_turns()
+            .filter(|(_, turn)| *turn < 0)
+            .map(|(vertex, _)| vertex)
+    }
+
+    /// Each vertex of the outline, with the cross product of the edges before and after it.
+    /// The outline goes clockwise, so a positive cross product is a clockwise turn.
+    fn outline_turns(&self) -> impl Iterator<Item = (DynamicVertex, i16)> {
+        let outline = self.draw_outline();
+        let last = self.draw_outline().last();
+        let first = self.draw_outline().next();
+
+        last.into_iter()
+            .chain(outline)
+            .chain(first)
+            .tuple_windows()
+            .map(
+                |(previous, vertex, next): (DynamicVertex, DynamicVertex, DynamicVertex)| {
+                    let incoming = vertex.0.const_sub(previous.0);
+                    let outgoing = next.0.const_sub(vertex.0);
+                    let turn = i16::from(incoming.x) * i16::from(outgoing.y)
+                        - i16::from(incoming.y) * i16::from(outgoing.x);
+                    (vertex, turn)
+                },
+            )
+    }
+
+    /// Iterate through the tiles which are orthogonally adjacent to this polyomino but not part of it.
+    /// Tiles are given in order and each is given once.
+    pub fn iter_adjacent_empty(&self) -> impl Iterator<Item = DynamicTile> + Clone + '_ {
+        let max_x = self.0.iter().map(|t| t.x).max().unwrap_or_default();
+        let max_y = self.0.iter().map(|t| t.y).max().unwrap_or_default();
+
+        (-1..=max_x + 1)
+            .cartesian_product(-1..=max_y + 1)
+            .map(|(x, y)| DynamicTile(Vector::new(x, y)))
+            .filter(move |tile| {
+                !self.0.contains(tile)
+                    && self
+                        .0
+                        .iter()
+                        .any(|t| Vector::CARDINALS.contains(&t.0.const_sub(tile.0)))
+            })
+    }
+
+    /// Whether this polyomino completely encloses any empty tiles
+    #[must_use]
+    pub fn has_holes(&self) -> bool {
+        self.iter_holes().next().is_some()
+    }
+
+    /// Iterate through the empty tiles which are enclosed by this polyomino.
+    /// A tile is enclosed if it cannot reach the edge of the bounding box by moving orthogonally through empty tiles.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn iter_holes(&self) -> impl Iterator<Item = DynamicTile> + Clone {
+        // Tiles are normalized so coordinates are in `0..128` and each row fits in a `u128`
+        let mut filled = [0u128; T];
+        let mut width = 0;
+        let mut height = 0;
+
+        for tile in self.0 {
+            let (x, y) = (tile.x as usize, tile.y as usize);
+            filled[y] |= 1 << x;
+            width = width.max(x + 1);
+            height = height.max(y + 1);
+        }
+
+        let mask = if width == 0 {
+            0
+        } else {
+            u128::MAX >> (u128::BITS as usize - width)
+        };
+
+        let mut outside = [0u128; T];
+        for y in 0..height {
+            let border = if y == 0 || y + 1 == height {
+                mask
+            } else {
+                1 | (1 << (width - 1))
+            };
+            outside[y] = border & !filled[y];
+        }
+
+        loop {
+            let mut changed = false;
+            for y in 0..height {
+                let mut next = outside[y] | (outside[y] << 1) | (outside[y] >> 1);
+                if y > 0 {
+                    next |= outside[y - 1];
+                }
+                if y + 1 < height {
+                    next |= outside[y + 1];
+                }
+                next &= mask & !filled[y];
+                if next != outside[y] {
+                    outside[y] = next;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let mut holes = [0u128; T];
+        for y in 0..height {
+            holes[y] = mask & !filled[y] & !outside[y];
+        }
+
+        (0..height).flat_map(move |y| {
+            let mut row = holes[y];
+            core::iter::from_fn(move || {
+                if row == 0 {
+                    return None;
+                }
+                let x = row.trailing_zeros();
+                row &= row - 1;
+                Some(DynamicTile(Vector::new(x as i8, y as i8)))
+            })
+        })
+    }
 }
 
 impl Polyomino<1> {
@@ -312,6 +878,10 @@ impl Polyomino<4> {
     ];
 
     pub const FREE_TETROMINO_NAMES: [&'static str; 5] = ["I", "O", "T", "L", "S"];
+
+    /// The seven one-sided tetrominos, where mirror images are distinct but rotations are not.
+    /// J and Z are the mirrored variants of L and S. The names are `TETROMINO_NAMES`.
+    pub const ONE_SIDED_TETROMINOS: [Self; 7] = Self::TETROMINOS;
 }
 
 impl Polyomino<5> {
@@ -481,6 +1051,10 @@ impl Polyomino<5> {
     pub const ALL_PENTOMINO_NAMES: [&'static str; 18] = [
         "F", "I", "L", "N", "P", "T", "U", "V", "W", "X", "Y", "Z", "7", "J", "5", "Q", "λ", "S",
     ];
+
+    /// The eighteen one-sided pentominos, where mirror images are distinct but rotations are not.
+    /// The last six are the mirrored variants of F, L, N, P, Y and Z. The names are `ALL_PENTOMINO_NAMES`.
+    pub const ONE_SIDED_PENTOMINOS: [Self; 18] = Self::ALL_PENTOMINOS;
 }
 
 /// WARNING hexomino names are subject to change
@@ -782,59 +1356,128 @@ impl<const POINTS: usize> Iterator for OutlineIter<POINTS> {
     type Item = DynamicVertex;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let mut direction_so_far: Option<Vector> = None;
-        let (coordinate_to_return, corner_to_return) = self.next?;
-
-        let mut next_coordinate = coordinate_to_return;
-        let mut next_corner = corner_to_return;
-
-        'line: loop {
-            'equivalency: loop {
-                let equivalent = next_coordinate + next_corner.clockwise_direction();
-                if self.arr.contains(&equivalent) {
-                    //perform an equivalency
-                    next_coordinate = equivalent;
-                    next_corner = next_corner.anticlockwise();
-                    assert!(
-                        next_coordinate != coordinate_to_return,
-                        "Infinite loop found in shape."
-                    );
-                    if next_corner == Corner::NorthWest && next_coordinate == self.arr[0] {
-                        break 'line;
-                    }
-                } else {
-                    break 'equivalency;
+        next_outline_vertex(&self.arr, self.arr[0], &mut self.next)
+    }
+}
+
+/// Whether the point is within any of the tiles, when each tile has sides of length `scale`
+#[cfg(any(test, feature = "glam"))]
+pub(crate) fn contains_point(tiles: &[DynamicTile], point: glam::f32::Vec2, scale: f32) -> bool {
+    let in_range = |v: f32| v >= f32::from(i8::MIN) && v <= f32::from(i8::MAX);
+    let x = (point.x / scale).floor();
+    let y = (point.y / scale).floor();
+    if !(in_range(x) && in_range(y)) {
+        return false;
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let tile = DynamicTile(Vector::new(x as i8, y as i8));
+    tiles.contains(&tile)
+}
+
+/// Check that the tiles of a shape are non-empty, distinct and orthogonally connected, so that its outline can be drawn.
+/// `tiles` is sorted in place, so pass a copy of the shape's tiles.
+/// `reached` and `stack` are scratch space and must have the same length as `tiles`.
+pub(crate) fn validate_shape_tiles(
+    tiles: &mut [DynamicTile],
+    reached: &mut [bool],
+    stack: &mut [usize],
+) -> Result<(), ShapeError> {
+    if tiles.is_empty() {
+        return Err(ShapeError::Empty);
+    }
+
+    tiles.sort_unstable();
+    if tiles.windows(2).any(|pair| pair[0] == pair[1]) {
+        return Err(ShapeError::DuplicateTile);
+    }
+
+    // Depth first search from the first tile, finding neighbours by binary search
+    reached[0] = true;
+    stack[0] = 0;
+    let mut stack_len = 1;
+    let mut reached_count = 1;
+    while stack_len > 0 {
+        stack_len -= 1;
+        let tile = tiles[stack[stack_len]];
+        for direction in Vector::CARDINALS {
+            if let Ok(index) = tiles.binary_search(&(tile + direction)) {
+                if !reached[index] {
+                    reached[index] = true;
+                    reached_count += 1;
+                    stack[stack_len] = index;
+                    stack_len += 1;
                 }
             }
+        }
+    }
 
-            match direction_so_far {
-                None => {
-                    direction_so_far = Some(next_corner.clockwise_direction());
-                    next_corner = next_corner.clockwise();
-                }
-                Some(d) => {
-                    if d == next_corner.clockwise_direction() {
-                        next_corner = next_corner.clockwise();
-                    } else {
-                        break 'line;
-                    }
+    if reached_count == tiles.len() {
+        Ok(())
+    } else {
+        Err(ShapeError::Disconnected)
+    }
+}
+
+/// Get the next vertex of the outline of a shape.
+/// `first` must be the minimum tile of the shape.
+pub(crate) fn next_outline_vertex(
+    arr: &[DynamicTile],
+    first: DynamicTile,
+    next: &mut Option<(DynamicTile, Corner)>,
+) -> Option<DynamicVertex> {
+    let mut direction_so_far: Option<Vector> = None;
+    let (coordinate_to_return, corner_to_return) = (*next)?;
+
+    let mut next_coordinate = coordinate_to_return;
+    let mut next_corner = corner_to_return;
+
+    'line: loop {
+        'equivalency: loop {
+            let equivalent = next_coordinate + next_corner.clockwise_direction();
+            if arr.contains(&equivalent) {
+                //perform an equivalency
+                next_coordinate = equivalent;
+                next_corner = next_corner.anticlockwise();
+                assert!(
+                    next_coordinate != coordinate_to_return,
+                    "Infinite loop found in shape."
+                );
+                if next_corner == Corner::NorthWest && next_coordinate == first {
+                    break 'line;
                 }
-            }
-            if next_corner == Corner::NorthWest && next_coordinate == self.arr[0] {
-                break 'line;
+            } else {
+                break 'equivalency;
             }
         }
 
-        if next_corner == Corner::NorthWest && next_coordinate == self.arr[0] {
-            self.next = None;
-        } else {
-            self.next = Some((next_coordinate, next_corner));
+        match direction_so_far {
+            None => {
+                direction_so_far = Some(next_corner.clockwise_direction());
+                next_corner = next_corner.clockwise();
+            }
+            Some(d) => {
+                if d == next_corner.clockwise_direction() {
+                    next_corner = next_corner.clockwise();
+                } else {
+                    break 'line;
+                }
+            }
         }
+        if next_corner == Corner::NorthWest && next_coordinate == first {
+            break 'line;
+        }
+    }
 
-        let r = coordinate_to_return.get_vertex(&corner_to_return);
-
-        Some(r)
+    if next_corner == Corner::NorthWest && next_coordinate == first {
+        *next = None;
+    } else {
+        *next = Some((next_coordinate, next_corner));
     }
+
+    let r = coordinate_to_return.get_vertex(&corner_to_return);
+
+    Some(r)
 }
 
 /// Iterator for deconstructing polyominos to rectangles
@@ -854,55 +1497,95 @@ impl<const P: usize> Iterator for RectangleIter<P> {
     type Item = Rectangle;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let p1 = self.remaining_tiles.pop()?;
-        let mut min_x = p1.x;
-        let mut max_x = p1.x;
-        let mut min_y = p1.y;
+        let mut len = self.remaining_tiles.len();
+        let result = next_rectangle(self.remaining_tiles.as_mut_slice(), &mut len);
+        self.remaining_tiles.truncate(len);
+        result
+    }
+}
 
-        while let Some((index, &p2)) = self
-            .remaining_tiles
-            .iter()
-            .find_position(|p2| p2.y == min_y && (p2.x == max_x + 1 || p2.x == min_x - 1))
-        {
-            let _ = self.remaining_tiles.swap_remove(index);
-            min_x = min_x.min(p2.x);
-            max_x = max_x.max(p2.x);
-        }
-        let range = min_x..=max_x;
-
-        let mut max_y = p1.y;
-
-        'outer: loop {
-            for is_max in [false, true] {
-                let y = if is_max { max_y + 1 } else { min_y - 1 };
-                let condition = |p2: &&DynamicTile| p2.y == y && range.contains(&p2.x);
-                if self.remaining_tiles.iter().filter(condition).count() == range.len() {
-                    while let Some((position, _)) =
-                        self.remaining_tiles.iter().find_position(condition)
-                    {
-                        let _ = self.remaining_tiles.swap_remove(position);
-                    }
-                    if is_max {
-                        max_y += 1;
-                    } else {
-                        min_y -= 1;
-                    }
+/// Remove tiles forming a rectangle from the first `len` remaining tiles.
+/// Removed tiles are moved after the new `len`.
+pub(crate) fn next_rectangle(remaining: &mut [DynamicTile], len: &mut usize) -> Option<Rectangle> {
+    let mut remaining_tiles = RemainingTiles { remaining, len };
+    let p1 = remaining_tiles.pop()?;
+    let mut min_x = p1.x;
+    let mut max_x = p1.x;
+    let mut min_y = p1.y;
+
+    while let Some((index, &p2)) = remaining_tiles
+        .iter()
+        .find_position(|p2| p2.y == min_y && (p2.x == max_x + 1 || p2.x == min_x - 1))
+    {
+        let _ = remaining_tiles.swap_remove(index);
+        min_x = min_x.min(p2.x);
+        max_x = max_x.max(p2.x);
+    }
+    let range = min_x..=max_x;
 
-                    continue 'outer;
+    let mut max_y = p1.y;
+
+    'outer: loop {
+        for is_max in [false, true] {
+            let y = if is_max { max_y + 1 } else { min_y - 1 };
+            let condition = |p2: &&DynamicTile| p2.y == y && range.contains(&p2.x);
+            if remaining_tiles.iter().filter(condition).count() == range.len() {
+                while let Some((position, _)) = remaining_tiles.iter().find_position(condition) {
+                    let _ = remaining_tiles.swap_remove(position);
+                }
+                if is_max {
+                    max_y += 1;
+                } else {
+                    min_y -= 1;
                 }
+
+                continue 'outer;
             }
-            break 'outer;
         }
+        break 'outer;
+    }
 
-        let north_west = Vector { x: min_x, y: min_y }.into();
-        let width: u8 = max_x.abs_diff(min_x) + 1;
-        let height: u8 = max_y.abs_diff(min_y) + 1;
+    let north_west = Vector { x: min_x, y: min_y }.into();
+    let width: u8 = max_x.abs_diff(min_x) + 1;
+    let height: u8 = max_y.abs_diff(min_y) + 1;
 
-        Some(Rectangle {
-            north_west,
-            width,
-            height,
-        })
+    Some(Rectangle {
+        north_west,
+        width,
+        height,
+    })
+}
+
+struct RemainingTiles<'a, 'b> {
+    remaining: &'a mut [DynamicTile],
+    len: &'b mut usize,
+}
+
+impl RemainingTiles<'_, '_> {
+    fn pop(&mut self) -> Option<DynamicTile> {
+        *self.len = self.len.checked_sub(1)?;
+        Some(self.remaining[*self.len])
+    }
+
+    fn swap_remove(&mut self, index: usize) -> DynamicTile {
+        *self.len -= 1;
+        self.remaining.swap(index, *self.len);
+        self.remaining[*self.len]
+    }
+
+    fn iter(&self) -> core::slice::Iter<'_, DynamicTile> {
+        self.remaining[..*self.len].iter()
+    }
+}
+
+/// `coordinate * scale + offset`, if it fits in an `i8`
+#[allow(clippy::cast_possible_truncation)]
+const fn scale_coordinate(coordinate: i8, scale: u8, offset: u8) -> Option<i8> {
+    let value = coordinate as i16 * scale as i16 + offset as i16;
+    if value >= i8::MIN as i16 && value <= i8::MAX as i16 {
+        Some(value as i8)
+    } else {
+        None
     }
 }
 
@@ -911,6 +1594,262 @@ mod tests {
     use super::*;
     use itertools::Itertools;
 
+    #[test]
+    fn test_try_from_rectangle() {
+        let rectangle = Rectangle::new(Vector::new(3, -2).into(), 3, 2);
+        let polyomino = Polyomino::<6>::try_from(rectangle).unwrap();
+        assert_eq!(polyomino.to_string(), "###\n###");
+        assert_eq!(
+            polyomino.deconstruct_into_rectangles().collect_vec(),
+            [Rectangle::new(Vector::ZERO.into(), 3, 2)]
+        );
+
+        assert_eq!(
+            Polyomino::<4>::try_from(Rectangle::new(Vector::ZERO.into(), 4, 1)),
+            Ok(Polyomino::I_TETROMINO)
+        );
+        assert_eq!(
+            Polyomino::<5>::try_from(rectangle),
+            Err(DimensionError::SizeMismatch {
+                expected: 5,
+                found: 6
+            })
+        );
+        assert_eq!(
+            Polyomino::<200>::try_from(Rectangle::new(Vector::ZERO.into(), 200, 1)),
+            Err(DimensionError::TooLarge {
+                size: 200,
+                max: 127
+            })
+        );
+    }
+
+    #[test]
+    fn test_ascii_options() {
+        let options = AsciiOptions::DEFAULT.with_glyphs(b'X', b' ');
+        assert_eq!(
+            Polyomino::<4>::try_new_from_ascii_with("XXX\n X ", options),
+            Ok(Polyomino::T_TETROMINO)
+        );
+        assert_eq!(
+            Polyomino::<4>::try_new_from_ascii_with("XXX\n.X", options),
+            Err(ParseShapeError::UnexpectedCharacter {
+                byte: b'.',
+                index: 4
+            })
+        );
+
+        let options = AsciiOptions::DEFAULT.with_any_non_space_is_tile(true);
+        assert_eq!(
+            Polyomino::<4>::try_new_from_ascii_with("ab  \n.@.\n@", options),
+            Ok(Polyomino::new([
+                Vector::new(0, 0),
+                Vector::new(1, 0),
+                Vector::new(1, 1),
+                Vector::new(0, 2),
+            ]))
+        );
+
+        let options = AsciiOptions::DEFAULT.with_flip(FlipAxes::Vertical);
+        assert_eq!(
+            Polyomino::<4>::try_new_from_ascii_with(".#.\n###", options),
+            Ok(Polyomino::T_TETROMINO)
+        );
+    }
+
+    /// Every rotation of the polyomino, normalized
+    fn rotations<const P: usize>(shape: &Polyomino<P>) -> Vec<Polyomino<P>> {
+        use strum::IntoEnumIterator;
+        QuarterTurns::iter()
+            .map(|quarter_turns| Polyomino::new(shape.tiles().map(|t| t.0.rotate(quarter_turns))))
+            .collect()
+    }
+
+    fn check_one_sided<const P: usize>(shapes: &[Polyomino<P>], names: &[&str]) {
+        for (i, (shape, name)) in shapes.iter().zip(names).enumerate() {
+            let mirror = shape.mirror();
+            assert_eq!(mirror.mirror(), *shape, "{name}");
+
+            let mirror_index = shapes
+                .iter()
+                .position(|other| rotations(other).contains(&mirror))
+                .unwrap_or_else(|| panic!("Mirror of {name} should be in the set"));
+            let chiral = !rotations(shape).contains(&mirror);
+            assert_eq!(mirror_index != i, chiral, "{name}");
+
+            for other in &shapes[(i + 1)..] {
+                assert!(!rotations(other).contains(shape), "{name}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_is_congruent_to() {
+        let s = Polyomino::S_TETROMINO;
+        assert!(s.is_congruent_to(&s));
+        assert!(s.is_congruent_to(&Polyomino::Z_TETROMINO));
+        assert!(s.is_congruent_to(&Rotate::rotate(&s, QuarterTurns::One)));
+        assert!(!s.is_congruent_to(&Polyomino::T_TETROMINO));
+
+        for (i, a) in Polyomino::FREE_PENTOMINOS.iter().enumerate() {
+            for (j, b) in Polyomino::FREE_PENTOMINOS.iter().enumerate() {
+                assert_eq!(a.is_congruent_to(b), i == j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_second_moments() {
+        let (ixx, iyy, ixy) = Polyomino::I_TETROMINO.second_moments();
+        assert!((ixx - 1.0 / 3.0).abs() < 1e-6);
+        assert!((iyy - 16.0 / 3.0).abs() < 1e-6);
+        assert!(ixy.abs() < 1e-6);
+
+        let (ixx, iyy, ixy) = Polyomino::S_TETROMINO.second_moments();
+        assert!((ixx - 4.0 / 3.0).abs() < 1e-6);
+        assert!((iyy - 7.0 / 3.0).abs() < 1e-6);
+        assert!((ixy + 1.0).abs() < 1e-6);
+
+        for shape in Polyomino::ALL_PENTOMINOS {
+            let (ixx, iyy, ixy) = shape.second_moments();
+            let (rxx, ryy, rxy) = Rotate::rotate(&shape, QuarterTurns::One).second_moments();
+            assert!(
+                (ixx - ryy).abs() < 1e-5 && (iyy - rxx).abs() < 1e-5,
+                "{shape}"
+            );
+            assert!((ixy + rxy).abs() < 1e-5, "{shape}");
+        }
+    }
+
+    #[test]
+    fn test_principal_axis_angle() {
+        use core::f32::consts::FRAC_PI_2;
+
+        let i = Polyomino::I_TETROMINO;
+        assert_eq!(i.principal_axis_angle(), Some(0.0));
+        assert_eq!(
+            Rotate::rotate(&i, QuarterTurns::One).principal_axis_angle(),
+            Some(FRAC_PI_2)
+        );
+        assert_eq!(Polyomino::O_TETROMINO.principal_axis_angle(), None);
+        assert_eq!(Polyomino::X_PENTOMINO.principal_axis_angle(), None);
+
+        // The S tetromino leans from south west to north east, which is anticlockwise from east
+        let s = Polyomino::S_TETROMINO.principal_axis_angle().unwrap();
+        assert!(s < 0.0 && s > -FRAC_PI_2 / 2.0, "{s}");
+        let z = Polyomino::Z_TETROMINO.principal_axis_angle().unwrap();
+        assert!((s + z).abs() < 1e-6, "{z}");
+    }
+
+    #[test]
+    fn test_fits_in_rectangle() {
+        let shape = Polyomino::L_PENTOMINO;
+        assert!(shape.fits_in_rectangle(4, 2));
+        assert!(shape.fits_in_rectangle(2, 4));
+        assert!(!shape.fits_in_rectangle(3, 3));
+        assert!(!shape.fits_in_rectangle(4, 1));
+        assert_eq!(shape.min_bounding_square(), 4);
+
+        assert!(Polyomino::X_PENTOMINO.fits_in_rectangle(3, 3));
+        assert_eq!(Polyomino::X_PENTOMINO.min_bounding_square(), 3);
+        assert_eq!(Polyomino::MONOMINO.min_bounding_square(), 1);
+    }
+
+    #[test]
+    fn test_one_sided() {
+        assert_eq!(
+            Polyomino::S_TETROMINO.mirror().to_string(),
+            Polyomino::Z_TETROMINO.to_string()
+        );
+        check_one_sided(
+            &Polyomino::ONE_SIDED_TETROMINOS,
+            &Polyomino::TETROMINO_NAMES,
+        );
+        check_one_sided(
+            &Polyomino::ONE_SIDED_PENTOMINOS,
+            &Polyomino::ALL_PENTOMINO_NAMES,
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", Polyomino::T_TETROMINO),
+            "Polyomino(###/.#.)"
+        );
+        assert_eq!(
+            format!("{:#?}", Polyomino::T_TETROMINO),
+            "Polyomino(\n###\n.#.\n)"
+        );
+    }
+
+    #[test]
+    fn test_display_and_from_str() {
+        for (shape, name) in Polyomino::ALL_PENTOMINOS
+            .iter()
+            .zip(Polyomino::ALL_PENTOMINO_NAMES)
+        {
+            let ascii = shape.to_string();
+            assert_eq!(ascii, shape.to_ascii_string(), "{name}");
+            assert_eq!(ascii.parse::<Polyomino<5>>(), Ok(*shape), "{name}");
+        }
+
+        assert_eq!(
+            "##".parse::<Polyomino<3>>(),
+            Err(ParseShapeError::NotEnoughTiles {
+                expected: 3,
+                found: 2
+            })
+        );
+        assert_eq!(
+            "###".parse::<Polyomino<2>>(),
+            Err(ParseShapeError::TooManyTiles)
+        );
+        assert_eq!(
+            "#.\n#x".parse::<Polyomino<2>>(),
+            Err(ParseShapeError::UnexpectedCharacter {
+                byte: b'x',
+                index: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_serde_ascii() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "serde_ascii")] Polyomino<4>);
+
+        serde_test::assert_tokens(
+            &Wrapper(Polyomino::T_TETROMINO),
+            &[
+                serde_test::Token::NewtypeStruct { name: "Wrapper" },
+                serde_test::Token::Str("###\n.#."),
+            ],
+        );
+
+        serde_test::assert_de_tokens_error::<Wrapper>(
+            &[
+                serde_test::Token::NewtypeStruct { name: "Wrapper" },
+                serde_test::Token::Str("##"),
+            ],
+            "Not enough tiles: expected 4, found 2",
+        );
+    }
+
+    #[test]
+    fn test_contains_point() {
+        let shape = Polyomino::T_TETROMINO;
+        let contains = |x, y| shape.contains_point(glam::f32::Vec2::new(x, y), 2.0);
+
+        assert!(contains(1.0, 1.0));
+        assert!(contains(5.9, 0.0));
+        assert!(contains(3.0, 3.0));
+        assert!(!contains(1.0, 3.0));
+        assert!(!contains(6.0, 1.0));
+        assert!(!contains(-0.1, 1.0));
+        assert!(!contains(1000.0, 1000.0));
+    }
+
     #[test]
     fn test_basic_outlines() {
         test_outline(&Polyomino::MONOMINO, "Square outline");
@@ -920,7 +1859,7 @@ mod tests {
     #[test]
     fn test_tetromino_outlines() {
         for (shape, name) in Polyomino::TETROMINOS.iter().zip(Polyomino::TETROMINO_NAMES) {
-            test_outline(shape, (name.to_string() + " tetromino outline").as_str())
+            test_outline(shape, (name.to_string() + " tetromino outline").as_str());
         }
     }
 
@@ -930,7 +1869,7 @@ mod tests {
             .iter()
             .zip(Polyomino::ALL_PENTOMINO_NAMES)
         {
-            test_outline(shape, (name.to_string() + " pentomino outline").as_str())
+            test_outline(shape, (name.to_string() + " pentomino outline").as_str());
         }
     }
 
@@ -943,7 +1882,7 @@ mod tests {
             test_deconstruct_into_rectangles(
                 shape,
                 (name.to_string() + " pentomino rectangles").as_str(),
-            )
+            );
         }
     }
 
@@ -1007,11 +1946,11 @@ mod tests {
     fn test_outline<P: Shape + HasCenter>(shape: &'static P, name: &str) {
         let outline: Vec<_> = shape.draw_outline().take(100).collect();
         assert!(outline.len() < 100);
-        let max_x = outline.iter().map(|q| q.x).max().unwrap() as f32;
-        let max_y = outline.iter().map(|q| q.y).max().unwrap() as f32;
+        let max_x = f32::from(outline.iter().map(|q| q.x).max().unwrap());
+        let max_y = f32::from(outline.iter().map(|q| q.y).max().unwrap());
 
-        let min_x = outline.iter().map(|q| q.x).min().unwrap() as f32;
-        let min_y = outline.iter().map(|q| q.y).min().unwrap() as f32;
+        let min_x = f32::from(outline.iter().map(|q| q.x).min().unwrap());
+        let min_y = f32::from(outline.iter().map(|q| q.y).min().unwrap());
 
         let glam::f32::Vec2 {
             x: centre_x,
@@ -1029,9 +1968,249 @@ mod tests {
 
     fn test_deconstruct_into_rectangles<const P: usize>(shape: &'static Polyomino<P>, name: &str) {
         let rectangles = shape.deconstruct_into_rectangles().collect_vec();
-        let sum: usize = rectangles.iter().map(|x| x.area()).sum();
+        let sum: usize = rectangles
+            .iter()
+            .map(super::super::rectangle::Rectangle::area)
+            .sum();
         assert_eq!(sum, P);
 
         insta::assert_json_snapshot!(name, rectangles);
     }
+
+    #[test]
+    fn test_iter_adjacent_empty() {
+        assert_eq!(
+            Polyomino::MONOMINO.iter_adjacent_empty().join("|"),
+            "(-1,0)|(0,-1)|(0,1)|(1,0)"
+        );
+        assert_eq!(
+            Polyomino::L_TETROMINO.iter_adjacent_empty().join("|"),
+            "(-1,1)|(0,0)|(0,2)|(1,0)|(1,2)|(2,-1)|(2,2)|(3,0)|(3,1)"
+        );
+
+        for shape in Polyomino::FREE_PENTOMINOS {
+            for tile in shape.iter_adjacent_empty() {
+                assert!(!shape.tiles().contains(&tile));
+                assert!(shape
+                    .tiles()
+                    .iter()
+                    .any(|t| Vector::CARDINALS.contains(&t.0.const_sub(tile.0))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_holes() {
+        for shape in Polyomino::ALL_PENTOMINOS {
+            assert!(!shape.has_holes());
+        }
+
+        let heptomino = Polyomino::<7>::try_new_from_ascii("###\n#.#\n##.").unwrap();
+        assert!(heptomino.has_holes());
+        assert_eq!(
+            heptomino.iter_holes().collect_vec(),
+            [DynamicTile(Vector::new(1, 1))]
+        );
+
+        let decomino = Polyomino::<10>::try_new_from_ascii("####\n#..#\n####").unwrap();
+        assert_eq!(decomino.iter_holes().join(";"), "(1,1);(2,1)");
+
+        let spiral = Polyomino::<7>::try_new_from_ascii("###.\n#.#.\n#...\n#...").unwrap();
+        assert!(!spiral.has_holes());
+    }
+
+    #[test]
+    fn test_try_draw_outline() {
+        assert_eq!(
+            Polyomino::L_TETROMINO.try_draw_outline().unwrap().count(),
+            Polyomino::L_TETROMINO.draw_outline().count()
+        );
+
+        let disconnected = Polyomino::new([Vector::new(0, 0), Vector::new(1, 1)]);
+        assert_eq!(
+            disconnected.try_draw_outline().err(),
+            Some(ShapeError::Disconnected)
+        );
+
+        let duplicate = Polyomino::new([Vector::new(0, 0), Vector::new(0, 0)]);
+        assert_eq!(
+            duplicate.try_draw_outline().err(),
+            Some(ShapeError::DuplicateTile)
+        );
+
+        let duplicate = Polyomino::new([
+            Vector::new(2, 0),
+            Vector::new(0, 0),
+            Vector::new(1, 0),
+            Vector::new(0, 0),
+        ]);
+        assert_eq!(
+            duplicate.try_draw_outline().err(),
+            Some(ShapeError::DuplicateTile)
+        );
+
+        // Connected only through a tile which comes last in sorted order
+        let u = Polyomino::new([
+            Vector::new(0, 1),
+            Vector::new(2, 1),
+            Vector::new(0, 0),
+            Vector::new(2, 0),
+            Vector::new(1, 1),
+        ]);
+        assert!(u.try_draw_outline().is_ok());
+
+        assert_eq!(
+            Polyomino::<0>::new([]).try_draw_outline().err(),
+            Some(ShapeError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_corners() {
+        let l = Polyomino::L_TETROMINO;
+
+        assert_eq!(
+            l.convex_corners().join("|"),
+            "(0,1)|(2,0)|(3,0)|(3,2)|(0,2)"
+        );
+        assert_eq!(l.concave_corners().join("|"), "(2,1)");
+
+        assert_eq!(Polyomino::MONOMINO.convex_corners().count(), 4);
+        assert_eq!(Polyomino::MONOMINO.concave_corners().count(), 0);
+
+        for shape in Polyomino::FREE_PENTOMINOS {
+            let convex = shape.convex_corners().count();
+            let concave = shape.concave_corners().count();
+            assert_eq!(convex, concave + 4, "{shape}");
+        }
+    }
+
+    #[test]
+    fn test_overlap_area() {
+        let square = Polyomino::O_TETROMINO;
+        let line = Polyomino::I_TETROMINO;
+
+        assert_eq!(square.overlap_area(Vector::ZERO, &square), 4);
+        assert_eq!(square.overlap_area(Vector::new(1, 1), &square), 1);
+        assert_eq!(square.overlap_area(Vector::new(-1, 1), &line), 2);
+        assert_eq!(square.overlap_area(Vector::new(0, 2), &line), 0);
+
+        for shape in Polyomino::FREE_TETROMINOS {
+            assert_eq!(
+                square.overlap_area(Vector::new(1, 0), &shape) > 0,
+                square.overlaps(Vector::new(1, 0), &shape)
+            );
+        }
+    }
+
+    #[test]
+    fn test_best_overlap_offset() {
+        let square = Polyomino::O_TETROMINO;
+        let line = Polyomino::I_TETROMINO;
+
+        assert_eq!(square.best_overlap_offset(&square), (Vector::ZERO, 4));
+        assert_eq!(square.best_overlap_offset(&line), (Vector::ZERO, 2));
+        assert_eq!(
+            Polyomino::MONOMINO.best_overlap_offset(&Polyomino::DOMINO),
+            (Vector::ZERO, 1)
+        );
+
+        for (a, b) in Polyomino::FREE_TETROMINOS
+            .iter()
+            .cartesian_product(Polyomino::FREE_TETROMINOS.iter())
+        {
+            let (offset, area) = a.best_overlap_offset(b);
+            assert_eq!(a.overlap_area(offset, b), area);
+            assert!((-4..=4)
+                .cartesian_product(-4..=4)
+                .all(|(x, y)| a.overlap_area(Vector::new(x, y), b) <= area));
+        }
+    }
+
+    #[test]
+    fn test_touching_tiles_and_overlaps() {
+        let square = Polyomino::O_TETROMINO;
+        let line = Polyomino::I_TETROMINO;
+
+        // The line sits directly below the square
+        let below = Vector::new(0, 2);
+        assert!(!square.overlaps(below, &line));
+        assert_eq!(
+            square
+                .touching_tiles(below, &line)
+                .map(|(a, b)| format!("{a}{b}"))
+                .join(";"),
+            "(0,1)(0,2);(1,1)(1,2)"
+        );
+
+        // The line sits diagonally below the square, touching only at a corner
+        let diagonal = Vector::new(2, 2);
+        assert!(!square.overlaps(diagonal, &line));
+        assert_eq!(square.touching_tiles(diagonal, &line).count(), 0);
+
+        let overlapping = Vector::new(-1, 1);
+        assert!(square.overlaps(overlapping, &line));
+        assert!(line.overlaps(overlapping.const_neg(), &square));
+        assert!(square.overlaps(Vector::ZERO, &square));
+        assert!(!square.overlaps(Vector::new(2, 0), &square));
+        assert_eq!(square.touching_tiles(Vector::new(2, 0), &square).count(), 2);
+    }
+
+    #[test]
+    fn test_tiles_at() {
+        let l = Polyomino::L_TETROMINO;
+
+        let tiles = l.tiles_at(Tile::<3, 3>::NORTH_WEST).unwrap();
+        assert_eq!(
+            tiles.iter().join("|"),
+            l.tiles()
+                .iter()
+                .map(|t| Tile::<3, 3>::try_from_dynamic(*t).unwrap())
+                .join("|")
+        );
+
+        assert_eq!(l.tiles_at(Tile::<3, 3>::CENTER), None);
+        assert_eq!(l.tiles_at(Tile::<2, 2>::NORTH_WEST), None);
+    }
+
+    #[test]
+    fn test_inflate() {
+        let inflated: Polyomino<16> = Polyomino::L_TETROMINO.inflate(2);
+        assert_eq!(
+            inflated.to_string(),
+            Polyomino::L_TETROMINO
+                .to_string()
+                .lines()
+                .flat_map(|line| {
+                    let doubled: String = line.chars().flat_map(|c| [c, c]).collect();
+                    [doubled.clone(), doubled]
+                })
+                .join("\n")
+        );
+        assert_eq!(
+            Polyomino::L_TETROMINO.inflate::<4>(1),
+            Polyomino::L_TETROMINO
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "n * n times")]
+    fn test_inflate_wrong_size() {
+        let _: Polyomino<8> = Polyomino::L_TETROMINO.inflate(2);
+    }
+
+    #[test]
+    fn test_inflate_to_the_largest_vector() {
+        let inflated: Polyomino<8> = Polyomino::new([Vector::ZERO, Vector::new(63, 0)]).inflate(2);
+        assert_eq!(
+            inflated.tiles().last(),
+            Some(&DynamicTile(Vector::new(127, 1)))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in a Vector")]
+    fn test_inflate_too_large() {
+        let _: Polyomino<8> = Polyomino::new([Vector::ZERO, Vector::new(64, 0)]).inflate(2);
+    }
 }
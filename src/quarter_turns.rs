@@ -3,6 +3,8 @@ use core::ops::Add;
 use serde::{Deserialize, Serialize};
 use strum::{Display, EnumCount, EnumIs, EnumIter};
 
+use crate::vector::Vector;
+
 /// The number of quarter turns to rotate clockwise
 #[derive(
     Default,
@@ -31,6 +33,36 @@ pub enum QuarterTurns {
     Three,
 }
 
+impl QuarterTurns {
+    /// The rotation as a matrix in row-major order, to be multiplied by a column vector `[x, y]`.
+    /// As y increases to the south, this rotates clockwise as displayed.
+    #[must_use]
+    pub const fn to_matrix(&self) -> [[i8; 2]; 2] {
+        match self {
+            QuarterTurns::Zero => [[1, 0], [0, 1]],
+            QuarterTurns::One => [[0, -1], [1, 0]],
+            QuarterTurns::Two => [[-1, 0], [0, -1]],
+            QuarterTurns::Three => [[0, 1], [-1, 0]],
+        }
+    }
+
+    /// Rotate a vector about the origin
+    pub const fn apply(&self, vector: Vector) -> Vector {
+        vector.rotate(*self)
+    }
+
+    /// Rotate a point about the origin
+    #[cfg(any(test, feature = "glam"))]
+    #[must_use]
+    pub fn apply_to_point(&self, point: glam::f32::Vec2) -> glam::f32::Vec2 {
+        let [[a, b], [c, d]] = self.to_matrix();
+        glam::f32::Vec2::new(
+            f32::from(a) * point.x + f32::from(b) * point.y,
+            f32::from(c) * point.x + f32::from(d) * point.y,
+        )
+    }
+}
+
 impl Add for QuarterTurns {
     type Output = QuarterTurns;
 
@@ -58,13 +90,36 @@ impl Add for QuarterTurns {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_matrix_and_apply() {
+        let vector = Vector::new(2, 1);
+        for quarter_turns in QuarterTurns::iter() {
+            let [[a, b], [c, d]] = quarter_turns.to_matrix();
+            let expected = Vector::new(a * vector.x + b * vector.y, c * vector.x + d * vector.y);
+            assert_eq!(quarter_turns.apply(vector), expected);
+
+            let point = quarter_turns.apply_to_point(glam::f32::Vec2::new(2.0, 1.0));
+            assert_eq!(
+                point,
+                glam::f32::Vec2::new(f32::from(expected.x), f32::from(expected.y))
+            );
+        }
+
+        assert_eq!(QuarterTurns::One.apply(Vector::NORTH), Vector::EAST);
+        assert_eq!(
+            QuarterTurns::Three.apply_to_point(glam::f32::Vec2::new(0.5, 0.0)),
+            glam::f32::Vec2::new(0.0, -0.5)
+        );
+    }
 
     #[test]
     fn test_add() {
         use QuarterTurns::*;
         let arr = [(0, Zero), (1, One), (2, Two), (3, Three)];
-        for (i, qi) in arr.iter().cloned() {
-            for (j, qj) in arr.iter().cloned() {
+        for (i, qi) in arr.iter().copied() {
+            for (j, qj) in arr.iter().copied() {
                 let sum = i + j;
                 let q_sum = qi + qj;
 
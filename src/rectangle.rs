@@ -32,6 +32,112 @@ impl Rectangle {
     pub fn area(&self) -> usize {
         self.width as usize * self.height as usize
     }
+
+    /// The rectangle with opposite corners `a` and `b`
+    fn from_corners(a: Vector, b: Vector) -> Self {
+        Self {
+            north_west: Vector::new(a.x.min(b.x), a.y.min(b.y)).into(),
+            width: a.x.abs_diff(b.x),
+            height: a.y.abs_diff(b.y),
+        }
+    }
+
+    /// The vertex at the south east corner of the rectangle
+    fn south_east(self) -> Vector {
+        Vector::new(
+            self.north_west.x.saturating_add_unsigned(self.width),
+            self.north_west.y.saturating_add_unsigned(self.height),
+        )
+    }
+
+    /// The four corners of the rectangle, clockwise from the north west as displayed (y increasing southwards).
+    /// This is the same winding order as polyomino outlines.
+    pub fn corners(&self) -> [DynamicVertex; 4] {
+        let south_east = self.south_east();
+        [
+            self.north_west,
+            Vector::new(south_east.x, self.north_west.y).into(),
+            south_east.into(),
+            Vector::new(self.north_west.x, south_east.y).into(),
+        ]
+    }
+
+    /// Iterate through every vertex on the outline of the rectangle, one unit apart.
+    /// Goes clockwise from the north west, in the same winding order as `corners`, without repeating the first vertex.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn iter_vertices(
+        &self,
+    ) -> impl ExactSizeIterator<Item = DynamicVertex> + DoubleEndedIterator + Clone {
+        let (west, north) = (i16::from(self.north_west.x), i16::from(self.north_west.y));
+        let (width, height) = (i16::from(self.width), i16::from(self.height));
+
+        (0..2 * (width + height)).map(move |index| {
+            let (dx, dy) = if index < width {
+                (index, 0)
+            } else if index < width + height {
+                (width, index - width)
+            } else if index < width + width + height {
+                (width + width + height - index, height)
+            } else {
+                (0, width + width + height + height - index)
+            };
+            Vector::new((west + dx) as i8, (north + dy) as i8).into()
+        })
+    }
+
+    /// Iterate through the tiles on the border of the rectangle, each exactly once.
+    /// Goes clockwise from the north west tile, in the same winding order as `corners`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn iter_border_tiles(
+        &self,
+    ) -> impl ExactSizeIterator<Item = DynamicTile> + DoubleEndedIterator + Clone {
+        let (west, north) = (i16::from(self.north_west.x), i16::from(self.north_west.y));
+        let (width, height) = (i16::from(self.width), i16::from(self.height));
+
+        let count = if width == 0 || height == 0 {
+            0
+        } else if width == 1 || height == 1 {
+            width + height - 1
+        } else {
+            2 * (width + height) - 4
+        };
+
+        (0..count).map(move |index| {
+            let (dx, dy) = if index < width {
+                (index, 0)
+            } else if index < width + height - 1 {
+                (width - 1, index - width + 1)
+            } else if index < width + width + height - 2 {
+                (width + width + height - 3 - index, height - 1)
+            } else {
+                (0, width + width + height + height - 4 - index)
+            };
+            DynamicTile(Vector::new((west + dx) as i8, (north + dy) as i8))
+        })
+    }
+
+    /// Rotate the rectangle around `pivot`.
+    /// Odd numbers of quarter turns swap the width and height.
+    pub fn rotate(&self, quarter_turns: QuarterTurns, pivot: DynamicVertex) -> Self {
+        let transform = |v: Vector| {
+            pivot
+                .0
+                .const_add(v.const_add(pivot.0.const_neg()).rotate(quarter_turns))
+        };
+        Self::from_corners(transform(self.north_west.0), transform(self.south_east()))
+    }
+
+    /// Flip the rectangle in the given axes through `pivot`
+    pub fn flip(&self, axes: FlipAxes, pivot: DynamicVertex) -> Self {
+        let transform = |v: Vector| {
+            pivot
+                .0
+                .const_add(v.const_add(pivot.0.const_neg()).flip(axes))
+        };
+        Self::from_corners(transform(self.north_west.0), transform(self.south_east()))
+    }
 }
 
 #[cfg(any(test, feature = "glam"))]
@@ -49,31 +155,27 @@ impl Shape for Rectangle {
 
     type RectangleIter = array::IntoIter<Rectangle, 1>;
 
+    /// The four corners of the rectangle, clockwise from the north west
     fn draw_outline(&self) -> Self::OutlineIter {
-        [
-            self.north_west,
-            Vector {
-                x: self.north_west.x.saturating_add_unsigned(self.width),
-                y: self.north_west.y,
-            }
-            .into(),
-            Vector {
-                x: self.north_west.x,
-                y: self.north_west.y.saturating_add_unsigned(self.height),
-            }
-            .into(),
-            Vector {
-                x: self.north_west.x.saturating_add_unsigned(self.width),
-                y: self.north_west.y.saturating_add_unsigned(self.height),
-            }
-            .into(),
-        ]
-        .into_iter()
+        self.corners().into_iter()
     }
 
     fn deconstruct_into_rectangles(&self) -> Self::RectangleIter {
         [*self].into_iter()
     }
+
+    #[cfg(any(test, feature = "glam"))]
+    fn contains_point(&self, point: glam::f32::Vec2, scale: f32) -> bool {
+        let x = point.x / scale;
+        let y = point.y / scale;
+        let west = f32::from(self.north_west.x);
+        let north = f32::from(self.north_west.y);
+
+        x >= west
+            && x < west + f32::from(self.width)
+            && y >= north
+            && y < north + f32::from(self.height)
+    }
 }
 
 impl IntoIterator for Rectangle {
@@ -144,7 +246,20 @@ mod tests {
         let rect = Rectangle::new(Vector::NORTH_EAST.into(), 2, 4);
 
         let center = rect.get_center(3.0);
-        assert_eq!(center, glam::f32::Vec2::new(6.0, 3.0))
+        assert_eq!(center, glam::f32::Vec2::new(6.0, 3.0));
+    }
+
+    #[test]
+    pub fn test_contains_point() {
+        let rect = Rectangle::new(Vector::NORTH_EAST.into(), 2, 4);
+        let contains = |x, y| rect.contains_point(glam::f32::Vec2::new(x, y), 3.0);
+
+        assert!(contains(3.0, -3.0));
+        assert!(contains(8.9, 8.9));
+        assert!(!contains(9.0, 0.0));
+        assert!(!contains(2.9, 0.0));
+        assert!(!contains(4.0, -3.1));
+        assert!(!contains(4.0, 9.0));
     }
 
     #[test]
@@ -155,8 +270,49 @@ mod tests {
 
         assert_eq!(
             outline.into_iter().join("; "),
-            "(1,-1); (3,-1); (1,3); (3,3)"
+            "(1,-1); (3,-1); (3,3); (1,3)"
+        );
+    }
+
+    #[test]
+    pub fn test_rotate() {
+        let rect = Rectangle::new(Vector::NORTH_EAST.into(), 2, 4);
+        let pivot = DynamicVertex(Vector::ZERO);
+
+        assert_eq!(rect.rotate(QuarterTurns::Zero, pivot), rect);
+        let rotated = rect.rotate(QuarterTurns::One, pivot);
+        assert_eq!((rotated.width, rotated.height), (4, 2));
+        assert_eq!(rotated.area(), rect.area());
+        assert_eq!(
+            rect.rotate(QuarterTurns::Two, pivot),
+            Rectangle::new(Vector::new(-3, -3).into(), 2, 4)
+        );
+        assert_eq!(
+            rotated.rotate(QuarterTurns::Three, pivot),
+            rect,
+            "Rotating back should give the original"
+        );
+
+        let pivot = DynamicVertex(Vector::new(2, 1));
+        assert_eq!(
+            rect.rotate(QuarterTurns::Two, pivot),
+            Rectangle::new(Vector::new(1, -1).into(), 2, 4)
+        );
+    }
+
+    #[test]
+    pub fn test_flip() {
+        let rect = Rectangle::new(Vector::NORTH_EAST.into(), 2, 4);
+
+        assert_eq!(
+            rect.flip(FlipAxes::Horizontal, DynamicVertex(Vector::ZERO)),
+            Rectangle::new(Vector::new(-3, -1).into(), 2, 4)
+        );
+        assert_eq!(
+            rect.flip(FlipAxes::Vertical, DynamicVertex(Vector::new(0, 1))),
+            rect
         );
+        assert_eq!(rect.flip(FlipAxes::None, DynamicVertex(Vector::ZERO)), rect);
     }
 
     #[test]
@@ -164,7 +320,7 @@ mod tests {
         let rect = Rectangle::new(Vector::NORTH_EAST.into(), 2, 4);
         let deconstructed = rect.deconstruct_into_rectangles().collect_vec();
 
-        assert_eq!(deconstructed, [rect])
+        assert_eq!(deconstructed, [rect]);
     }
     #[test]
     pub fn test_iter() {
@@ -172,6 +328,66 @@ mod tests {
 
         let tiles = rect.into_iter().collect_vec();
 
-        assert_eq!(tiles.iter().join(";"), "(1,-1);(2,-1);(3,-1);(1,0);(2,0);(3,0);(1,1);(2,1);(3,1);(1,2);(2,2);(3,2);(1,3);(2,3);(3,3)")
+        assert_eq!(tiles.iter().join(";"), "(1,-1);(2,-1);(3,-1);(1,0);(2,0);(3,0);(1,1);(2,1);(3,1);(1,2);(2,2);(3,2);(1,3);(2,3);(3,3)");
+    }
+
+    #[test]
+    pub fn test_corners_and_vertices() {
+        let rect = Rectangle::new(Vector::NORTH_EAST.into(), 2, 3);
+
+        assert_eq!(rect.corners().iter().join(";"), "(1,-1);(3,-1);(3,2);(1,2)");
+        assert_eq!(
+            rect.iter_vertices().join(";"),
+            "(1,-1);(2,-1);(3,-1);(3,0);(3,1);(3,2);(2,2);(1,2);(1,1);(1,0)"
+        );
+        assert_eq!(rect.iter_vertices().len(), 10);
+        assert_eq!(
+            rect.iter_vertices().next_back(),
+            Some(Vector::new(1, 0).into())
+        );
+        assert!(rect
+            .corners()
+            .iter()
+            .all(|c| rect.iter_vertices().contains(c)));
+
+        let shape = Polyomino::<2>::new([Vector::ZERO, Vector::EAST]);
+        let rect = Rectangle::new(Vector::ZERO.into(), 2, 1);
+        assert_eq!(
+            rect.draw_outline().collect_vec(),
+            shape.draw_outline().collect_vec()
+        );
+    }
+
+    #[test]
+    pub fn test_border_tiles() {
+        let rect = Rectangle::new(Vector::NORTH_EAST.into(), 3, 3);
+        assert_eq!(
+            rect.iter_border_tiles().join(";"),
+            "(1,-1);(2,-1);(3,-1);(3,0);(3,1);(2,1);(1,1);(1,0)"
+        );
+
+        let border = |w, h| {
+            Rectangle::new(Vector::ZERO.into(), w, h)
+                .iter_border_tiles()
+                .join(";")
+        };
+        assert_eq!(border(3, 1), "(0,0);(1,0);(2,0)");
+        assert_eq!(border(1, 3), "(0,0);(0,1);(0,2)");
+        assert_eq!(border(1, 1), "(0,0)");
+        assert_eq!(border(0, 3), "");
+        assert_eq!(border(2, 2), "(0,0);(1,0);(1,1);(0,1)");
+
+        for (w, h) in (0..6).cartesian_product(0..6) {
+            let rect = Rectangle::new(Vector::new(-2, 1).into(), w, h);
+            let tiles = rect.iter_border_tiles().collect_vec();
+            assert_eq!(tiles.len(), rect.iter_border_tiles().len());
+            assert!(tiles.iter().all_unique(), "{w}x{h}");
+            let interior = usize::from(w.saturating_sub(2)) * usize::from(h.saturating_sub(2));
+            assert_eq!(tiles.len(), rect.area() - interior, "{w}x{h}");
+            assert!(tiles
+                .iter()
+                .tuple_windows()
+                .all(|(a, b)| (a.0 - b.0).x.abs() + (a.0 - b.0).y.abs() == 1));
+        }
     }
 }
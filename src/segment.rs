@@ -0,0 +1,145 @@
+use itertools::Itertools;
+
+use crate::prelude::*;
+
+/// Iterates the tiles whose interiors are crossed by the straight segment between two vertices, in order from `from` to `to`.
+/// Tiles which the segment only touches at a corner or along an edge are not included.
+#[allow(clippy::cast_possible_truncation)]
+pub fn iter_segment_tiles(
+    from: &DynamicVertex,
+    to: &DynamicVertex,
+) -> impl Iterator<Item = DynamicTile> + Clone {
+    let from = *from;
+    let dx = i32::from(to.x) - i32::from(from.x);
+    let dy = i32::from(to.y) - i32::from(from.y);
+    let (width, height) = (dx.abs(), dy.abs());
+
+    (0..width).flat_map(move |column| {
+        // The range of rows crossed while the segment is in this column
+        let low = height * column / width;
+        let high = (height * (column + 1) + width - 1) / width;
+
+        let x = if dx > 0 {
+            i32::from(from.x) + column
+        } else {
+            i32::from(from.x) - column - 1
+        };
+
+        (low..high).map(move |row| {
+            let y = if dy > 0 {
+                i32::from(from.y) + row
+            } else {
+                i32::from(from.y) - row - 1
+            };
+            DynamicTile(Vector::new(x as i8, y as i8))
+        })
+    })
+}
+
+/// Iterates the unit edges of the grid which are crossed by or lie along the straight segment between two vertices, in order from `from` to `to`.
+/// Each edge is given as a pair of vertices, smallest first.
+/// Edges which the segment only touches at one end are not included.
+#[allow(clippy::cast_possible_truncation)]
+pub fn iter_segment_edges(
+    from: &DynamicVertex,
+    to: &DynamicVertex,
+) -> impl Iterator<Item = (DynamicVertex, DynamicVertex)> + Clone {
+    let start = *from;
+    let dx = i32::from(to.x) - i32::from(from.x);
+    let dy = i32::from(to.y) - i32::from(from.y);
+
+    let edges_along = if dx == 0 || dy == 0 {
+        dx.abs() + dy.abs()
+    } else {
+        0
+    };
+    let step = Vector::new(dx.signum() as i8, dy.signum() as i8);
+
+    let along = (0..edges_along).map(move |index| {
+        let a = start.const_add(step.const_mul(index as i8));
+        let b = a.const_add(step);
+        (a.min(b), a.max(b))
+    });
+
+    let crossed = iter_segment_tiles(from, to)
+        .tuple_windows()
+        .filter_map(|(a, b)| shared_edge(a, b));
+
+    along.chain(crossed)
+}
+
+/// The edge between two orthogonally adjacent tiles
+fn shared_edge(a: DynamicTile, b: DynamicTile) -> Option<(DynamicVertex, DynamicVertex)> {
+    let corners = match b.0.const_sub(a.0) {
+        Vector::NORTH => (Corner::NorthWest, Corner::NorthEast),
+        Vector::EAST => (Corner::NorthEast, Corner::SouthEast),
+        Vector::SOUTH => (Corner::SouthWest, Corner::SouthEast),
+        Vector::WEST => (Corner::NorthWest, Corner::SouthWest),
+        _ => return None,
+    };
+    Some((a.get_vertex(&corners.0), a.get_vertex(&corners.1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(x: i8, y: i8) -> DynamicVertex {
+        DynamicVertex(Vector::new(x, y))
+    }
+
+    fn tiles(from: DynamicVertex, to: DynamicVertex) -> String {
+        iter_segment_tiles(&from, &to).join(";")
+    }
+
+    fn edges(from: DynamicVertex, to: DynamicVertex) -> String {
+        iter_segment_edges(&from, &to)
+            .map(|(a, b)| format!("{a}-{b}"))
+            .join(";")
+    }
+
+    #[test]
+    fn test_shallow_segment() {
+        assert_eq!(tiles(v(0, 0), v(3, 2)), "(0,0);(1,0);(1,1);(2,1)");
+        assert_eq!(
+            edges(v(0, 0), v(3, 2)),
+            "(1,0)-(1,1);(1,1)-(2,1);(2,1)-(2,2)"
+        );
+
+        assert_eq!(tiles(v(3, 2), v(0, 0)), "(2,1);(1,1);(1,0);(0,0)");
+        assert_eq!(
+            edges(v(3, 2), v(0, 0)),
+            "(2,1)-(2,2);(1,1)-(2,1);(1,0)-(1,1)"
+        );
+    }
+
+    #[test]
+    fn test_steep_segment() {
+        assert_eq!(tiles(v(1, 0), v(0, 2)), "(0,0);(0,1)");
+        assert_eq!(edges(v(1, 0), v(0, 2)), "(0,1)-(1,1)");
+
+        assert_eq!(tiles(v(-1, -1), v(1, -4)), "(-1,-2);(-1,-3);(0,-3);(0,-4)");
+    }
+
+    #[test]
+    fn test_diagonal_segment() {
+        assert_eq!(tiles(v(0, 0), v(2, 2)), "(0,0);(1,1)");
+        assert_eq!(edges(v(0, 0), v(2, 2)), "");
+
+        assert_eq!(tiles(v(0, 0), v(4, 2)), "(0,0);(1,0);(2,1);(3,1)");
+        assert_eq!(edges(v(0, 0), v(4, 2)), "(1,0)-(1,1);(3,1)-(3,2)");
+    }
+
+    #[test]
+    fn test_axis_aligned_segment() {
+        assert_eq!(tiles(v(0, 0), v(3, 0)), "");
+        assert_eq!(
+            edges(v(0, 0), v(3, 0)),
+            "(0,0)-(1,0);(1,0)-(2,0);(2,0)-(3,0)"
+        );
+        assert_eq!(edges(v(2, 2), v(2, 0)), "(2,1)-(2,2);(2,0)-(2,1)");
+
+        assert_eq!(tiles(v(1, 1), v(1, 1)), "");
+        assert_eq!(edges(v(1, 1), v(1, 1)), "");
+    }
+}
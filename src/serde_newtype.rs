@@ -0,0 +1,37 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
+
+/// Deserialize the inner value of a newtype struct called `name`, in the same format as `#[derive(Deserialize)]`.
+/// Used by types which need to validate the inner value before constructing themselves.
+pub(crate) fn deserialize_newtype<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+    name: &'static str,
+) -> Result<T, D::Error> {
+    struct NewtypeVisitor<T>(&'static str, PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>> Visitor<'de> for NewtypeVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "tuple struct {}", self.0)
+        }
+
+        fn visit_newtype_struct<E: Deserializer<'de>>(
+            self,
+            deserializer: E,
+        ) -> Result<Self::Value, E::Error> {
+            T::deserialize(deserializer)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            seq.next_element()?
+                .ok_or_else(|| de::Error::invalid_length(0, &self))
+        }
+    }
+
+    deserializer.deserialize_newtype_struct(name, NewtypeVisitor(name, PhantomData))
+}
@@ -8,4 +8,52 @@ pub trait Shape :// Flippable + Rotatable +
 
     fn draw_outline(&self)-> Self::OutlineIter;
     fn deconstruct_into_rectangles(&self)-> Self::RectangleIter;
+
+    /// Whether this point is within one of the tiles of the shape, when each tile has sides of length `scale`.
+    /// Points on the north or west edge of a tile are inside it; points on its south or east edge are not.
+    /// The default implementation checks each of the rectangles the shape deconstructs into.
+    #[cfg(any(test, feature = "glam"))]
+    fn contains_point(&self, point: glam::f32::Vec2, scale: f32)-> bool {
+        self.deconstruct_into_rectangles()
+            .any(|rectangle| rectangle.contains_point(point, scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A shape which only implements the required methods
+    struct Row(Rectangle);
+
+    impl IntoIterator for Row {
+        type Item = DynamicTile;
+        type IntoIter = <Rectangle as IntoIterator>::IntoIter;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.into_iter()
+        }
+    }
+
+    impl Shape for Row {
+        type OutlineIter = <Rectangle as Shape>::OutlineIter;
+        type RectangleIter = <Rectangle as Shape>::RectangleIter;
+
+        fn draw_outline(&self) -> Self::OutlineIter {
+            self.0.draw_outline()
+        }
+
+        fn deconstruct_into_rectangles(&self) -> Self::RectangleIter {
+            self.0.deconstruct_into_rectangles()
+        }
+    }
+
+    #[test]
+    fn test_default_contains_point() {
+        let row = Row(Rectangle::new(Vector::new(1, 0).into(), 3, 1));
+        assert!(row.contains_point(glam::f32::Vec2::new(2.5, 0.5), 1.0));
+        assert!(row.contains_point(glam::f32::Vec2::new(7.0, 0.0), 2.0));
+        assert!(!row.contains_point(glam::f32::Vec2::new(0.5, 0.5), 1.0));
+        assert!(!row.contains_point(glam::f32::Vec2::new(2.5, 1.0), 1.0));
+    }
 }
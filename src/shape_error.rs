@@ -0,0 +1,46 @@
+use core::fmt;
+
+/// An error from a shape whose tiles cannot form a valid outline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShapeError {
+    /// The shape has no tiles
+    Empty,
+    /// The shape contains the same tile more than once
+    DuplicateTile,
+    /// The tiles of the shape are not all orthogonally connected
+    Disconnected,
+}
+
+impl ShapeError {
+    /// A short description of the error, usable in const contexts
+    #[must_use]
+    pub const fn message(&self) -> &'static str {
+        match self {
+            ShapeError::Empty => "Shape has no tiles",
+            ShapeError::DuplicateTile => "Shape contains a duplicate tile",
+            ShapeError::Disconnected => "Shape is not connected",
+        }
+    }
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl core::error::Error for ShapeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(ShapeError::Empty.to_string(), "Shape has no tiles");
+        assert_eq!(
+            ShapeError::Disconnected.to_string(),
+            "Shape is not connected"
+        );
+    }
+}
@@ -0,0 +1,196 @@
+use crate::prelude::*;
+
+/// A map from tiles to values which only stores values for tiles which are present.
+/// The present tiles are stored in a tile set of type `S`, such as `TileSet64<8, 8, 64>`.
+/// The values are stored compactly in tile order, and indexed by their rank in the set of present tiles.
+/// Requires `std`
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SparseTileMap<T, const WIDTH: u8, const HEIGHT: u8, S: TileSetTrait<WIDTH, HEIGHT>> {
+    tiles: S,
+    values: Vec<T>,
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, S: TileSetTrait<WIDTH, HEIGHT>> Default
+    for SparseTileMap<T, WIDTH, HEIGHT, S>
+{
+    fn default() -> Self {
+        Self {
+            tiles: S::EMPTY,
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, S: TileSetTrait<WIDTH, HEIGHT>>
+    SparseTileMap<T, WIDTH, HEIGHT, S>
+{
+    /// The tiles which have values
+    pub const fn tiles(&self) -> &S {
+        &self.tiles
+    }
+
+    /// The number of tiles which have values
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    #[must_use]
+    pub fn contains(&self, tile: Tile<WIDTH, HEIGHT>) -> bool {
+        self.tiles.get_bit(&tile)
+    }
+
+    #[must_use]
+    pub fn get(&self, tile: Tile<WIDTH, HEIGHT>) -> Option<&T> {
+        if self.contains(tile) {
+            self.values.get(self.index_of(tile))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn get_mut(&mut self, tile: Tile<WIDTH, HEIGHT>) -> Option<&mut T> {
+        if self.contains(tile) {
+            let index = self.index_of(tile);
+            self.values.get_mut(index)
+        } else {
+            None
+        }
+    }
+
+    /// Set the value at a tile, returning the previous value if there was one
+    pub fn insert(&mut self, tile: Tile<WIDTH, HEIGHT>, value: T) -> Option<T> {
+        let index = self.index_of(tile);
+        if self.contains(tile) {
+            Some(core::mem::replace(&mut self.values[index], value))
+        } else {
+            self.tiles.set_bit(&tile, true);
+            self.values.insert(index, value);
+            None
+        }
+    }
+
+    /// Remove the value at a tile, returning it if there was one
+    pub fn remove(&mut self, tile: Tile<WIDTH, HEIGHT>) -> Option<T> {
+        if self.contains(tile) {
+            self.tiles.set_bit(&tile, false);
+            Some(self.values.remove(self.index_of(tile)))
+        } else {
+            None
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.tiles = S::EMPTY;
+        self.values.clear();
+    }
+
+    /// Iterate through the tiles and values, in tile order
+    pub fn iter(
+        &self,
+    ) -> impl ExactSizeIterator<Item = (Tile<WIDTH, HEIGHT>, &T)> + DoubleEndedIterator + Clone
+    {
+        self.tiles.iter_true_tiles().zip(self.values.iter())
+    }
+
+    /// Mutably iterate through the tiles and values, in tile order
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl ExactSizeIterator<Item = (Tile<WIDTH, HEIGHT>, &mut T)> + DoubleEndedIterator {
+        self.tiles.iter_true_tiles().zip(self.values.iter_mut())
+    }
+
+    /// The index in the values of a tile, whether or not it is present
+    fn index_of(&self, tile: Tile<WIDTH, HEIGHT>) -> usize {
+        self.tiles.tiles_before(tile) as usize
+    }
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, S: TileSetTrait<WIDTH, HEIGHT>>
+    FromIterator<(Tile<WIDTH, HEIGHT>, T)> for SparseTileMap<T, WIDTH, HEIGHT, S>
+{
+    fn from_iter<I: IntoIterator<Item = (Tile<WIDTH, HEIGHT>, T)>>(iter: I) -> Self {
+        let mut result = Self::default();
+        for (tile, value) in iter {
+            result.insert(tile, value);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut map: SparseTileMap<char, 3, 3, TileSet16<3, 3, 9>> = SparseTileMap::default();
+        assert!(map.is_empty());
+
+        assert_eq!(map.insert(Tile::CENTER, 'b'), None);
+        assert_eq!(map.insert(Tile::SOUTH_EAST, 'c'), None);
+        assert_eq!(map.insert(Tile::NORTH_WEST, 'a'), None);
+        assert_eq!(map.insert(Tile::CENTER, 'x'), Some('b'));
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(Tile::CENTER), Some(&'x'));
+        assert_eq!(map.get(Tile::NORTH_EAST), None);
+        assert_eq!(
+            map.iter().map(|(t, v)| format!("{t}{v}")).join("|"),
+            "(0,0)a|(1,1)x|(2,2)c"
+        );
+
+        assert_eq!(map.remove(Tile::CENTER), Some('x'));
+        assert_eq!(map.remove(Tile::CENTER), None);
+        assert_eq!(
+            map.iter().map(|(t, v)| format!("{t}{v}")).join("|"),
+            "(0,0)a|(2,2)c"
+        );
+        assert_eq!(map.tiles().count(), 2);
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut map: SparseTileMap<u32, 4, 4, TileSet16<4, 4, 16>> = Tile::<4, 4>::iter_by_row()
+            .filter(|t| t.x() == t.y())
+            .map(|t| (t, u32::from(t.inner())))
+            .collect();
+
+        *map.get_mut(Tile::new_const::<2, 2>()).unwrap() += 100;
+        assert_eq!(map.get_mut(Tile::new_const::<2, 1>()), None);
+
+        for (_, value) in map.iter_mut() {
+            *value += 1;
+        }
+
+        assert_eq!(map.iter().map(|(_, v)| v).join("|"), "1|6|111|16");
+    }
+
+    #[test]
+    fn test_smaller_than_tile_map() {
+        let map: SparseTileMap<u64, 12, 12, TileSet256<12, 12, 144>> =
+            [(Tile::NORTH_WEST, 1), (Tile::SOUTH_EAST, 2)]
+                .into_iter()
+                .collect();
+        assert_eq!(
+            map.iter().map(|(t, v)| format!("{t}{v}")).join("|"),
+            "(0,0)1|(11,11)2"
+        );
+
+        assert!(
+            core::mem::size_of::<SparseTileMap<u64, 12, 12, TileSet256<12, 12, 144>>>()
+                < core::mem::size_of::<TileMap<Option<u64>, 12, 12, 144>>()
+        );
+    }
+}
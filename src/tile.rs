@@ -5,10 +5,12 @@ use crate::prelude::*;
 #[cfg(any(test, feature = "serde"))]
 use serde::{Deserialize, Serialize};
 
-/// A tile in 2d space
+/// A tile in 2d space.
+/// Tiles are stored as their index in a `u8`, which limits grids to 256 tiles.
+/// Use `Tile16`, `TileMap16` and `TileSet1024` for larger grids.
 #[must_use]
 #[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize))]
 pub struct Tile<const WIDTH: u8, const HEIGHT: u8>(u8);
 
 impl<const WIDTH: u8, const HEIGHT: u8> From<Tile<WIDTH, HEIGHT>> for DynamicTile {
@@ -55,6 +57,17 @@ impl<const WIDTH: u8, const HEIGHT: u8> Display for Tile<WIDTH, HEIGHT> {
         write!(f, "({},{})", self.x(), self.y())
     }
 }
+
+impl<const WIDTH: u8, const HEIGHT: u8> core::str::FromStr for Tile<WIDTH, HEIGHT> {
+    type Err = ParseCoordinatesError;
+
+    /// Parses tiles of the form `(x,y)` or `x,y`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = crate::parse_coordinates_error::parse_coordinates(s)?;
+        Self::try_new(x, y).ok_or(ParseCoordinatesError::OutOfBounds)
+    }
+}
+
 impl<const WIDTH: u8, const HEIGHT: u8> core::fmt::Debug for Tile<WIDTH, HEIGHT> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "({},{})", self.x(), self.y())
@@ -74,6 +87,23 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
 
     pub const CENTER: Self = Self::new_unchecked(WIDTH / 2, HEIGHT / 2);
 
+    /// Every tile in the grid in row order, as an array that can be built and used in const contexts.
+    /// `SIZE` must be `COUNT`; `try_from_usize` gets a single tile by index.
+    ///
+    /// # Panics
+    /// If `SIZE` is not the number of tiles in the grid
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn all<const SIZE: usize>() -> [Self; SIZE] {
+        DimensionError::assert(Self::COUNT, SIZE, u8::MAX as usize + 1);
+        let mut result = [Self(0); SIZE];
+        let mut index = 0;
+        while index < SIZE {
+            result[index] = Self(index as u8);
+            index += 1;
+        }
+        result
+    }
+
     pub const fn new_const<const X: u8, const Y: u8>() -> Self {
         Self::new_unchecked(X, Y)
     }
@@ -81,7 +111,7 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
     pub(crate) const fn new_unchecked(x: u8, y: u8) -> Self {
         debug_assert!(x < WIDTH);
         debug_assert!(y < HEIGHT);
-        debug_assert!(Self::COUNT <= u8::MAX as usize);
+        debug_assert!(Self::COUNT <= u8::MAX as usize + 1);
         Self(x + (WIDTH * y))
     }
 
@@ -124,6 +154,18 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
         self.0 / WIDTH
     }
 
+    /// The tile in the same row with column `x`, if it is in the grid
+    #[must_use]
+    pub const fn try_with_x(&self, x: u8) -> Option<Self> {
+        Self::try_new(x, self.y())
+    }
+
+    /// The tile in the same column with row `y`, if it is in the grid
+    #[must_use]
+    pub const fn try_with_y(&self, y: u8) -> Option<Self> {
+        Self::try_new(self.x(), y)
+    }
+
     #[must_use]
     pub const fn inner(&self) -> u8 {
         self.0
@@ -163,6 +205,18 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
         }
     }
 
+    /// This tile in the grid rotated one quarter turn clockwise, which has its width and height swapped.
+    /// For square grids this is the same as `rotate(QuarterTurns::One)`.
+    pub const fn rotate_clockwise(&self) -> Tile<HEIGHT, WIDTH> {
+        Tile::new_unchecked(HEIGHT - 1 - self.y(), self.x())
+    }
+
+    /// This tile in the grid rotated one quarter turn anticlockwise, which has its width and height swapped.
+    /// For square grids this is the same as `rotate(QuarterTurns::Three)`.
+    pub const fn rotate_anticlockwise(&self) -> Tile<HEIGHT, WIDTH> {
+        Tile::new_unchecked(self.y(), WIDTH - 1 - self.x())
+    }
+
     #[must_use]
     pub const fn try_next(&self) -> Option<Self> {
         let Some(next) = self.inner().checked_add(1) else {
@@ -171,11 +225,71 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
         Self::try_from_inner(next)
     }
 
+    #[must_use]
+    pub const fn try_prev(&self) -> Option<Self> {
+        let Some(prev) = self.inner().checked_sub(1) else {
+            return None;
+        };
+        Self::try_from_inner(prev)
+    }
+
+    /// Iterate through the tiles from `from` to `to` (inclusive) in row order.
+    /// The iterator will be empty if `to` comes before `from`.
+    pub fn iter_range(
+        from: Self,
+        to: Self,
+    ) -> impl FusedIterator<Item = Self> + Clone + ExactSizeIterator + DoubleEndedIterator {
+        (from.0..=to.0).map(Self)
+    }
+
     /// Iterate through all tiles by row
     /// This method has better performance than `iter_by_col`
     pub fn iter_by_row(
     ) -> impl FusedIterator<Item = Self> + Clone + ExactSizeIterator + DoubleEndedIterator {
-        (0..(WIDTH * HEIGHT)).map(Self)
+        // `WIDTH * HEIGHT` may be 256, which does not fit in a `u8`
+        (0..=u8::MAX).take(Self::COUNT).map(Self)
+    }
+
+    /// Iterate through all tiles in the given rows, by row.
+    /// Rows outside the grid are ignored.
+    pub fn iter_rows_range(
+        rows: core::ops::Range<u8>,
+    ) -> impl FusedIterator<Item = Self> + Clone + ExactSizeIterator + DoubleEndedIterator {
+        let end = rows.end.min(HEIGHT);
+        let start = rows.start.min(end);
+        // The indices are computed in `usize` as the end may be 256
+        let width = usize::from(WIDTH);
+        (0..=u8::MAX)
+            .take(usize::from(end) * width)
+            .skip(usize::from(start) * width)
+            .map(Self)
+    }
+
+    /// Iterate through the tiles which are in the rectangle, by row.
+    /// Parts of the rectangle outside the grid are ignored.
+    #[must_use]
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn iter_region(
+        rectangle: Rectangle,
+    ) -> impl FusedIterator<Item = Self> + Clone + ExactSizeIterator + DoubleEndedIterator {
+        let clamp = |value: i16, max: u8| value.clamp(0, i16::from(max)) as u8;
+        let north_west = rectangle.north_west.0;
+        let min_x = clamp(i16::from(north_west.x), WIDTH);
+        let max_x = clamp(i16::from(north_west.x) + i16::from(rectangle.width), WIDTH);
+        let min_y = clamp(i16::from(north_west.y), HEIGHT);
+        let max_y = clamp(
+            i16::from(north_west.y) + i16::from(rectangle.height),
+            HEIGHT,
+        );
+
+        let width = max_x - min_x;
+        let count = usize::from(width) * usize::from(max_y - min_y);
+
+        (0..count).map(move |index| {
+            let index = index as u8;
+            Self::new_unchecked(min_x + (index % width), min_y + (index / width))
+        })
     }
 
     /// Iterate through all tiles by column
@@ -197,16 +311,40 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
         }
     }
 
+    /// Iterate through the neighbours of this tile which are in the grid, in the order of the neighbourhood's offsets
+    #[must_use]
+    pub fn iter_neighbours(
+        self,
+        neighbourhood: Neighbourhood<'_>,
+    ) -> impl FusedIterator<Item = Self> + DoubleEndedIterator + Clone + '_ {
+        neighbourhood
+            .offsets()
+            .iter()
+            .filter_map(move |v| self.const_add(v))
+    }
+
     /// Iterate through adjacent elements (includes diagonals)
     #[must_use]
     pub fn iter_adjacent(self) -> impl FusedIterator<Item = Self> + DoubleEndedIterator + Clone {
-        Vector::UNITS.into_iter().filter_map(move |v| self + v)
+        self.iter_neighbours(Neighbourhood::MOORE)
     }
 
     /// Iterate through contiguous elements (does not include diagonals)
     #[must_use]
     pub fn iter_contiguous(self) -> impl FusedIterator<Item = Self> + DoubleEndedIterator + Clone {
-        Vector::CARDINALS.into_iter().filter_map(move |v| self + v)
+        self.iter_neighbours(Neighbourhood::VON_NEUMANN)
+    }
+
+    /// Iterate through the tiles reached by repeatedly adding `direction` to this tile, until leaving the grid.
+    /// Does not include this tile. If `direction` is zero, the iterator is empty.
+    #[must_use]
+    pub fn iter_ray(self, direction: Vector) -> impl FusedIterator<Item = Self> + Clone {
+        let first = if direction.x == 0 && direction.y == 0 {
+            None
+        } else {
+            self.const_add(&direction)
+        };
+        core::iter::successors(first, move |tile| tile.const_add(&direction))
     }
 
     /// Whether two tiles are adjacent (includes diagonals)
@@ -242,6 +380,83 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
         Self::try_new(c, r)
     }
 
+    /// The angle in radians from this tile to `other`, measured from east.
+    /// As y increases to the south, positive angles are clockwise.
+    /// Requires `std` or `libm`
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn angle_to(&self, other: &Self) -> f32 {
+        let dx = f32::from(other.x()) - f32::from(self.x());
+        let dy = f32::from(other.y()) - f32::from(self.y());
+        crate::float_math::atan2(dy, dx)
+    }
+
+    /// The euclidean distance between the centers of this tile and `other`.
+    /// Requires `std` or `libm`
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn distance_to(&self, other: &Self) -> f32 {
+        let dx = f32::from(other.x()) - f32::from(self.x());
+        let dy = f32::from(other.y()) - f32::from(self.y());
+        crate::float_math::sqrt(dx * dx + dy * dy)
+    }
+
+    /// The offset from this tile to `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the offset does not fit in a `Vector`.
+    /// This cannot happen if the grid is at most 128 tiles wide and high.
+    #[must_use = "this returns the offset without changing either tile"]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn vector_to(&self, other: &Self) -> Vector {
+        let (x, y) = self.offset_to(*other);
+        assert!(
+            x >= i8::MIN as i16
+                && x <= i8::MAX as i16
+                && y >= i8::MIN as i16
+                && y <= i8::MAX as i16,
+            "The offset between the tiles does not fit in a Vector"
+        );
+        Vector::new(x as i8, y as i8)
+    }
+
+    const fn offset_to(self, other: Self) -> (i16, i16) {
+        (
+            other.x() as i16 - self.x() as i16,
+            other.y() as i16 - self.y() as i16,
+        )
+    }
+
+    /// The unit vector pointing from this tile to `other`, if they share a row, column or diagonal.
+    /// Returns `None` if the tiles are equal.
+    #[must_use]
+    pub const fn direction_to(&self, other: &Self) -> Option<Vector> {
+        let (x, y) = self.offset_to(*other);
+        if x == 0 && y == 0 {
+            return None;
+        }
+        if x == 0 || y == 0 || x.abs() == y.abs() {
+            Some(Vector::new(x.signum() as i8, y.signum() as i8))
+        } else {
+            None
+        }
+    }
+
+    /// The neighbouring tile in the direction of `vector`, if it is in the grid
+    #[must_use]
+    #[inline]
+    pub const fn neighbor(&self, vector: Vector) -> Option<Self> {
+        self.const_add(&vector)
+    }
+
+    /// The neighbouring tile in a direction, if it is in the grid
+    #[must_use]
+    #[inline]
+    pub const fn neighbor_dir(&self, direction: Direction) -> Option<Self> {
+        self.const_add(&direction.vector())
+    }
+
     #[must_use]
     pub const fn get_vertex(&self, corner: &Corner) -> Option<Vertex<WIDTH, HEIGHT>> {
         match corner {
@@ -252,6 +467,36 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
         }
     }
 
+    /// Get the vertex at a corner of this tile.
+    /// Always succeeds because every tile has four vertices.
+    pub(crate) const fn get_vertex_unchecked(self, corner: Corner) -> Vertex<WIDTH, HEIGHT> {
+        match corner {
+            Corner::NorthWest => Vertex::new_unchecked(self.x(), self.y()),
+            Corner::NorthEast => Vertex::new_unchecked(self.x() + 1, self.y()),
+            Corner::SouthWest => Vertex::new_unchecked(self.x(), self.y() + 1),
+            Corner::SouthEast => Vertex::new_unchecked(self.x() + 1, self.y() + 1),
+        }
+    }
+
+    /// The four vertices of this tile, in the order of `Corner::ALL`
+    pub const fn vertices(&self) -> [Vertex<WIDTH, HEIGHT>; 4] {
+        [
+            self.get_vertex_unchecked(Corner::NorthWest),
+            self.get_vertex_unchecked(Corner::NorthEast),
+            self.get_vertex_unchecked(Corner::SouthWest),
+            self.get_vertex_unchecked(Corner::SouthEast),
+        ]
+    }
+
+    /// Iterate through the four vertices of this tile, in the order of `Corner::ALL`
+    #[must_use]
+    pub fn iter_vertices(
+        self,
+    ) -> impl FusedIterator<Item = Vertex<WIDTH, HEIGHT>> + ExactSizeIterator + DoubleEndedIterator + Clone
+    {
+        self.vertices().into_iter()
+    }
+
     pub const fn get_north_west_vertex(&self) -> Vertex<WIDTH, HEIGHT> {
         Vertex::new_unchecked(self.x(), self.y())
     }
@@ -263,6 +508,90 @@ impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
         self.x().abs_diff(other.x()) + self.y().abs_diff(other.y())
     }
 
+    /// Returns the Chebyshev distance between two tiles.
+    /// Also known as the chessboard distance, the Chebyshev distance is the greater of the distances in the two axes.
+    #[must_use]
+    pub const fn chebyshev_distance(&self, other: &Self) -> u8 {
+        let dx = self.x().abs_diff(other.x());
+        let dy = self.y().abs_diff(other.y());
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    /// Iterate through all tiles within `radius` Manhattan distance of this tile (including this tile), in row order.
+    #[must_use]
+    pub fn iter_within_manhattan(&self, radius: u8) -> impl FusedIterator<Item = Self> + Clone {
+        let center = *self;
+        let min_y = center.y().saturating_sub(radius);
+        let max_y = center.y().saturating_add(radius).min(Self::MAX_ROW);
+        (min_y..=max_y).flat_map(move |y| {
+            let remaining = radius - center.y().abs_diff(y);
+            let min_x = center.x().saturating_sub(remaining);
+            let max_x = center.x().saturating_add(remaining).min(Self::MAX_COL);
+            (min_x..=max_x).map(move |x| Self::new_unchecked(x, y))
+        })
+    }
+
+    /// Returns true if this tile is in the northern half of the grid.
+    /// If the grid has an odd height, tiles in the middle row are in neither half.
+    #[must_use]
+    pub const fn is_in_north_half(&self) -> bool {
+        (self.y() as u16 * 2 + 1) < HEIGHT as u16
+    }
+
+    /// Returns true if this tile is in the southern half of the grid.
+    /// If the grid has an odd height, tiles in the middle row are in neither half.
+    #[must_use]
+    pub const fn is_in_south_half(&self) -> bool {
+        (self.y() as u16 * 2 + 1) > HEIGHT as u16
+    }
+
+    /// Returns true if this tile is in the western half of the grid.
+    /// If the grid has an odd width, tiles in the middle column are in neither half.
+    #[must_use]
+    pub const fn is_in_west_half(&self) -> bool {
+        (self.x() as u16 * 2 + 1) < WIDTH as u16
+    }
+
+    /// Returns true if this tile is in the eastern half of the grid.
+    /// If the grid has an odd width, tiles in the middle column are in neither half.
+    #[must_use]
+    pub const fn is_in_east_half(&self) -> bool {
+        (self.x() as u16 * 2 + 1) > WIDTH as u16
+    }
+
+    /// The quadrant of the grid containing this tile, named by the corner of the grid it contains.
+    /// Returns `None` for tiles in the middle row or column of a grid with an odd width or height.
+    #[must_use]
+    pub const fn quadrant(&self) -> Option<Corner> {
+        match (
+            self.is_in_north_half(),
+            self.is_in_south_half(),
+            self.is_in_west_half(),
+            self.is_in_east_half(),
+        ) {
+            (true, _, true, _) => Some(Corner::NorthWest),
+            (true, _, _, true) => Some(Corner::NorthEast),
+            (_, true, true, _) => Some(Corner::SouthWest),
+            (_, true, _, true) => Some(Corner::SouthEast),
+            _ => None,
+        }
+    }
+
+    /// Returns true if this tile is in the quadrant of the grid containing `corner`
+    #[must_use]
+    pub const fn is_in_quadrant(&self, corner: Corner) -> bool {
+        match corner {
+            Corner::NorthWest => self.is_in_north_half() && self.is_in_west_half(),
+            Corner::NorthEast => self.is_in_north_half() && self.is_in_east_half(),
+            Corner::SouthWest => self.is_in_south_half() && self.is_in_west_half(),
+            Corner::SouthEast => self.is_in_south_half() && self.is_in_east_half(),
+        }
+    }
+
     /// Returns true if this is an edge tile (or corner tile)
     #[must_use]
     pub const fn is_edge(&self) -> bool {
@@ -305,6 +634,18 @@ impl<const L: u8> Tile<L, L> {
     }
 }
 
+impl<const WIDTH: u8, const HEIGHT: u8> Flip for Tile<WIDTH, HEIGHT> {
+    fn flip(&self, axes: FlipAxes) -> Self {
+        Tile::flip(self, axes)
+    }
+}
+
+impl<const L: u8> Rotate for Tile<L, L> {
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        Tile::rotate(self, quarter_turns)
+    }
+}
+
 #[cfg(any(test, feature = "glam"))]
 impl<const C: u8, const R: u8> HasCenter for Tile<C, R> {
     fn get_center(&self, scale: f32) -> glam::f32::Vec2 {
@@ -315,6 +656,82 @@ impl<const C: u8, const R: u8> HasCenter for Tile<C, R> {
     }
 }
 
+#[cfg(any(test, feature = "glam"))]
+impl<const WIDTH: u8, const HEIGHT: u8> Tile<WIDTH, HEIGHT> {
+    /// Gets the tile containing this point, if it is within the grid.
+    /// This is the inverse of `get_center`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn try_from_center(point: glam::f32::Vec2, scale: f32) -> Option<Self> {
+        let x = (point.x / scale).floor();
+        let y = (point.y / scale).floor();
+
+        if !(x >= 0.0 && x < f32::from(WIDTH) && y >= 0.0 && y < f32::from(HEIGHT)) {
+            return None;
+        }
+
+        Self::try_new(x as u8, y as u8)
+    }
+}
+
+#[cfg(any(test, feature = "serde"))]
+impl<'de, const WIDTH: u8, const HEIGHT: u8> Deserialize<'de> for Tile<WIDTH, HEIGHT> {
+    /// Deserializes the index in the same format as it is serialized, failing if it is outside the grid
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner: u8 = crate::serde_newtype::deserialize_newtype(deserializer, "Tile")?;
+        Self::try_from_inner(inner).ok_or_else(|| {
+            serde::de::Error::custom(format_args!(
+                "tile index {inner} is outside a {WIDTH}x{HEIGHT} grid"
+            ))
+        })
+    }
+}
+
+/// Serialize and deserialize a tile as a struct with `x` and `y` fields, rather than as its index.
+/// Use with `#[serde(with = "geometrid::tile::serde_tile_xy")]`
+#[cfg(any(test, feature = "serde"))]
+pub mod serde_tile_xy {
+    use super::Tile;
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Tile")]
+    struct TileXY {
+        x: u8,
+        y: u8,
+    }
+
+    /// Serialize a tile as a struct with `x` and `y` fields
+    ///
+    /// # Errors
+    /// If the serializer fails
+    pub fn serialize<S: Serializer, const WIDTH: u8, const HEIGHT: u8>(
+        tile: &Tile<WIDTH, HEIGHT>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        TileXY {
+            x: tile.x(),
+            y: tile.y(),
+        }
+        .serialize(serializer)
+    }
+
+    /// Deserialize a tile from a struct with `x` and `y` fields
+    ///
+    /// # Errors
+    /// If the fields are missing or the tile is outside the grid
+    pub fn deserialize<'de, D: Deserializer<'de>, const WIDTH: u8, const HEIGHT: u8>(
+        deserializer: D,
+    ) -> Result<Tile<WIDTH, HEIGHT>, D::Error> {
+        let TileXY { x, y } = TileXY::deserialize(deserializer)?;
+        Tile::try_new(x, y).ok_or_else(|| {
+            de::Error::custom(format_args!(
+                "tile ({x},{y}) is outside a {WIDTH}x{HEIGHT} grid"
+            ))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,7 +745,7 @@ mod tests {
         assert_eq!(
             str,
             "(0,0)|(1,0)|(2,0)|(0,1)|(1,1)|(2,1)|(0,2)|(1,2)|(2,2)|(0,3)|(1,3)|(2,3)",
-        )
+        );
     }
 
     #[test]
@@ -338,14 +755,14 @@ mod tests {
         assert_eq!(
             str,
             "(0,0)|(0,1)|(0,2)|(0,3)|(1,0)|(1,1)|(1,2)|(1,3)|(2,0)|(2,1)|(2,2)|(2,3)",
-        )
+        );
     }
 
     #[test]
     fn test_from() {
         for tile in Tile::<3, 4>::iter_by_row() {
             let n = Tile::try_new(tile.x(), tile.y()).unwrap();
-            assert_eq!(tile, n)
+            assert_eq!(tile, n);
         }
     }
 
@@ -355,7 +772,7 @@ mod tests {
             .map(|x| x.flip(FlipAxes::Vertical))
             .join("|");
 
-        assert_eq!(str, "(0,2)|(1,2)|(2,2)|(0,1)|(1,1)|(2,1)|(0,0)|(1,0)|(2,0)")
+        assert_eq!(str, "(0,2)|(1,2)|(2,2)|(0,1)|(1,1)|(2,1)|(0,0)|(1,0)|(2,0)");
     }
 
     #[test]
@@ -364,7 +781,7 @@ mod tests {
             .map(|x| x.rotate(QuarterTurns::One))
             .join("|");
 
-        assert_eq!(str, "(2,0)|(2,1)|(2,2)|(1,0)|(1,1)|(1,2)|(0,0)|(0,1)|(0,2)")
+        assert_eq!(str, "(2,0)|(2,1)|(2,2)|(1,0)|(1,1)|(1,2)|(0,0)|(0,1)|(0,2)");
     }
 
     #[test]
@@ -385,6 +802,22 @@ mod tests {
         assert_eq!(tile.rotate(QuarterTurns::Three), Tile::new_const::<0, 3>());
     }
 
+    #[test]
+    fn test_from_str() {
+        for tile in Tile::<3, 4>::iter_by_row() {
+            assert_eq!(tile.to_string().parse(), Ok(tile));
+        }
+        assert_eq!("2, 1".parse(), Ok(Tile::<3, 4>::new_const::<2, 1>()));
+        assert_eq!(
+            "(3,1)".parse::<Tile<3, 4>>(),
+            Err(ParseCoordinatesError::OutOfBounds)
+        );
+        assert_eq!(
+            "(a,1)".parse::<Tile<3, 4>>(),
+            Err(ParseCoordinatesError::InvalidNumber)
+        );
+    }
+
     #[test]
     fn test_serde() {
         let tile: Tile<3, 3> = Tile(2);
@@ -393,6 +826,49 @@ mod tests {
             &tile,
             &[Token::NewtypeStruct { name: "Tile" }, Token::U8(2)],
         );
+
+        serde_test::assert_de_tokens_error::<Tile<3, 3>>(
+            &[Token::NewtypeStruct { name: "Tile" }, Token::U8(200)],
+            "tile index 200 is outside a 3x3 grid",
+        );
+    }
+
+    #[test]
+    fn test_serde_tile_xy() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Wrapper(#[serde(with = "serde_tile_xy")] Tile<3, 3>);
+
+        assert_tokens(
+            &Wrapper(Tile::new_const::<2, 1>()),
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Struct {
+                    name: "Tile",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::U8(2),
+                Token::Str("y"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+        );
+
+        serde_test::assert_de_tokens_error::<Wrapper>(
+            &[
+                Token::NewtypeStruct { name: "Wrapper" },
+                Token::Struct {
+                    name: "Tile",
+                    len: 2,
+                },
+                Token::Str("x"),
+                Token::U8(3),
+                Token::Str("y"),
+                Token::U8(1),
+                Token::StructEnd,
+            ],
+            "tile (3,1) is outside a 3x3 grid",
+        );
     }
 
     #[test]
@@ -407,14 +883,27 @@ mod tests {
     #[test]
     fn test_add() {
         let tile: Tile<3, 3> = Tile::new_const::<1, 1>();
-        assert_eq!(tile + Vector::NORTH, Tile::try_new(1, 0))
+        assert_eq!(tile + Vector::NORTH, Tile::try_new(1, 0));
     }
 
     #[test]
     fn test_add_gives_none() {
         let tile: Tile<4, 4> = Tile::new_const::<3, 0>();
         let r = tile + Vector::new(1, 0);
-        assert_eq!(r, None)
+        assert_eq!(r, None);
+    }
+
+    #[test]
+    fn test_iter_ray() {
+        let tile: Tile<5, 5> = Tile::new_const::<1, 1>();
+        assert_eq!(tile.iter_ray(Vector::EAST).join("|"), "(2,1)|(3,1)|(4,1)");
+        assert_eq!(
+            tile.iter_ray(Vector::SOUTH_EAST).join("|"),
+            "(2,2)|(3,3)|(4,4)"
+        );
+        assert_eq!(tile.iter_ray(Vector::new(1, 2)).join("|"), "(2,3)");
+        assert_eq!(tile.iter_ray(Vector::new(-2, 0)).count(), 0);
+        assert_eq!(tile.iter_ray(Vector::ZERO).count(), 0);
     }
 
     #[test]
@@ -434,11 +923,41 @@ mod tests {
         assert_eq!(tile.get_center(2.0), glam::f32::Vec2::new(3.0, 5.0));
     }
 
+    #[test]
+    fn test_try_from_center() {
+        type T = Tile<3, 2>;
+
+        for tile in T::iter_by_row() {
+            assert_eq!(T::try_from_center(tile.get_center(2.0), 2.0), Some(tile));
+        }
+
+        assert_eq!(
+            T::try_from_center(glam::f32::Vec2::new(5.9, 0.1), 2.0),
+            Some(Tile::new_const::<2, 0>())
+        );
+        assert_eq!(
+            T::try_from_center(glam::f32::Vec2::new(6.0, 0.1), 2.0),
+            None
+        );
+        assert_eq!(
+            T::try_from_center(glam::f32::Vec2::new(-0.1, 0.1), 2.0),
+            None
+        );
+        assert_eq!(
+            T::try_from_center(glam::f32::Vec2::new(1.0, 4.0), 2.0),
+            None
+        );
+        assert_eq!(
+            T::try_from_center(glam::f32::Vec2::new(f32::NAN, 1.0), 2.0),
+            None
+        );
+    }
+
     #[test]
     fn test_debug() {
         let tile: Tile<3, 3> = Tile::new_const::<1, 2>();
 
-        assert_eq!(format!("{tile:?}"), "(1,2)")
+        assert_eq!(format!("{tile:?}"), "(1,2)");
     }
 
     #[test]
@@ -455,6 +974,16 @@ mod tests {
         assert_eq!(Tile::<3, 3>::try_from_usize(9), None);
     }
 
+    #[test]
+    fn test_try_with_x_and_y() {
+        let tile: Tile<3, 4> = Tile::new_const::<1, 2>();
+
+        assert_eq!(tile.try_with_x(2), Some(Tile::new_const::<2, 2>()));
+        assert_eq!(tile.try_with_x(3), None);
+        assert_eq!(tile.try_with_y(3), Some(Tile::new_const::<1, 3>()));
+        assert_eq!(tile.try_with_y(4), None);
+    }
+
     #[test]
     fn test_try_next() {
         let mut tile = Tile::<3, 3>(0);
@@ -471,6 +1000,106 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_iter_within_manhattan() {
+        let center = Tile::<5, 5>::CENTER;
+        assert_eq!(center.iter_within_manhattan(0).join("|"), "(2,2)");
+        assert_eq!(
+            center.iter_within_manhattan(1).join("|"),
+            "(2,1)|(1,2)|(2,2)|(3,2)|(2,3)"
+        );
+        assert_eq!(center.iter_within_manhattan(2).count(), 13);
+        assert_eq!(center.iter_within_manhattan(u8::MAX).count(), 25);
+
+        let corner = Tile::<5, 5>::NORTH_WEST;
+        assert_eq!(
+            corner.iter_within_manhattan(1).join("|"),
+            "(0,0)|(1,0)|(0,1)"
+        );
+
+        for radius in 0..10 {
+            for tile in Tile::<5, 4>::iter_by_row() {
+                let expected = Tile::<5, 4>::iter_by_row()
+                    .filter(|t| t.manhattan_distance(&tile) <= radius)
+                    .collect_vec();
+                assert_eq!(tile.iter_within_manhattan(radius).collect_vec(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_try_prev() {
+        let mut tile = Tile::<3, 3>::SOUTH_EAST;
+        let mut i = 8;
+        while let Some(prev) = tile.try_prev() {
+            i -= 1;
+            tile = prev;
+            assert_eq!(tile.inner(), i);
+        }
+        assert_eq!(i, 0);
+    }
+
+    #[test]
+    fn test_iter_range() {
+        let from = Tile::<3, 3>::new_const::<1, 0>();
+        let to = Tile::<3, 3>::new_const::<0, 2>();
+        let range = Tile::iter_range(from, to);
+        assert_eq!(range.len(), 6);
+        assert_eq!(
+            range.clone().join("|"),
+            "(1,0)|(2,0)|(0,1)|(1,1)|(2,1)|(0,2)"
+        );
+        assert_eq!(range.rev().join("|"), "(0,2)|(2,1)|(1,1)|(0,1)|(2,0)|(1,0)");
+
+        assert_eq!(Tile::iter_range(to, from).count(), 0);
+        assert_eq!(Tile::iter_range(from, from).count(), 1);
+    }
+
+    #[test]
+    fn test_iter_rows_range() {
+        let mut iter = Tile::<3, 4>::iter_rows_range(1..3);
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.join("|"), "(0,1)|(1,1)|(2,1)|(0,2)|(1,2)|(2,2)");
+
+        assert_eq!(
+            Tile::<3, 4>::iter_rows_range(3..10).rev().join("|"),
+            "(2,3)|(1,3)|(0,3)"
+        );
+        assert_eq!(Tile::<3, 4>::iter_rows_range(5..10).len(), 0);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 2..1;
+        assert_eq!(Tile::<3, 4>::iter_rows_range(reversed).len(), 0);
+
+        let last_rows = Tile::<16, 16>::iter_rows_range(14..16);
+        assert_eq!(last_rows.len(), 32);
+        assert_eq!(last_rows.clone().next(), Tile::try_from_usize(224));
+        assert_eq!(last_rows.last(), Some(Tile::SOUTH_EAST));
+        assert_eq!(Tile::<16, 16>::iter_rows_range(0..16).len(), 256);
+    }
+
+    #[test]
+    fn test_iter_region() {
+        let rectangle = Rectangle::new(Vector::new(1, 1).into(), 2, 2);
+        let mut iter = Tile::<4, 4>::iter_region(rectangle);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.join("|"), "(1,1)|(2,1)|(1,2)|(2,2)");
+
+        let rectangle = Rectangle::new(Vector::new(-1, 2).into(), 3, 5);
+        let iter = Tile::<4, 4>::iter_region(rectangle);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.rev().join("|"), "(1,3)|(0,3)|(1,2)|(0,2)");
+
+        let rectangle = Rectangle::new(Vector::new(5, 0).into(), 3, 5);
+        assert_eq!(Tile::<4, 4>::iter_region(rectangle).len(), 0);
+    }
+
+    #[test]
+    fn test_iter_by_col_rev() {
+        let iter = Tile::<3, 2>::iter_by_col();
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.rev().join("|"), "(2,1)|(2,0)|(1,1)|(1,0)|(0,1)|(0,0)");
+    }
+
     #[test]
     fn test_get_vertex() {
         let tile = Tile::<2, 2>::new_const::<0, 0>();
@@ -492,7 +1121,58 @@ mod tests {
             Some(Vertex::new_const::<1, 1>())
         );
 
-        assert_eq!(tile.get_north_west_vertex(), Vertex::new_const::<0, 0>())
+        assert_eq!(tile.get_north_west_vertex(), Vertex::new_const::<0, 0>());
+    }
+
+    #[test]
+    fn test_all() {
+        const TILES: [Tile<3, 2>; 6] = Tile::all();
+        // A lookup table of the row mask for each tile, built in a const context
+        const ROW_MASKS: [TileSet8<3, 2, 6>; 6] = {
+            let mut masks = [TileSet8::EMPTY; 6];
+            let mut index = 0;
+            while index < TILES.len() {
+                masks[index] = TileSet8::row_mask(TILES[index].y());
+                index += 1;
+            }
+            masks
+        };
+
+        assert!(TILES.into_iter().eq(Tile::<3, 2>::iter_by_row()));
+        assert_eq!(ROW_MASKS[4].to_string(), "___\n***");
+    }
+
+    #[test]
+    fn test_largest_grid() {
+        type T = Tile<16, 16>;
+        assert_eq!(T::SOUTH_EAST.inner(), 255);
+        assert_eq!(T::iter_by_row().len(), 256);
+        assert_eq!(T::iter_by_row().next_back(), Some(T::SOUTH_EAST));
+        assert!(T::iter_by_col().eq(T::iter_by_row().map(|t| T::new_unchecked(t.y(), t.x()))));
+        assert_eq!(T::try_from_usize(255), Some(T::SOUTH_EAST));
+        assert_eq!(T::try_from_usize(256), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "SIZE does not match the grid dimensions")]
+    fn test_all_wrong_size() {
+        let _ = Tile::<3, 2>::all::<5>();
+    }
+
+    #[test]
+    fn test_iter_vertices() {
+        for tile in Tile::<3, 4>::iter_by_row() {
+            assert!(tile
+                .iter_vertices()
+                .eq(Corner::ALL.iter().filter_map(|c| tile.get_vertex(c))));
+            assert!(tile
+                .iter_vertices()
+                .all(|v| v.adjacent_tiles().contains(&tile)));
+        }
+        assert_eq!(
+            Tile::<3, 4>::new_const::<2, 3>().iter_vertices().join("|"),
+            "(2,3)|(3,3)|(2,4)|(3,4)"
+        );
     }
 
     #[test]
@@ -510,7 +1190,7 @@ mod tests {
         for rhs in Tile::<3, 3>::iter_by_row() {
             let expected = expected_adjacent_tiles.contains(&rhs);
             let actual = tile.is_adjacent_to(&rhs);
-            assert_eq!(expected, actual)
+            assert_eq!(expected, actual);
         }
     }
 
@@ -531,7 +1211,7 @@ mod tests {
         for rhs in Tile::<3, 3>::iter_by_row() {
             let expected = expected_contiguous_tiles.contains(&rhs);
             let actual = tile.is_contiguous_with(&rhs);
-            assert_eq!(expected, actual, "{}", rhs)
+            assert_eq!(expected, actual, "{rhs}");
         }
     }
 
@@ -561,14 +1241,14 @@ mod tests {
     fn test_is_corner() {
         let corners: TileSet16<3, 4, 12> = TileSet16::from_fn(|tile| tile.is_corner());
 
-        assert_eq!("*_*\n___\n___\n*_*", corners.to_string())
+        assert_eq!("*_*\n___\n___\n*_*", corners.to_string());
     }
 
     #[test]
     fn test_is_edge() {
         let edges: TileSet16<3, 4, 12> = TileSet16::from_fn(|tile| tile.is_edge());
 
-        assert_eq!("***\n*_*\n*_*\n***", edges.to_string())
+        assert_eq!("***\n*_*\n*_*\n***", edges.to_string());
     }
 
     #[test]
@@ -576,6 +1256,117 @@ mod tests {
         let adjacencies: TileMap<u8, 3, 4, 12> =
             TileMap::from_fn(|tile| tile.adjacent_tile_count());
 
-        assert_eq!("3|5|3\n5|8|5\n5|8|5\n3|5|3", adjacencies.to_string())
+        assert_eq!("3|5|3\n5|8|5\n5|8|5\n3|5|3", adjacencies.to_string());
+    }
+
+    #[test]
+    fn test_neighbor() {
+        let tile: Tile<3, 3> = Tile::NORTH_EAST;
+        assert_eq!(
+            tile.neighbor(Vector::SOUTH),
+            Some(Tile::new_const::<2, 1>())
+        );
+        assert_eq!(tile.neighbor(Vector::EAST), None);
+        assert_eq!(tile.neighbor_dir(Direction::SouthWest), Some(Tile::CENTER));
+        assert_eq!(tile.neighbor_dir(Direction::North), None);
+
+        let neighbors = Direction::ALL
+            .iter()
+            .filter_map(|d| Tile::<3, 3>::CENTER.neighbor_dir(*d))
+            .count();
+        assert_eq!(neighbors, 8);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_angle_to() {
+        let tile: Tile<5, 5> = Tile::CENTER;
+        for unit in Vector::UNITS {
+            let other = (tile + unit).unwrap();
+            assert_eq!(tile.angle_to(&other), unit.angle());
+            assert_eq!(Vector::from_angle_snapped(tile.angle_to(&other)), unit);
+        }
+
+        let tile: Tile<7, 7> = Tile::CENTER;
+        assert_eq!(
+            Vector::from_angle_snapped(tile.angle_to(&Tile::new_const::<6, 4>())),
+            Vector::EAST
+        );
+        assert_eq!(
+            Tile::<7, 7>::NORTH_WEST.distance_to(&Tile::new_const::<3, 4>()),
+            5.0
+        );
+    }
+
+    #[test]
+    fn test_vector_to_and_direction_to() {
+        let tile: Tile<5, 5> = Tile::new_const::<1, 3>();
+
+        assert_eq!(tile.vector_to(&Tile::NORTH_EAST), Vector::new(3, -3));
+        assert_eq!(tile.vector_to(&tile), Vector::ZERO);
+        assert_eq!((tile + tile.vector_to(&Tile::CENTER)), Some(Tile::CENTER));
+
+        assert_eq!(
+            tile.direction_to(&Tile::NORTH_EAST),
+            Some(Vector::NORTH_EAST)
+        );
+        assert_eq!(
+            tile.direction_to(&Tile::new_const::<1, 0>()),
+            Some(Vector::NORTH)
+        );
+        assert_eq!(
+            tile.direction_to(&Tile::new_const::<4, 3>()),
+            Some(Vector::EAST)
+        );
+        assert_eq!(
+            tile.direction_to(&Tile::new_const::<0, 4>()),
+            Some(Vector::SOUTH_WEST)
+        );
+        assert_eq!(tile.direction_to(&Tile::new_const::<2, 0>()), None);
+        assert_eq!(tile.direction_to(&tile), None);
+
+        let wide: Tile<127, 2> = Tile::new_const::<126, 0>();
+        assert_eq!(
+            wide.vector_to(&Tile::new_const::<0, 1>()),
+            Vector::new(-126, 1)
+        );
+
+        let widest: Tile<255, 1> = Tile::new_const::<254, 0>();
+        assert_eq!(widest.direction_to(&Tile::NORTH_WEST), Some(Vector::WEST));
+    }
+
+    #[test]
+    #[should_panic(expected = "The offset between the tiles does not fit in a Vector")]
+    fn test_vector_to_too_far() {
+        let tile: Tile<200, 1> = Tile::NORTH_WEST;
+        let _ = tile.vector_to(&Tile::new_const::<199, 0>());
+    }
+
+    #[test]
+    fn test_halves_and_quadrants() {
+        let quadrants = Tile::<5, 4>::iter_by_row()
+            .map(|tile| match tile.quadrant() {
+                Some(Corner::NorthWest) => '1',
+                Some(Corner::NorthEast) => '2',
+                Some(Corner::SouthWest) => '3',
+                Some(Corner::SouthEast) => '4',
+                None => '_',
+            })
+            .chunks(5)
+            .into_iter()
+            .map(Iterator::collect::<String>)
+            .join("\n");
+        assert_eq!(quadrants, "11_22\n11_22\n33_44\n33_44");
+
+        for tile in Tile::<5, 4>::iter_by_row() {
+            assert_ne!(tile.is_in_north_half(), tile.is_in_south_half());
+            assert_eq!(
+                tile.x() == 2,
+                !tile.is_in_west_half() && !tile.is_in_east_half()
+            );
+            for corner in Corner::ALL {
+                assert_eq!(tile.is_in_quadrant(corner), tile.quadrant() == Some(corner));
+            }
+        }
     }
 }
@@ -0,0 +1,413 @@
+use core::{fmt::Display, iter::FusedIterator, ops::Add};
+
+use crate::prelude::*;
+
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+/// A tile in 2d space, stored as its index in a `u16`.
+/// Use this instead of `Tile` for grids with more than 255 tiles.
+#[must_use]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize))]
+pub struct Tile16<const WIDTH: u16, const HEIGHT: u16>(u16);
+
+impl<const WIDTH: u16, const HEIGHT: u16, V: AsRef<Vector>> Add<V> for Tile16<WIDTH, HEIGHT> {
+    type Output = Option<Self>;
+
+    fn add(self, rhs: V) -> Self::Output {
+        self.const_add(rhs.as_ref())
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> From<Tile16<WIDTH, HEIGHT>> for u16 {
+    fn from(value: Tile16<WIDTH, HEIGHT>) -> Self {
+        value.0
+    }
+}
+impl<const WIDTH: u16, const HEIGHT: u16> From<&Tile16<WIDTH, HEIGHT>> for u16 {
+    fn from(value: &Tile16<WIDTH, HEIGHT>) -> Self {
+        value.0
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> From<Tile16<WIDTH, HEIGHT>> for usize {
+    fn from(value: Tile16<WIDTH, HEIGHT>) -> Self {
+        value.0.into()
+    }
+}
+impl<const WIDTH: u16, const HEIGHT: u16> From<&Tile16<WIDTH, HEIGHT>> for usize {
+    fn from(value: &Tile16<WIDTH, HEIGHT>) -> Self {
+        value.0.into()
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Display for Tile16<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({},{})", self.x(), self.y())
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> core::fmt::Debug for Tile16<WIDTH, HEIGHT> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({},{})", self.x(), self.y())
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> core::str::FromStr for Tile16<WIDTH, HEIGHT> {
+    type Err = ParseCoordinatesError;
+
+    /// Parses tiles of the form `(x,y)` or `x,y`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = crate::parse_coordinates_error::parse_coordinates(s)?;
+        Self::try_new(x, y).ok_or(ParseCoordinatesError::OutOfBounds)
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16> Tile16<WIDTH, HEIGHT> {
+    pub const NORTH_WEST: Self = Self(0);
+    pub const NORTH_EAST: Self = Self::new_unchecked(Self::MAX_COL, 0);
+    pub const SOUTH_WEST: Self = Self::new_unchecked(0, Self::MAX_ROW);
+    pub const SOUTH_EAST: Self = Self::new_unchecked(Self::MAX_COL, Self::MAX_ROW);
+
+    pub const MAX_COL: u16 = WIDTH - 1;
+    pub const MAX_ROW: u16 = HEIGHT - 1;
+
+    pub const COUNT: usize = WIDTH as usize * HEIGHT as usize;
+
+    pub const CENTER: Self = Self::new_unchecked(WIDTH / 2, HEIGHT / 2);
+
+    pub const fn new_const<const X: u16, const Y: u16>() -> Self {
+        Self::new_unchecked(X, Y)
+    }
+
+    pub(crate) const fn new_unchecked(x: u16, y: u16) -> Self {
+        debug_assert!(x < WIDTH);
+        debug_assert!(y < HEIGHT);
+        debug_assert!(Self::COUNT <= u16::MAX as usize + 1);
+        Self(x + (WIDTH * y))
+    }
+
+    #[must_use]
+    pub const fn try_new(x: u16, y: u16) -> Option<Self> {
+        if x >= WIDTH {
+            return None;
+        }
+        if y >= HEIGHT {
+            return None;
+        }
+        let Some(i1) = y.checked_mul(WIDTH) else {
+            return None;
+        };
+        let Some(i2) = i1.checked_add(x) else {
+            return None;
+        };
+        Self::try_from_inner(i2)
+    }
+
+    #[must_use]
+    pub const fn try_from_dynamic(dynamic_tile: DynamicTile) -> Option<Self> {
+        if dynamic_tile.0.x.is_negative() || dynamic_tile.0.y.is_negative() {
+            return None;
+        }
+
+        Self::try_new(
+            dynamic_tile.0.x.unsigned_abs() as u16,
+            dynamic_tile.0.y.unsigned_abs() as u16,
+        )
+    }
+
+    #[must_use]
+    pub const fn x(&self) -> u16 {
+        self.0 % WIDTH
+    }
+
+    #[must_use]
+    pub const fn y(&self) -> u16 {
+        self.0 / WIDTH
+    }
+
+    #[must_use]
+    pub const fn inner(&self) -> u16 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn try_from_inner(inner: u16) -> Option<Self> {
+        if inner <= Self::SOUTH_EAST.inner() {
+            Some(Self(inner))
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn try_from_usize(value: usize) -> Option<Self> {
+        if value >= Self::COUNT {
+            return None;
+        }
+        let inner = value as u16;
+        Some(Self(inner))
+    }
+
+    pub const fn flip(&self, axes: FlipAxes) -> Self {
+        match axes {
+            FlipAxes::None => *self,
+            FlipAxes::Horizontal => Self::new_unchecked(Self::MAX_COL - self.x(), self.y()),
+            FlipAxes::Vertical => Self::new_unchecked(self.x(), Self::MAX_ROW - self.y()),
+            FlipAxes::Both => {
+                Self::new_unchecked(Self::MAX_COL - self.x(), Self::MAX_ROW - self.y())
+            }
+        }
+    }
+
+    #[must_use]
+    pub const fn try_next(&self) -> Option<Self> {
+        let Some(next) = self.inner().checked_add(1) else {
+            return None;
+        };
+        Self::try_from_inner(next)
+    }
+
+    #[must_use]
+    pub const fn try_prev(&self) -> Option<Self> {
+        let Some(prev) = self.inner().checked_sub(1) else {
+            return None;
+        };
+        Self::try_from_inner(prev)
+    }
+
+    /// Iterate through all tiles by row
+    /// This method has better performance than `iter_by_col`
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn iter_by_row(
+    ) -> impl FusedIterator<Item = Self> + Clone + ExactSizeIterator + DoubleEndedIterator {
+        (0..Self::COUNT).map(|index| Self(index as u16))
+    }
+
+    /// Iterate through all tiles by column
+    /// This method has worse performance than `iter_by_row`
+    pub fn iter_by_col(
+    ) -> impl FusedIterator<Item = Self> + ExactSizeIterator + Clone + DoubleEndedIterator {
+        Tile16::<HEIGHT, WIDTH>::iter_by_row().map(Tile16::transpose)
+    }
+
+    /// Return this tile in a transposed grid system (i.e. the height and width are swapped)
+    ///
+    /// # Panics
+    /// If the tile is invalid
+    pub const fn transpose(self) -> Tile16<HEIGHT, WIDTH> {
+        if let Some(r) = Tile16::try_new(self.y(), self.x()) {
+            r
+        } else {
+            panic!("Cannot transpose invalid tile")
+        }
+    }
+
+    /// Iterate through the neighbours of this tile which are in the grid, in the order of the neighbourhood's offsets
+    #[must_use]
+    pub fn iter_neighbours(
+        self,
+        neighbourhood: Neighbourhood<'_>,
+    ) -> impl FusedIterator<Item = Self> + DoubleEndedIterator + Clone + '_ {
+        neighbourhood
+            .offsets()
+            .iter()
+            .filter_map(move |v| self.const_add(v))
+    }
+
+    /// Iterate through adjacent elements (includes diagonals)
+    #[must_use]
+    pub fn iter_adjacent(self) -> impl FusedIterator<Item = Self> + DoubleEndedIterator + Clone {
+        self.iter_neighbours(Neighbourhood::MOORE)
+    }
+
+    /// Iterate through contiguous elements (does not include diagonals)
+    #[must_use]
+    pub fn iter_contiguous(self) -> impl FusedIterator<Item = Self> + DoubleEndedIterator + Clone {
+        self.iter_neighbours(Neighbourhood::VON_NEUMANN)
+    }
+
+    /// Whether two tiles are adjacent (includes diagonals)
+    #[must_use]
+    pub const fn is_adjacent_to(&self, rhs: &Self) -> bool {
+        self.0 != rhs.0 && self.x().abs_diff(rhs.x()) <= 1 && self.y().abs_diff(rhs.y()) <= 1
+    }
+
+    /// Whether two tiles are contiguous (does not include diagonals)
+    #[must_use]
+    pub const fn is_contiguous_with(&self, rhs: &Self) -> bool {
+        self.x().abs_diff(rhs.x()) + self.y().abs_diff(rhs.y()) == 1
+    }
+
+    #[must_use]
+    pub const fn const_add(&self, vector: &Vector) -> Option<Self> {
+        let Some(c) = self.x().checked_add_signed(vector.x as i16) else {
+            return None;
+        };
+        let Some(r) = self.y().checked_add_signed(vector.y as i16) else {
+            return None;
+        };
+
+        Self::try_new(c, r)
+    }
+
+    /// The neighbouring tile in a direction, if it is in the grid
+    #[must_use]
+    #[inline]
+    pub const fn neighbor_dir(&self, direction: Direction) -> Option<Self> {
+        self.const_add(&direction.vector())
+    }
+
+    /// Returns the Manhattan distance between two tiles.
+    /// Also known as the taxicab distance, the Manhattan distance is the sum of the distances in the two axes.
+    #[must_use]
+    pub const fn manhattan_distance(&self, other: &Self) -> u16 {
+        self.x().abs_diff(other.x()) + self.y().abs_diff(other.y())
+    }
+
+    /// Returns true if this is an edge tile (or corner tile)
+    #[must_use]
+    pub const fn is_edge(&self) -> bool {
+        (self.x() == 0 || self.x() == Self::MAX_COL) || (self.y() == 0 || self.y() == Self::MAX_ROW)
+    }
+
+    /// Returns true if this is a corner tile
+    #[must_use]
+    pub const fn is_corner(&self) -> bool {
+        (self.x() == 0 || self.x() == Self::MAX_COL) && (self.y() == 0 || self.y() == Self::MAX_ROW)
+    }
+}
+
+impl<const L: u16> Tile16<L, L> {
+    pub const fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        match quarter_turns {
+            QuarterTurns::Zero => *self,
+            QuarterTurns::One => Self::new_unchecked(L - 1 - self.y(), self.x()),
+            QuarterTurns::Two => Self::new_unchecked(L - 1 - self.x(), L - 1 - self.y()),
+            QuarterTurns::Three => Self::new_unchecked(self.y(), L - 1 - self.x()),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "glam"))]
+impl<const WIDTH: u16, const HEIGHT: u16> HasCenter for Tile16<WIDTH, HEIGHT> {
+    fn get_center(&self, scale: f32) -> glam::f32::Vec2 {
+        let x = scale * (f32::from(self.x()) + 0.5);
+        let y = scale * (f32::from(self.y()) + 0.5);
+
+        glam::f32::Vec2 { x, y }
+    }
+}
+
+#[cfg(any(test, feature = "serde"))]
+impl<'de, const WIDTH: u16, const HEIGHT: u16> Deserialize<'de> for Tile16<WIDTH, HEIGHT> {
+    /// Deserializes the index in the same format as it is serialized, failing if it is outside the grid
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner: u16 = crate::serde_newtype::deserialize_newtype(deserializer, "Tile16")?;
+        Self::try_from_inner(inner).ok_or_else(|| {
+            serde::de::Error::custom(format_args!(
+                "tile index {inner} is outside a {WIDTH}x{HEIGHT} grid"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use serde_test::{assert_tokens, Token};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_large_grid() {
+        type T = Tile16<100, 50>;
+
+        assert_eq!(T::COUNT, 5000);
+        assert_eq!(T::SOUTH_EAST.inner(), 4999);
+        assert_eq!(T::iter_by_row().len(), 5000);
+        assert_eq!(T::try_new(99, 49), Some(T::SOUTH_EAST));
+        assert_eq!(T::try_new(100, 0), None);
+        assert_eq!(T::try_from_inner(5000), None);
+        assert_eq!(T::SOUTH_EAST.try_next(), None);
+        assert_eq!(T::CENTER.to_string(), "(50,25)");
+        assert_eq!("(99, 49)".parse(), Ok(T::SOUTH_EAST));
+        assert_eq!(
+            "(100,49)".parse::<T>(),
+            Err(ParseCoordinatesError::OutOfBounds)
+        );
+    }
+
+    #[test]
+    fn test_iter_by_row_and_col() {
+        assert_eq!(
+            Tile16::<3, 2>::iter_by_row().join("|"),
+            "(0,0)|(1,0)|(2,0)|(0,1)|(1,1)|(2,1)"
+        );
+        assert_eq!(
+            Tile16::<3, 2>::iter_by_col().join("|"),
+            "(0,0)|(0,1)|(1,0)|(1,1)|(2,0)|(2,1)"
+        );
+    }
+
+    #[test]
+    fn test_adjacency() {
+        let tile = Tile16::<300, 3>::new_const::<299, 0>();
+
+        assert_eq!(tile.iter_contiguous().join("|"), "(299,1)|(298,0)");
+        assert_eq!(tile.iter_adjacent().count(), 3);
+        assert!(tile.is_contiguous_with(&Tile16::new_const::<298, 0>()));
+        assert!(!tile.is_contiguous_with(&Tile16::new_const::<298, 1>()));
+        assert!(tile.is_adjacent_to(&Tile16::new_const::<298, 1>()));
+        assert!(tile.is_corner());
+        assert_eq!(tile.manhattan_distance(&Tile16::new_const::<0, 2>()), 301);
+        assert_eq!(tile + Vector::EAST, None);
+    }
+
+    #[test]
+    fn test_flip_and_rotate() {
+        let tile: Tile16<20, 20> = Tile16::new_const::<1, 2>();
+        assert_eq!(
+            tile.flip(FlipAxes::Horizontal),
+            Tile16::new_const::<18, 2>()
+        );
+        assert_eq!(tile.flip(FlipAxes::Vertical), Tile16::new_const::<1, 17>());
+        assert_eq!(tile.flip(FlipAxes::Both), Tile16::new_const::<18, 17>());
+
+        for (tile, tile16) in Tile::<4, 4>::iter_by_row().zip(Tile16::<4, 4>::iter_by_row()) {
+            for quarter_turns in QuarterTurns::iter() {
+                let rotated = tile.rotate(quarter_turns);
+                let rotated16 = tile16.rotate(quarter_turns);
+                assert_eq!(u16::from(rotated.inner()), rotated16.inner());
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_dynamic() {
+        type T = Tile16<200, 2>;
+        assert_eq!(
+            T::try_from_dynamic(DynamicTile(Vector::new(127, 1))),
+            T::try_new(127, 1)
+        );
+        assert_eq!(T::try_from_dynamic(DynamicTile(Vector::new(-1, 1))), None);
+    }
+
+    #[test]
+    fn test_serde() {
+        let tile: Tile16<300, 3> = Tile16(600);
+
+        assert_tokens(
+            &tile,
+            &[Token::NewtypeStruct { name: "Tile16" }, Token::U16(600)],
+        );
+
+        serde_test::assert_de_tokens_error::<Tile16<300, 3>>(
+            &[Token::NewtypeStruct { name: "Tile16" }, Token::U16(900)],
+            "tile index 900 is outside a 300x3 grid",
+        );
+    }
+}
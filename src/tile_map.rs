@@ -1,7 +1,9 @@
 use core::{
+    cmp::Ordering,
     fmt::{self, Write},
     iter,
     ops::{Index, IndexMut},
+    str::FromStr,
 };
 
 use crate::prelude::*;
@@ -29,7 +31,7 @@ impl<T: Default + Copy, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> De
     for TileMap<T, WIDTH, HEIGHT, SIZE>
 {
     fn default() -> Self {
-        debug_assert!(SIZE == (WIDTH * HEIGHT) as usize);
+        Self::assert_legal();
         Self([T::default(); SIZE])
     }
 }
@@ -37,11 +39,49 @@ impl<T: Default + Copy, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> De
 impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileMap<T, WIDTH, HEIGHT, SIZE> {
     #[allow(clippy::missing_panics_doc)]
     pub fn from_fn<F: FnMut(Tile<WIDTH, HEIGHT>) -> T>(mut cb: F) -> Self {
-        debug_assert!(SIZE == (WIDTH * HEIGHT) as usize);
+        Self::assert_legal();
         let arr = core::array::from_fn(|i| cb(Tile::try_from_usize(i).unwrap()));
         Self(arr)
     }
 
+    /// Create a map from its rows, north to south, so that literal maps can be written in their visual layout.
+    /// Fails to compile if there are not `HEIGHT` rows of `WIDTH` values.
+    ///
+    /// ```compile_fail
+    /// # use geometrid::prelude::*;
+    /// let map: TileMap<u8, 3, 2, 6> = TileMap::from_rows([[1, 2], [3, 4], [5, 6]]);
+    /// ```
+    /// # Panics
+    /// If `SIZE` does not match the dimensions
+    pub fn from_rows<const W: usize, const H: usize>(rows: [[T; W]; H]) -> Self {
+        const {
+            assert!(W == WIDTH as usize, "from_rows needs rows of WIDTH values");
+        };
+        const {
+            assert!(H == HEIGHT as usize, "from_rows needs HEIGHT rows");
+        };
+        let mut values = rows.into_iter().flatten();
+        Self::from_fn(|_| values.next().unwrap())
+    }
+
+    /// Create a map from its columns, west to east.
+    /// Fails to compile if there are not `WIDTH` columns of `HEIGHT` values.
+    /// # Panics
+    /// If `SIZE` does not match the dimensions
+    pub fn from_columns<const W: usize, const H: usize>(columns: [[T; H]; W]) -> Self {
+        const {
+            assert!(W == WIDTH as usize, "from_columns needs WIDTH columns");
+        };
+        const {
+            assert!(
+                H == HEIGHT as usize,
+                "from_columns needs columns of HEIGHT values"
+            );
+        };
+        let mut columns = columns.map(IntoIterator::into_iter);
+        Self::from_fn(|tile| columns[tile.x() as usize].next().unwrap())
+    }
+
     #[must_use]
     #[inline]
     pub fn into_inner(self) -> [T; SIZE] {
@@ -51,10 +91,32 @@ impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileMap<T, WIDTH,
 
     #[inline]
     pub const fn from_inner(inner: [T; SIZE]) -> Self {
-        debug_assert!(SIZE == (WIDTH * HEIGHT) as usize);
+        Self::assert_legal();
         Self(inner)
     }
 
+    /// Create a map from its values, checking that `SIZE` matches the dimensions
+    /// # Errors
+    /// If `SIZE` is not the number of tiles in the grid
+    pub fn try_from_inner(inner: [T; SIZE]) -> Result<Self, DimensionError> {
+        DimensionError::check(WIDTH as usize * HEIGHT as usize, SIZE, usize::MAX)?;
+        Ok(Self(inner))
+    }
+
+    /// Create a map by calling `cb` for each tile, checking that `SIZE` matches the dimensions
+    /// # Errors
+    /// If `SIZE` is not the number of tiles in the grid
+    pub fn try_from_fn<F: FnMut(Tile<WIDTH, HEIGHT>) -> T>(cb: F) -> Result<Self, DimensionError> {
+        DimensionError::check(WIDTH as usize * HEIGHT as usize, SIZE, usize::MAX)?;
+        Ok(Self::from_fn(cb))
+    }
+
+    /// Panics if `SIZE` is not the number of tiles in the grid
+    #[inline]
+    const fn assert_legal() {
+        DimensionError::assert(WIDTH as usize * HEIGHT as usize, SIZE, usize::MAX);
+    }
+
     #[inline]
     #[allow(clippy::missing_panics_doc)]
     pub fn enumerate(&self) -> impl iter::Iterator<Item = (Tile<WIDTH, HEIGHT>, &'_ T)> {
@@ -64,6 +126,29 @@ impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileMap<T, WIDTH,
             .map(|(inner, x)| (Tile::try_from_usize(inner).unwrap(), x))
     }
 
+    /// Consume the map, iterating through each tile and its value by row
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn into_enumerate(
+        self,
+    ) -> impl ExactSizeIterator<Item = (Tile<WIDTH, HEIGHT>, T)> + DoubleEndedIterator {
+        self.0
+            .into_iter()
+            .enumerate()
+            .map(|(inner, x)| (Tile::try_from_usize(inner).unwrap(), x))
+    }
+
+    /// Iterate through the tiles of the map, in the same order as the values
+    #[inline]
+    pub fn keys(
+        &self,
+    ) -> impl iter::FusedIterator<Item = Tile<WIDTH, HEIGHT>>
+           + Clone
+           + ExactSizeIterator
+           + DoubleEndedIterator {
+        Tile::iter_by_row()
+    }
+
     #[inline]
     pub fn swap(&mut self, p1: Tile<WIDTH, HEIGHT>, p2: Tile<WIDTH, HEIGHT>) {
         self.0.swap(p1.into(), p2.into());
@@ -105,6 +190,94 @@ impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileMap<T, WIDTH,
             .map(|x| &self.0[x as usize])
     }
 
+    /// Display this map, writing each value with `f`.
+    /// The returned adapter can be further customized with separators and headers.
+    pub fn display_with<'a, D: fmt::Display>(
+        &'a self,
+        f: impl Fn(&T) -> D + 'a,
+    ) -> DisplayAdapter<
+        impl Fn(Tile<WIDTH, HEIGHT>, &mut fmt::Formatter<'_>) -> fmt::Result + 'a,
+        WIDTH,
+        HEIGHT,
+    > {
+        DisplayAdapter::new(move |tile, formatter| f(&self[tile]).fmt(formatter))
+    }
+
+    /// The first tile, in row order, whose value matches the predicate
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn find(&self, pred: impl FnMut(&T) -> bool) -> Option<Tile<WIDTH, HEIGHT>> {
+        self.0
+            .iter()
+            .position(pred)
+            .map(|inner| Tile::try_from_usize(inner).unwrap())
+    }
+
+    /// The last tile, in row order, whose value matches the predicate
+    #[must_use]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn rfind(&self, pred: impl FnMut(&T) -> bool) -> Option<Tile<WIDTH, HEIGHT>> {
+        self.0
+            .iter()
+            .rposition(pred)
+            .map(|inner| Tile::try_from_usize(inner).unwrap())
+    }
+
+    /// Iterate through the tiles, in row order, whose values match the predicate
+    #[allow(clippy::missing_panics_doc)]
+    pub fn positions<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = Tile<WIDTH, HEIGHT>> + 'a {
+        self.0
+            .iter()
+            .enumerate()
+            .filter(move |(_, x)| pred(x))
+            .map(|(inner, _)| Tile::try_from_usize(inner).unwrap())
+    }
+
+    /// Iterate through every tile and its value, sorted by comparing the values with `compare`.
+    /// Tiles whose values are equal stay in row order.
+    /// Does not allocate.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn tiles_sorted_by(
+        &self,
+        mut compare: impl FnMut(&T, &T) -> Ordering,
+    ) -> impl ExactSizeIterator<Item = (Tile<WIDTH, HEIGHT>, &'_ T)> + DoubleEndedIterator + '_
+    {
+        let mut tiles: [Tile<WIDTH, HEIGHT>; SIZE] =
+            core::array::from_fn(|inner| Tile::try_from_usize(inner).unwrap());
+        tiles.sort_unstable_by(|a, b| compare(&self[*a], &self[*b]).then(a.cmp(b)));
+        tiles.into_iter().map(move |tile| (tile, &self[tile]))
+    }
+
+    /// Iterate through the `k` tiles with the greatest keys, greatest first.
+    /// Tiles whose keys are equal are in row order.
+    /// Like `slice::sort_by_key`, `f` is called for every comparison.
+    /// Does not allocate.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn top_k_by_key<K: Ord>(
+        &self,
+        k: usize,
+        mut f: impl FnMut(&T) -> K,
+    ) -> impl ExactSizeIterator<Item = (Tile<WIDTH, HEIGHT>, &'_ T)> + DoubleEndedIterator + '_
+    {
+        let k = k.min(SIZE);
+        let mut tiles: [Tile<WIDTH, HEIGHT>; SIZE] =
+            core::array::from_fn(|inner| Tile::try_from_usize(inner).unwrap());
+        let mut compare = |a: &Tile<WIDTH, HEIGHT>, b: &Tile<WIDTH, HEIGHT>| {
+            f(&self[*b]).cmp(&f(&self[*a])).then(a.cmp(b))
+        };
+        if k > 0 && k < SIZE {
+            tiles.select_nth_unstable_by(k - 1, &mut compare);
+        }
+        tiles[..k].sort_unstable_by(compare);
+        tiles
+            .into_iter()
+            .take(k)
+            .map(move |tile| (tile, &self[tile]))
+    }
+
     /// Get the scale to make the grid take up as much as possible of a given area
     #[must_use]
     pub fn get_scale(total_width: f32, total_height: f32) -> f32 {
@@ -154,6 +327,99 @@ impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileMap<T, WIDTH,
             }
         }
     }
+
+    /// Summarize rectangular chunks of this map into a smaller map.
+    /// Each chunk is `WIDTH / W2` by `HEIGHT / H2` tiles and its values are passed to `f` in row order.
+    /// # Panics
+    /// If `W2` and `H2` do not exactly divide `WIDTH` and `HEIGHT`
+    pub fn chunked<U, const W2: u8, const H2: u8, const S2: usize>(
+        &self,
+        mut f: impl FnMut(&[&T]) -> U,
+    ) -> TileMap<U, W2, H2, S2> {
+        assert!(
+            W2 > 0 && H2 > 0 && WIDTH % W2 == 0 && HEIGHT % H2 == 0,
+            "The chunked grid must divide the size of the grid"
+        );
+        let chunk_width = WIDTH / W2;
+        let chunk_height = HEIGHT / H2;
+        let mut buffer: [&T; SIZE] = core::array::from_fn(|i| &self.0[i]);
+
+        TileMap::from_fn(|chunk| {
+            let mut len = 0;
+            for y in 0..chunk_height {
+                for x in 0..chunk_width {
+                    let tile = Tile::<WIDTH, HEIGHT>::new_unchecked(
+                        chunk.x() * chunk_width + x,
+                        chunk.y() * chunk_height + y,
+                    );
+                    buffer[len] = &self[tile];
+                    len += 1;
+                }
+            }
+            f(&buffer[..len])
+        })
+    }
+}
+
+impl<T: Copy, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Like `from_rows`, but usable in const contexts such as level data.
+    /// Fails to compile if there are not `HEIGHT` rows of `WIDTH` values, or the grid is empty.
+    /// # Panics
+    /// If `SIZE` does not match the dimensions
+    pub const fn const_from_rows<const W: usize, const H: usize>(rows: [[T; W]; H]) -> Self {
+        const {
+            assert!(
+                W == WIDTH as usize,
+                "const_from_rows needs rows of WIDTH values"
+            );
+        };
+        const {
+            assert!(H == HEIGHT as usize, "const_from_rows needs HEIGHT rows");
+        };
+        const {
+            assert!(W > 0 && H > 0, "const_from_rows needs at least one tile");
+        };
+        Self::assert_legal();
+        let mut values = [rows[0][0]; SIZE];
+        let mut index = 0;
+        while index < SIZE {
+            values[index] = rows[index / W][index % W];
+            index += 1;
+        }
+        Self(values)
+    }
+
+    /// Like `from_columns`, but usable in const contexts.
+    /// Fails to compile if there are not `WIDTH` columns of `HEIGHT` values, or the grid is empty.
+    /// # Panics
+    /// If `SIZE` does not match the dimensions
+    pub const fn const_from_columns<const W: usize, const H: usize>(columns: [[T; H]; W]) -> Self {
+        const {
+            assert!(
+                W == WIDTH as usize,
+                "const_from_columns needs WIDTH columns"
+            );
+        };
+        const {
+            assert!(
+                H == HEIGHT as usize,
+                "const_from_columns needs columns of HEIGHT values"
+            );
+        };
+        const {
+            assert!(W > 0 && H > 0, "const_from_columns needs at least one tile");
+        };
+        Self::assert_legal();
+        let mut values = [columns[0][0]; SIZE];
+        let mut index = 0;
+        while index < SIZE {
+            values[index] = columns[index % W][index / W];
+            index += 1;
+        }
+        Self(values)
+    }
 }
 
 impl<T, const L: u8, const SIZE: usize> TileMap<T, L, L, SIZE> {
@@ -172,7 +438,7 @@ impl<T, const L: u8, const SIZE: usize> TileMap<T, L, L, SIZE> {
                     'x: loop {
                         if x + y + 1 >= L {
                             break 'x;
-                        };
+                        }
 
                         let o_x = L - (1 + x);
                         if y != o_y || x != o_x {
@@ -193,7 +459,7 @@ impl<T, const L: u8, const SIZE: usize> TileMap<T, L, L, SIZE> {
                 }
             }
             QuarterTurns::Two => {
-                for y in 0..((L + 1) / 2) {
+                for y in 0..L.div_ceil(2) {
                     let o_y = L - (1 + y);
                     let x_max = if (y * 2) + 1 == L { L / 2 } else { L };
                     for x in 0..x_max {
@@ -215,7 +481,7 @@ impl<T, const L: u8, const SIZE: usize> TileMap<T, L, L, SIZE> {
                     'x: loop {
                         if x + y + 1 >= L {
                             break 'x;
-                        };
+                        }
 
                         let o_x = L - (1 + x);
                         if y != o_y || x != o_x {
@@ -245,6 +511,26 @@ impl<T: Clone, const L: u8, const SIZE: usize> TileMap<T, L, L, SIZE> {
         grid.rotate(quarter_turns);
         grid
     }
+
+    /// Apply a combined flip and rotation to the whole grid
+    pub fn with_transform(&self, transform: &Dihedral) -> Self {
+        let mut grid = self.clone();
+        if transform.flipped {
+            grid.flip(FlipAxes::Horizontal);
+        }
+        grid.rotate(transform.quarter_turns);
+        grid
+    }
+}
+
+impl<T: Clone + PartialEq, const L: u8, const SIZE: usize> TileMap<T, L, L, SIZE> {
+    /// Whether some combination of flips and rotations transforms this grid into `other`
+    #[must_use]
+    pub fn is_congruent_to(&self, other: &Self) -> bool {
+        Dihedral::ALL
+            .iter()
+            .any(|transform| &self.with_transform(transform) == other)
+    }
 }
 
 impl<T: Clone, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
@@ -257,6 +543,18 @@ impl<T: Clone, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
     }
 }
 
+impl<T, const W: u8, const H: u8, const SIZE: usize> Flippable for TileMap<T, W, H, SIZE> {
+    fn flip(&mut self, axes: FlipAxes) {
+        TileMap::flip(self, axes);
+    }
+}
+
+impl<T, const L: u8, const SIZE: usize> Rotatable for TileMap<T, L, L, SIZE> {
+    fn rotate(&mut self, quarter_turns: QuarterTurns) {
+        TileMap::rotate(self, quarter_turns);
+    }
+}
+
 impl<T, const W: u8, const H: u8, const SIZE: usize> Index<Tile<W, H>> for TileMap<T, W, H, SIZE> {
     type Output = T;
 
@@ -320,6 +618,132 @@ impl<T, const W: u8, const H: u8, const SIZE: usize> IntoIterator for TileMap<T,
     }
 }
 
+impl<T: Copy + Into<i32>, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Apply a kernel to every tile, summing the neighbouring values weighted by the kernel.
+    /// The kernel is centred on the tile at `(KW / 2, KH / 2)` and is not flipped.
+    /// Positions outside the grid are handled according to `edge`.
+    pub fn convolve<const KW: u8, const KH: u8, const KSIZE: usize>(
+        &self,
+        kernel: &TileMap<i32, KW, KH, KSIZE>,
+        edge: EdgePolicy,
+    ) -> TileMap<i32, WIDTH, HEIGHT, SIZE> {
+        TileMap::from_fn(|tile| {
+            kernel
+                .enumerate()
+                .filter(|(_, weight)| **weight != 0)
+                .filter_map(|(k, weight)| {
+                    let x = i16::from(tile.x()) + i16::from(k.x()) - i16::from(KW / 2);
+                    let y = i16::from(tile.y()) + i16::from(k.y()) - i16::from(KH / 2);
+                    edge.resolve(x, y)
+                        .map(|source: Tile<WIDTH, HEIGHT>| weight * self[source].into())
+                })
+                .sum()
+        })
+    }
+}
+
+impl<T: PartialEq, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Whether the two maps have equal values at every tile in the mask.
+    /// Tiles outside the mask are ignored.
+    pub fn eq_where(
+        &self,
+        other: &Self,
+        mask: impl IntoIterator<Item = Tile<WIDTH, HEIGHT>>,
+    ) -> bool {
+        mask.into_iter().all(|tile| self[tile] == other[tile])
+    }
+
+    /// The first tile, in row order, with the given value
+    #[must_use]
+    pub fn find_value(&self, value: &T) -> Option<Tile<WIDTH, HEIGHT>> {
+        self.find(|x| x == value)
+    }
+
+    /// The tiles whose values differ between the two maps
+    pub fn diff<S: FromIterator<Tile<WIDTH, HEIGHT>>>(&self, other: &Self) -> S {
+        Tile::<WIDTH, HEIGHT>::iter_by_row()
+            .filter(|tile| self[*tile] != other[*tile])
+            .collect()
+    }
+}
+
+#[cfg(any(test, feature = "std"))]
+impl<T: PartialEq + fmt::Display, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Write the map as a run-length encoded string of the form `3*a,1*b`.
+    /// The displayed values must not contain `,` or `*`.
+    /// Requires `std`
+    #[must_use]
+    pub fn to_rle_string(&self) -> String {
+        use itertools::Itertools;
+        let mut result = String::new();
+        for (count, value) in self.0.iter().dedup_with_count() {
+            if !result.is_empty() {
+                result.push(',');
+            }
+            // Writing to a string cannot fail
+            let _ = write!(result, "{count}*{value}");
+        }
+        result
+    }
+}
+
+impl<T: FromStr + Clone, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Read a map from a run-length encoded string of the form `3*a,1*b`
+    ///
+    /// # Errors
+    /// If a run is malformed, a value cannot be parsed, or the runs do not contain exactly `SIZE` values.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn from_rle_str(s: &str) -> Result<Self, ParseRleError> {
+        let mut total: usize = 0;
+        for run in Self::parse_runs(s) {
+            let (count, _) = run?;
+            total = match total.checked_add(count) {
+                Some(total) if total <= SIZE => total,
+                _ => return Err(ParseRleError::TooManyTiles),
+            };
+        }
+        if total != SIZE {
+            return Err(ParseRleError::NotEnoughTiles {
+                expected: SIZE,
+                found: total,
+            });
+        }
+
+        let mut values = Self::parse_runs(s).flat_map(|run| {
+            let (count, value) = run.unwrap();
+            iter::repeat_n(value, count)
+        });
+
+        Ok(Self(core::array::from_fn(|_| values.next().unwrap())))
+    }
+
+    fn parse_runs(s: &str) -> impl Iterator<Item = Result<(usize, T), ParseRleError>> + '_ {
+        s.split_terminator(',').enumerate().map(|(run, text)| {
+            let (count, value) = text
+                .split_once('*')
+                .ok_or(ParseRleError::MissingSeparator { run })?;
+            let count: usize = count
+                .parse()
+                .map_err(|_| ParseRleError::InvalidRunLength { run })?;
+            if count == 0 {
+                return Err(ParseRleError::InvalidRunLength { run });
+            }
+            let value: T = value
+                .parse()
+                .map_err(|_| ParseRleError::InvalidValue { run })?;
+            Ok((count, value))
+        })
+    }
+}
+
 impl<T: fmt::Display, const W: u8, const H: u8, const SIZE: usize> fmt::Display
     for TileMap<T, W, H, SIZE>
 {
@@ -341,17 +765,101 @@ impl<T: fmt::Display, const W: u8, const H: u8, const SIZE: usize> fmt::Display
     }
 }
 
+/// The `TileMap` type for a grid of the given width and height, computing `SIZE`.
+/// `tile_map!(u8, 4, 3)` is `TileMap<u8, 4, 3, 12>`
+#[macro_export]
+macro_rules! tile_map {
+    ($t:ty, $width:expr, $height:expr) => {
+        $crate::tile_map::TileMap<$t, { $width }, { $height }, { ($width as usize) * ($height as usize) }>
+    };
+}
+
 #[cfg(test)]
 mod tests {
-    use core::usize;
-
     use super::*;
     use itertools::Itertools;
     #[cfg(any(test, feature = "serde"))]
     use serde_test::{assert_tokens, Token};
 
     #[test]
-    #[should_panic(expected = "assertion failed")]
+    fn test_tile_map_macro() {
+        let map: tile_map!(u8, 4, 3) = TileMap::from_fn(|t| t.inner());
+        let _: TileMap<u8, 4, 3, 12> = map;
+        assert_eq!(map.into_inner().len(), 12);
+    }
+
+    #[test]
+    fn test_into_enumerate() {
+        let map: TileMap<String, 3, 2, 6> = TileMap::from_fn(|t| t.to_string());
+
+        assert_eq!(map.keys().join("|"), "(0,0)|(1,0)|(2,0)|(0,1)|(1,1)|(2,1)");
+        assert!(map
+            .clone()
+            .into_enumerate()
+            .all(|(tile, value)| tile.to_string() == value));
+        assert_eq!(
+            map.into_enumerate().next_back(),
+            Some((Tile::new_const::<2, 1>(), "(2,1)".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_sorting_and_top_k() {
+        let map: TileMap<u8, 3, 2, 6> = TileMap::from_inner([4, 1, 4, 0, 9, 1]);
+
+        assert_eq!(
+            map.tiles_sorted_by(Ord::cmp)
+                .map(|(t, v)| format!("{t}={v}"))
+                .join("|"),
+            "(0,1)=0|(1,0)=1|(2,1)=1|(0,0)=4|(2,0)=4|(1,1)=9"
+        );
+        assert_eq!(
+            map.top_k_by_key(3, |v| *v)
+                .map(|(t, v)| format!("{t}={v}"))
+                .join("|"),
+            "(1,1)=9|(0,0)=4|(2,0)=4"
+        );
+        assert_eq!(map.top_k_by_key(0, |v| *v).len(), 0);
+        assert_eq!(map.top_k_by_key(10, |v| *v).len(), 6);
+        assert!(map
+            .top_k_by_key(6, |v| core::cmp::Reverse(*v))
+            .eq(map.tiles_sorted_by(Ord::cmp)));
+    }
+
+    #[test]
+    fn test_from_rows_and_columns() {
+        let rows: TileMap<u8, 3, 2, 6> = TileMap::from_rows([
+            [1, 2, 3], //
+            [4, 5, 6],
+        ]);
+        assert_eq!(rows.into_inner(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(rows[Tile::new_const::<2, 0>()], 3);
+
+        let columns: TileMap<u8, 3, 2, 6> = TileMap::from_columns([[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(columns, rows);
+
+        let strings: TileMap<String, 2, 2, 4> = TileMap::from_columns([
+            ["a".to_string(), "b".to_string()],
+            ["c".to_string(), "d".to_string()],
+        ]);
+        assert_eq!(strings.iter().join(""), "acbd");
+    }
+
+    #[test]
+    fn test_const_from_rows_and_columns() {
+        const ROWS: TileMap<u8, 3, 2, 6> = TileMap::const_from_rows([
+            [1, 2, 3], //
+            [4, 5, 6],
+        ]);
+        const COLUMNS: TileMap<u8, 3, 2, 6> = TileMap::const_from_columns([[1, 4], [2, 5], [3, 6]]);
+
+        assert_eq!(ROWS.into_inner(), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(COLUMNS, ROWS);
+        assert_eq!(ROWS, TileMap::from_rows([[1, 2, 3], [4, 5, 6]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "SIZE does not match the grid dimensions")]
     #[allow(unused_variables)]
     fn test_bad_grid() {
         let grid: TileMap<usize, 3, 3, 10> = TileMap::default();
@@ -385,7 +893,8 @@ mod tests {
                               2|1|0",
             ),
         ] {
-            let grid: TileMap<usize, 3, 3, 9> = TileMap::from_fn(|x| x.into()).with_flip(axes);
+            let grid: TileMap<usize, 3, 3, 9> =
+                TileMap::from_fn(std::convert::Into::into).with_flip(axes);
 
             assert_eq!(grid.to_string(), expected);
         }
@@ -422,7 +931,8 @@ mod tests {
                   3|2|1|0",
             ),
         ] {
-            let grid: TileMap<usize, 4, 4, 16> = TileMap::from_fn(|x| x.into()).with_flip(axes);
+            let grid: TileMap<usize, 4, 4, 16> =
+                TileMap::from_fn(std::convert::Into::into).with_flip(axes);
             assert_eq!(grid.to_string(), expected);
         }
     }
@@ -482,8 +992,186 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_with_transform() {
+        fn check<const L: u8, const SIZE: usize>() {
+            let grid: TileMap<usize, L, L, SIZE> = TileMap::from_fn(std::convert::Into::into);
+            for transform in Dihedral::ALL {
+                let transformed = grid.with_transform(&transform);
+                for tile in Tile::<L, L>::iter_by_row() {
+                    assert_eq!(transformed[transform.apply_to_tile(&tile)], grid[tile]);
+                }
+                assert!(transformed.is_congruent_to(&grid));
+            }
+        }
+
+        check::<1, 1>();
+        check::<4, 16>();
+        check::<5, 25>();
+    }
+
+    #[test]
+    fn test_is_congruent_to() {
+        let grid: TileMap<u8, 2, 2, 4> = TileMap::from_inner([1, 2, 3, 4]);
+        assert!(grid.is_congruent_to(&TileMap::from_inner([2, 1, 4, 3])));
+        assert!(grid.is_congruent_to(&TileMap::from_inner([4, 2, 3, 1])));
+        assert!(!grid.is_congruent_to(&TileMap::from_inner([1, 2, 4, 3])));
+    }
+
+    #[test]
+    fn test_display_with() {
+        let map: TileMap<u8, 3, 2, 6> = TileMap::from_fn(|t| t.x() + t.y());
+
+        assert_eq!(
+            map.display_with(|x| if *x > 1 { 'X' } else { 'o' })
+                .to_string(),
+            "ooX\noXX"
+        );
+        assert_eq!(
+            map.display_with(|x| x * 10)
+                .with_separator(' ')
+                .with_headers(true)
+                .to_string(),
+            "  0 1 2\n0 0 10 20\n1 10 20 30"
+        );
+    }
+
+    #[test]
+    fn test_convolve() {
+        let map: TileMap<u8, 3, 3, 9> = TileMap::from_fn(|t| t.inner());
+        let kernel: TileMap<i32, 3, 3, 9> = TileMap::from_inner([0, 1, 0, 1, -4, 1, 0, 1, 0]);
+
+        assert_eq!(
+            map.convolve(&kernel, EdgePolicy::Zero).to_string(),
+            "4|2|-2\n-2|0|-6\n-14|-10|-20"
+        );
+        assert_eq!(
+            map.convolve(&kernel, EdgePolicy::Clamp).to_string(),
+            "4|3|2\n1|0|-1\n-2|-3|-4"
+        );
+        assert_eq!(
+            map.convolve(&kernel, EdgePolicy::Wrap).to_string(),
+            "12|9|6\n3|0|-3\n-6|-9|-12"
+        );
+
+        let identity: TileMap<i32, 1, 1, 1> = TileMap::from_inner([2]);
+        assert_eq!(
+            map.convolve(&identity, EdgePolicy::Zero),
+            TileMap::from_fn(|t| 2 * i32::from(t.inner()))
+        );
+    }
+
+    #[test]
+    fn test_try_from_inner() {
+        assert_eq!(
+            TileMap::<u8, 2, 2, 4>::try_from_inner([1, 2, 3, 4]),
+            Ok(TileMap::from_inner([1, 2, 3, 4]))
+        );
+        assert_eq!(
+            TileMap::<u8, 2, 3, 4>::try_from_inner([1, 2, 3, 4]),
+            Err(DimensionError::SizeMismatch {
+                expected: 6,
+                found: 4
+            })
+        );
+        assert_eq!(
+            TileMap::<u8, 2, 2, 4>::try_from_fn(|t| t.inner()),
+            Ok(TileMap::from_inner([0, 1, 2, 3]))
+        );
+        assert!(TileMap::<u8, 2, 2, 5>::try_from_fn(|_| 0).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "SIZE does not match the grid dimensions")]
+    fn test_from_fn_wrong_size() {
+        let _ = TileMap::<u8, 2, 3, 4>::from_fn(|_| 0);
+    }
+
+    #[test]
+    fn test_find() {
+        let map: TileMap<u8, 3, 3, 9> = TileMap::from_fn(|t| t.x() + t.y());
+
+        assert_eq!(map.find(|x| *x == 2), Some(Tile::new_const::<2, 0>()));
+        assert_eq!(map.rfind(|x| *x == 2), Some(Tile::new_const::<0, 2>()));
+        assert_eq!(map.find_value(&4), Some(Tile::SOUTH_EAST));
+        assert_eq!(map.find_value(&5), None);
+        assert_eq!(map.positions(|x| *x == 1).join("|"), "(1,0)|(0,1)");
+        assert_eq!(map.positions(|x| *x > 4).count(), 0);
+    }
+
+    #[test]
+    fn test_diff_and_eq_where() {
+        let a: TileMap<u8, 3, 3, 9> = TileMap::from_inner([0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let b: TileMap<u8, 3, 3, 9> = TileMap::from_inner([0, 1, 9, 3, 4, 5, 9, 7, 8]);
+
+        let diff: TileSet16<3, 3, 9> = a.diff(&b);
+        assert_eq!(diff.to_string(), "__*\n___\n*__");
+        assert_eq!(a.diff::<TileSet16<3, 3, 9>>(&a), TileSet16::EMPTY);
+
+        assert!(a.eq_where(&b, diff.negate()));
+        assert!(!a.eq_where(&b, diff));
+        assert!(!a.eq_where(&b, Tile::iter_by_row()));
+        assert!(a.eq_where(&b, [Tile::CENTER]));
+    }
+
+    #[test]
+    fn test_rle() {
+        let grid: TileMap<u8, 3, 3, 9> = TileMap::from_inner([0, 0, 0, 1, 2, 2, 0, 0, 0]);
+        let rle = grid.to_rle_string();
+        assert_eq!(rle, "3*0,1*1,2*2,3*0");
+        assert_eq!(TileMap::from_rle_str(&rle), Ok(grid));
+
+        let grid: TileMap<u8, 4, 4, 16> = TileMap::default();
+        assert_eq!(grid.to_rle_string(), "16*0");
+        assert_eq!(TileMap::from_rle_str("16*0"), Ok(grid));
+    }
+
+    #[test]
+    fn test_rle_errors() {
+        type Grid = TileMap<u8, 2, 2, 4>;
+        assert_eq!(
+            Grid::from_rle_str("3*0"),
+            Err(ParseRleError::NotEnoughTiles {
+                expected: 4,
+                found: 3
+            })
+        );
+        assert_eq!(
+            Grid::from_rle_str("3*0,2*1"),
+            Err(ParseRleError::TooManyTiles)
+        );
+        assert_eq!(
+            Grid::from_rle_str("4"),
+            Err(ParseRleError::MissingSeparator { run: 0 })
+        );
+        assert_eq!(
+            Grid::from_rle_str("x*1"),
+            Err(ParseRleError::InvalidRunLength { run: 0 })
+        );
+        assert_eq!(
+            Grid::from_rle_str("0*1,4*1"),
+            Err(ParseRleError::InvalidRunLength { run: 0 })
+        );
+        assert_eq!(
+            Grid::from_rle_str("2*1,2*a"),
+            Err(ParseRleError::InvalidValue { run: 1 })
+        );
+        assert_eq!(
+            Grid::from_rle_str("1*0,18446744073709551615*1,4*2"),
+            Err(ParseRleError::TooManyTiles)
+        );
+        assert_eq!(
+            Grid::from_rle_str(""),
+            Err(ParseRleError::NotEnoughTiles {
+                expected: 4,
+                found: 0
+            })
+        );
+    }
+
     fn test_rotation<const LENGTH: u8, const SIZE: usize>(e: &str) {
-        let original_grid: TileMap<usize, LENGTH, LENGTH, SIZE> = TileMap::from_fn(|x| x.into());
+        let original_grid: TileMap<usize, LENGTH, LENGTH, SIZE> =
+            TileMap::from_fn(std::convert::Into::into);
 
         let rotated_0 = original_grid.with_rotate(QuarterTurns::Zero);
         assert_eq!(original_grid, rotated_0);
@@ -516,10 +1204,10 @@ mod tests {
 
     #[test]
     fn basic_tests() {
-        let grid: TileMap<usize, 3, 3, 9> = TileMap::from_fn(|x| x.into());
+        let grid: TileMap<usize, 3, 3, 9> = TileMap::from_fn(std::convert::Into::into);
 
         for i in 0..9 {
-            assert_eq!(grid[Tile::<3, 3>::try_from_usize(i).unwrap()], i)
+            assert_eq!(grid[Tile::<3, 3>::try_from_usize(i).unwrap()], i);
         }
 
         let str = grid.to_string();
@@ -540,7 +1228,7 @@ mod tests {
     #[cfg(any(test, feature = "serde"))]
     #[test]
     fn test_serde() {
-        let grid: TileMap<usize, 2, 2, 4> = TileMap::from_fn(|x| x.into());
+        let grid: TileMap<usize, 2, 2, 4> = TileMap::from_fn(std::convert::Into::into);
 
         assert_tokens(
             &grid,
@@ -557,7 +1245,23 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::float_cmp)]
     fn test_get_scale() {
         assert_eq!(TileMap::<usize, 3, 2, 4>::get_scale(12.0, 20.0), 4.0);
     }
+
+    #[test]
+    fn test_chunked() {
+        let map: TileMap<u8, 4, 6, 24> = TileMap::from_fn(|tile| tile.inner());
+
+        let sums: TileMap<u32, 2, 2, 4> =
+            map.chunked(|values| values.iter().map(|v| u32::from(**v)).sum());
+        assert_eq!(sums.to_string(), "27|39\n99|111");
+
+        let firsts: TileMap<u8, 4, 2, 8> = map.chunked(|values| *values[0]);
+        assert_eq!(firsts.to_string(), "0|1|2|3\n12|13|14|15");
+
+        let lens: TileMap<usize, 1, 1, 1> = map.chunked(|values| values.len());
+        assert_eq!(lens[Tile::NORTH_WEST], 24);
+    }
 }
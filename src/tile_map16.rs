@@ -0,0 +1,353 @@
+use core::{
+    fmt::{self, Write},
+    iter,
+    ops::{Index, IndexMut},
+};
+
+use crate::prelude::*;
+
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+/// A map from `Tile16` tiles to values.
+/// Use this instead of `TileMap` for grids with more than 255 tiles.
+#[must_use]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+pub struct TileMap16<T, const WIDTH: u16, const HEIGHT: u16, const SIZE: usize>(
+    #[cfg_attr(any(test, feature = "serde"), serde(with = "serde_arrays"))]
+    #[cfg_attr(any(test, feature = "serde"), serde(bound(serialize = "T: Serialize")))]
+    #[cfg_attr(
+        any(test, feature = "serde"),
+        serde(bound(deserialize = "T: Deserialize<'de>"))
+    )]
+    [T; SIZE],
+);
+
+impl<T: Default + Copy, const WIDTH: u16, const HEIGHT: u16, const SIZE: usize> Default
+    for TileMap16<T, WIDTH, HEIGHT, SIZE>
+{
+    fn default() -> Self {
+        Self::assert_legal();
+        Self([T::default(); SIZE])
+    }
+}
+
+impl<T, const WIDTH: u16, const HEIGHT: u16, const SIZE: usize> TileMap16<T, WIDTH, HEIGHT, SIZE> {
+    /// The largest number of tiles which can be indexed by a `Tile16`
+    const MAX_SIZE: usize = u16::MAX as usize + 1;
+
+    #[allow(clippy::missing_panics_doc)]
+    pub fn from_fn<F: FnMut(Tile16<WIDTH, HEIGHT>) -> T>(mut cb: F) -> Self {
+        Self::assert_legal();
+        let arr = core::array::from_fn(|i| cb(Tile16::try_from_usize(i).unwrap()));
+        Self(arr)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> [T; SIZE] {
+        let Self(inner) = self;
+        inner
+    }
+
+    #[inline]
+    pub const fn from_inner(inner: [T; SIZE]) -> Self {
+        Self::assert_legal();
+        Self(inner)
+    }
+
+    /// Create a map from its values, checking that `SIZE` matches the dimensions
+    /// # Errors
+    /// If `SIZE` is not the number of tiles in the grid
+    pub fn try_from_inner(inner: [T; SIZE]) -> Result<Self, DimensionError> {
+        DimensionError::check(WIDTH as usize * HEIGHT as usize, SIZE, Self::MAX_SIZE)?;
+        Ok(Self(inner))
+    }
+
+    /// Panics if `SIZE` is not the number of tiles in the grid
+    #[inline]
+    const fn assert_legal() {
+        DimensionError::assert(WIDTH as usize * HEIGHT as usize, SIZE, Self::MAX_SIZE);
+    }
+
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn enumerate(&self) -> impl iter::Iterator<Item = (Tile16<WIDTH, HEIGHT>, &'_ T)> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(inner, x)| (Tile16::try_from_usize(inner).unwrap(), x))
+    }
+
+    #[inline]
+    pub fn swap(&mut self, p1: Tile16<WIDTH, HEIGHT>, p2: Tile16<WIDTH, HEIGHT>) {
+        self.0.swap(p1.into(), p2.into());
+    }
+
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn row(&self, y: u16) -> &[T] {
+        let start = y as usize * WIDTH as usize;
+        &self.0[start..start + WIDTH as usize]
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn row_mut(&mut self, y: u16) -> &mut [T] {
+        let start = y as usize * WIDTH as usize;
+        &mut self.0[start..start + WIDTH as usize]
+    }
+
+    /// Iterate through the values in a column, from north to south
+    pub fn column_iter(&self, x: u16) -> impl DoubleEndedIterator<Item = &'_ T> + '_ {
+        self.0.iter().skip(x as usize).step_by(WIDTH as usize)
+    }
+
+    pub fn flip(&mut self, axes: FlipAxes) {
+        for tile in Tile16::<WIDTH, HEIGHT>::iter_by_row() {
+            let flipped = tile.flip(axes);
+            if tile < flipped {
+                self.swap(tile, flipped);
+            }
+        }
+    }
+}
+
+impl<T, const L: u16, const SIZE: usize> TileMap16<T, L, L, SIZE> {
+    pub fn rotate(&mut self, quarter_turns: QuarterTurns) {
+        // Each ring of the grid is rotated by cycling groups of four tiles
+        for y in 0..L / 2 {
+            for x in y..(L - 1 - y) {
+                let p0 = Tile16::new_unchecked(x, y);
+                let p1 = p0.rotate(QuarterTurns::One);
+                let p2 = p0.rotate(QuarterTurns::Two);
+                let p3 = p0.rotate(QuarterTurns::Three);
+
+                match quarter_turns {
+                    QuarterTurns::Zero => {}
+                    QuarterTurns::One => {
+                        self.swap(p0, p1);
+                        self.swap(p0, p2);
+                        self.swap(p0, p3);
+                    }
+                    QuarterTurns::Two => {
+                        self.swap(p0, p2);
+                        self.swap(p1, p3);
+                    }
+                    QuarterTurns::Three => {
+                        self.swap(p0, p1);
+                        self.swap(p1, p2);
+                        self.swap(p2, p3);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T: Clone, const L: u16, const SIZE: usize> TileMap16<T, L, L, SIZE> {
+    pub fn with_rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        let mut grid = self.clone();
+        grid.rotate(quarter_turns);
+        grid
+    }
+}
+
+impl<T: Clone, const WIDTH: u16, const HEIGHT: u16, const SIZE: usize>
+    TileMap16<T, WIDTH, HEIGHT, SIZE>
+{
+    pub fn with_flip(&self, axes: FlipAxes) -> Self {
+        let mut grid = self.clone();
+        grid.flip(axes);
+        grid
+    }
+}
+
+impl<T, const W: u16, const H: u16, const SIZE: usize> Flippable for TileMap16<T, W, H, SIZE> {
+    fn flip(&mut self, axes: FlipAxes) {
+        TileMap16::flip(self, axes);
+    }
+}
+
+impl<T, const L: u16, const SIZE: usize> Rotatable for TileMap16<T, L, L, SIZE> {
+    fn rotate(&mut self, quarter_turns: QuarterTurns) {
+        TileMap16::rotate(self, quarter_turns);
+    }
+}
+
+impl<T, const W: u16, const H: u16, const SIZE: usize> Index<Tile16<W, H>>
+    for TileMap16<T, W, H, SIZE>
+{
+    type Output = T;
+
+    fn index(&self, index: Tile16<W, H>) -> &Self::Output {
+        let u: usize = index.into();
+        &self.0[u]
+    }
+}
+
+impl<T, const W: u16, const H: u16, const SIZE: usize> IndexMut<Tile16<W, H>>
+    for TileMap16<T, W, H, SIZE>
+{
+    fn index_mut(&mut self, index: Tile16<W, H>) -> &mut Self::Output {
+        let u: usize = index.into();
+        &mut self.0[u]
+    }
+}
+
+impl<T, const W: u16, const H: u16, const SIZE: usize> AsRef<[T; SIZE]>
+    for TileMap16<T, W, H, SIZE>
+{
+    #[inline]
+    fn as_ref(&self) -> &[T; SIZE] {
+        &self.0
+    }
+}
+
+impl<T, const W: u16, const H: u16, const SIZE: usize> AsMut<[T; SIZE]>
+    for TileMap16<T, W, H, SIZE>
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut [T; SIZE] {
+        &mut self.0
+    }
+}
+
+impl<'a, T, const W: u16, const H: u16, const SIZE: usize> IntoIterator
+    for &'a TileMap16<T, W, H, SIZE>
+{
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const W: u16, const H: u16, const SIZE: usize> IntoIterator
+    for &'a mut TileMap16<T, W, H, SIZE>
+{
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const W: u16, const H: u16, const SIZE: usize> IntoIterator for TileMap16<T, W, H, SIZE> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, SIZE>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.0)
+    }
+}
+
+impl<T: fmt::Display, const W: u16, const H: u16, const SIZE: usize> fmt::Display
+    for TileMap16<T, W, H, SIZE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.0.iter().enumerate() {
+            if i == 0 {
+            } else if !f.alternate() && i % (W as usize) == 0 {
+                f.write_char('\n')?;
+            } else {
+                f.write_char('|')?;
+            }
+
+            e.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The `TileMap16` type for a grid of the given width and height, computing `SIZE`.
+/// `tile_map16!(u8, 40, 30)` is `TileMap16<u8, 40, 30, 1200>`
+#[macro_export]
+macro_rules! tile_map16 {
+    ($t:ty, $width:expr, $height:expr) => {
+        $crate::tile_map16::TileMap16<$t, { $width }, { $height }, { ($width as usize) * ($height as usize) }>
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_large_map() {
+        let mut map: tile_map16!(u16, 40, 30) = TileMap16::from_fn(|tile| tile.x() + tile.y());
+
+        assert_eq!(map[Tile16::SOUTH_EAST], 68);
+        assert_eq!(map.row(29).len(), 40);
+        assert_eq!(map.column_iter(39).count(), 30);
+        assert_eq!(
+            map.enumerate()
+                .find(|(_, value)| **value == 68)
+                .map(|x| x.0),
+            Some(Tile16::SOUTH_EAST)
+        );
+
+        map[Tile16::NORTH_WEST] = 1000;
+        assert_eq!(map.iter().max(), Some(&1000));
+        assert_eq!(map.into_iter().count(), 1200);
+    }
+
+    #[test]
+    fn test_matches_tile_map() {
+        let map: TileMap<u8, 5, 5, 25> = TileMap::from_fn(|tile| tile.inner());
+        #[allow(clippy::cast_possible_truncation)]
+        let map16: TileMap16<u8, 5, 5, 25> = TileMap16::from_fn(|tile| tile.inner() as u8);
+
+        for axes in FlipAxes::iter() {
+            assert_eq!(
+                map.with_flip(axes).iter().join("|"),
+                map16.with_flip(axes).iter().join("|"),
+                "{axes}"
+            );
+        }
+
+        for quarter_turns in QuarterTurns::iter() {
+            assert_eq!(
+                map.with_rotate(quarter_turns).iter().join("|"),
+                map16.with_rotate(quarter_turns).iter().join("|"),
+                "{quarter_turns}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let map: TileMap16<u16, 3, 2, 6> = TileMap16::from_fn(|tile| tile.inner());
+
+        assert_eq!(map.to_string(), "0|1|2\n3|4|5");
+        assert_eq!(format!("{map:#}"), "0|1|2|3|4|5");
+    }
+
+    #[test]
+    fn test_try_from_inner() {
+        assert!(TileMap16::<u8, 3, 2, 6>::try_from_inner([0; 6]).is_ok());
+        assert_eq!(
+            TileMap16::<u8, 3, 2, 7>::try_from_inner([0; 7]),
+            Err(DimensionError::SizeMismatch {
+                expected: 6,
+                found: 7
+            })
+        );
+    }
+}
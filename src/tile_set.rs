@@ -3,18 +3,36 @@ use core::{
     ops::FnMut,
 };
 
+use crate::ascii_options::AsciiGridReader;
+use crate::moments::TileSums;
 use crate::prelude::*;
 
 #[cfg(any(test, feature = "serde"))]
 use serde::{Deserialize, Serialize};
 
+pub(crate) const fn min_i16(a: i16, b: i16) -> i16 {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+pub(crate) const fn max_i16(a: i16, b: i16) -> i16 {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
 macro_rules! tile_set {
     ($name:ident, $iter_name:ident, $true_iter_name:ident, $inner: ty) => {
         /// A grid
         /// A map from tiles to bools. Can store up to 256 tiles.
         #[must_use]
-        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
-        #[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+        #[cfg_attr(any(test, feature = "serde"), derive(Serialize))]
         pub struct $name<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>($inner);
 
         impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Default
@@ -38,8 +56,28 @@ macro_rules! tile_set {
 
             #[inline]
             const fn assert_legal() {
-                debug_assert!(SIZE == (WIDTH as usize * HEIGHT as usize));
-                debug_assert!(SIZE <= <$inner>::BITS as usize);
+                DimensionError::assert(
+                    WIDTH as usize * HEIGHT as usize,
+                    SIZE,
+                    <$inner>::BITS as usize,
+                );
+            }
+
+            /// Create a set from its inner value, checking that `SIZE` matches the dimensions
+            /// # Errors
+            /// If `SIZE` is not `WIDTH * HEIGHT` or is too large for this set
+            #[allow(clippy::cast_possible_truncation)]
+            pub const fn try_from_inner(inner: $inner) -> Result<Self, DimensionError> {
+                match DimensionError::check(
+                    WIDTH as usize * HEIGHT as usize,
+                    SIZE,
+                    <$inner>::BITS as usize,
+                ) {
+                    Ok(()) => Ok(Self(
+                        inner & (<$inner>::MAX >> (<$inner>::BITS - SIZE as u32)),
+                    )),
+                    Err(e) => Err(e),
+                }
             }
 
             #[inline]
@@ -56,6 +94,43 @@ macro_rules! tile_set {
                 result
             }
 
+            /// Parse a set from an ascii grid, where tiles are `*` and empty spaces are `_` as in `Display`.
+            /// Rows are separated by newlines and may be shorter than the grid. Other whitespace is ignored.
+            /// # Errors
+            /// If there are unexpected characters, or tiles or empty spaces outside the grid
+            pub const fn try_from_ascii(s: &str) -> Result<Self, ParseShapeError> {
+                Self::try_from_ascii_with(s, AsciiOptions::TILE_SET)
+            }
+
+            /// Parse a set from an ascii grid, using `options` to interpret the characters
+            /// # Errors
+            /// If there are unexpected characters, or tiles or empty spaces outside the grid
+            pub const fn try_from_ascii_with(
+                s: &str,
+                options: AsciiOptions,
+            ) -> Result<Self, ParseShapeError> {
+                let mut reader = AsciiGridReader::new(s, options);
+                let mut result = Self::EMPTY;
+                loop {
+                    match reader.next_tile() {
+                        Ok(Some(tile)) => result.set_bit(&tile, true),
+                        Ok(None) => return Ok(result),
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+
+            /// Parse a set from an ascii grid in the same format as `try_from_ascii`.
+            /// Used by the `tiles!` macro to check grids at compile time.
+            /// # Panics
+            /// If the grid cannot be parsed
+            pub const fn from_ascii(s: &str) -> Self {
+                match Self::try_from_ascii(s) {
+                    Ok(set) => set,
+                    Err(e) => const_panic::concat_panic!(e.message()),
+                }
+            }
+
             #[inline]
             pub const fn from_inner(inner: $inner) -> Self {
                 Self::assert_legal();
@@ -100,6 +175,23 @@ macro_rules! tile_set {
                 r
             }
 
+            /// Display this set with the given characters for present and absent tiles.
+            /// The returned adapter can be further customized with separators and headers.
+            pub fn display_with(
+                &self,
+                on: char,
+                off: char,
+            ) -> DisplayAdapter<
+                impl Fn(Tile<WIDTH, HEIGHT>, &mut fmt::Formatter<'_>) -> fmt::Result,
+                WIDTH,
+                HEIGHT,
+            > {
+                let set = *self;
+                DisplayAdapter::new(move |tile, f| {
+                    f.write_char(if set.get_bit(&tile) { on } else { off })
+                })
+            }
+
             /// Returns a copy of self with the bit at `tile` set to `bit`
             #[inline]
             pub const fn with_bit_set(&self, tile: &Tile<WIDTH, HEIGHT>, bit: bool) -> Self {
@@ -122,9 +214,9 @@ macro_rules! tile_set {
             #[inline]
             pub const fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
                 $iter_name::<1> {
-                    bottom_index: 0,
-                    top_index: SIZE,
                     inner: self.0,
+                    index: 0,
+                    remaining: SIZE,
                 }
             }
 
@@ -135,9 +227,9 @@ macro_rules! tile_set {
                 y: u8,
             ) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
                 $iter_name::<1> {
-                    bottom_index: (y * WIDTH) as usize,
-                    top_index: ((y + 1) * WIDTH) as usize,
                     inner: self.0,
+                    index: (y * WIDTH) as usize,
+                    remaining: WIDTH as usize,
                 }
             }
 
@@ -147,10 +239,10 @@ macro_rules! tile_set {
                 &self,
                 x: u8,
             ) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
-                $iter_name::<HEIGHT> {
-                    bottom_index: x as usize,
-                    top_index: ((WIDTH * (HEIGHT - 1)) + x + 1) as usize,
+                $iter_name::<WIDTH> {
                     inner: self.0,
+                    index: x as usize,
+                    remaining: HEIGHT as usize,
                 }
             }
 
@@ -178,6 +270,34 @@ macro_rules! tile_set {
                 Self(a & Self::ALL.0)
             }
 
+            /// Translate every tile in the set by `vector`.
+            /// Tiles which would leave the grid are discarded.
+            pub const fn translate(&self, vector: Vector) -> Self {
+                let dx = vector.x.unsigned_abs();
+                let dy = vector.y.unsigned_abs();
+                if dx >= WIDTH || dy >= HEIGHT {
+                    return Self::EMPTY;
+                }
+
+                // Only keep the columns which will still be in the grid, so nothing wraps onto another row
+                let mut keep: $inner = 0;
+                let mut col = 0;
+                while col < WIDTH - dx {
+                    let source = if vector.x < 0 { col + dx } else { col };
+                    keep |= Self::COL_ZERO_MASK << source;
+                    col += 1;
+                }
+                let kept = self.0 & keep;
+                let shifted = if vector.x < 0 { kept >> dx } else { kept << dx };
+                let result = Self(shifted & Self::ALL.0);
+
+                if vector.y < 0 {
+                    result.shift_north(dy)
+                } else {
+                    result.shift_south(dy)
+                }
+            }
+
             const ROW_ZERO_MASK: $inner = {
                 let mut inner: $inner = 0;
                 let mut tile = Some(Tile::<WIDTH, HEIGHT>::NORTH_WEST);
@@ -218,6 +338,178 @@ macro_rules! tile_set {
                 Self(inner)
             }
 
+            /// The tiles in `neighbourhood` of `tile` which are in the grid
+            pub const fn neighbourhood_mask(
+                tile: &Tile<WIDTH, HEIGHT>,
+                neighbourhood: Neighbourhood<'_>,
+            ) -> Self {
+                let offsets = neighbourhood.offsets();
+                let mut result = Self::EMPTY;
+                let mut index = 0;
+                while index < offsets.len() {
+                    if let Some(neighbour) = tile.const_add(&offsets[index]) {
+                        result = result.with_bit_set(&neighbour, true);
+                    }
+                    index += 1;
+                }
+                result
+            }
+
+            /// The neighbourhood mask of every tile, indexed by the tile's inner value
+            const fn neighbourhood_masks(neighbourhood: Neighbourhood<'_>) -> [Self; SIZE] {
+                let mut result = [Self::EMPTY; SIZE];
+                let mut index = 0;
+                while index < SIZE {
+                    if let Some(tile) = Tile::try_from_usize(index) {
+                        result[index] = Self::neighbourhood_mask(&tile, neighbourhood);
+                    }
+                    index += 1;
+                }
+                result
+            }
+
+            /// The tiles adjacent to each tile, including diagonals, indexed by the tile's inner value.
+            /// Computed at compile time
+            pub const ADJACENT_MASKS: [Self; SIZE] = Self::neighbourhood_masks(Neighbourhood::MOORE);
+
+            /// The tiles orthogonally adjacent to each tile, indexed by the tile's inner value.
+            /// Computed at compile time
+            pub const CONTIGUOUS_MASKS: [Self; SIZE] =
+                Self::neighbourhood_masks(Neighbourhood::VON_NEUMANN);
+
+            /// The set of all tiles in the rows in `range`.
+            /// Rows outside the grid are ignored.
+            pub const fn rows_mask(range: core::ops::Range<u8>) -> Self {
+                let end = if range.end < HEIGHT { range.end } else { HEIGHT };
+                if range.start >= end || WIDTH == 0 {
+                    return Self::EMPTY;
+                }
+                let bits = (end - range.start) as u32 * WIDTH as u32;
+                let first = range.start as u32 * WIDTH as u32;
+                Self((<$inner>::MAX >> (<$inner>::BITS - bits)) << first)
+            }
+
+            /// The set of all tiles in the columns in `range`.
+            /// Columns outside the grid are ignored.
+            pub const fn cols_mask(range: core::ops::Range<u8>) -> Self {
+                let end = if range.end < WIDTH { range.end } else { WIDTH };
+                if range.start >= end {
+                    return Self::EMPTY;
+                }
+                let row = (<$inner>::MAX >> (<$inner>::BITS - (end - range.start) as u32))
+                    << range.start;
+                // This copies `row` into every row of the grid. The copies do not overlap, so nothing carries
+                Self(Self::COL_ZERO_MASK * row)
+            }
+
+            /// The set of tiles on the edge of the grid
+            pub const fn border_mask() -> Self {
+                Self::row_mask(0)
+                    .union(&Self::row_mask(HEIGHT - 1))
+                    .union(&Self::col_mask(0))
+                    .union(&Self::col_mask(WIDTH - 1))
+            }
+
+            /// The set of tiles which are not on the edge of the grid
+            pub const fn interior_mask() -> Self {
+                Self::rows_mask(1..HEIGHT - 1).intersect(&Self::cols_mask(1..WIDTH - 1))
+            }
+
+            /// The set of all tiles where `x - y == k`, running from north west to south east
+            pub const fn diagonal_mask(k: i8) -> Self {
+                let mut result = Self::EMPTY;
+                let mut tile = Some(Tile::<WIDTH, HEIGHT>::NORTH_WEST);
+
+                while let Some(t) = tile {
+                    if t.x() as i16 - t.y() as i16 == k as i16 {
+                        result.set_bit(&t, true);
+                    }
+                    tile = t.try_next();
+                }
+                result
+            }
+
+            /// The set of all tiles where `x + y == k`, running from north east to south west
+            pub const fn anti_diagonal_mask(k: u8) -> Self {
+                let mut result = Self::EMPTY;
+                let mut tile = Some(Tile::<WIDTH, HEIGHT>::NORTH_WEST);
+
+                while let Some(t) = tile {
+                    if t.x() as u16 + t.y() as u16 == k as u16 {
+                        result.set_bit(&t, true);
+                    }
+                    tile = t.try_next();
+                }
+                result
+            }
+
+            /// The set of all tiles to the south east of `tile` (including `tile`).
+            /// See [`Self::corner_quadrant_mask`] for the quadrants about the center of the grid.
+            pub const fn quadrant_mask(tile: Tile<WIDTH, HEIGHT>) -> Self {
+                let mut result = Self::EMPTY;
+                let mut t = Some(tile);
+
+                while let Some(current) = t {
+                    if current.x() >= tile.x() {
+                        result.set_bit(&current, true);
+                    }
+                    t = current.try_next();
+                }
+                result
+            }
+
+            /// The set of tiles in the northern half of the grid.
+            /// If the grid has an odd height the middle row is in neither half.
+            pub const fn north_half_mask() -> Self {
+                Self::rows_mask(0..HEIGHT / 2)
+            }
+
+            /// The set of tiles in the southern half of the grid.
+            /// If the grid has an odd height the middle row is in neither half.
+            pub const fn south_half_mask() -> Self {
+                Self::rows_mask(HEIGHT.div_ceil(2)..HEIGHT)
+            }
+
+            /// The set of tiles in the western half of the grid.
+            /// If the grid has an odd width the middle column is in neither half.
+            pub const fn west_half_mask() -> Self {
+                Self::cols_mask(0..WIDTH / 2)
+            }
+
+            /// The set of tiles in the eastern half of the grid.
+            /// If the grid has an odd width the middle column is in neither half.
+            pub const fn east_half_mask() -> Self {
+                Self::cols_mask(WIDTH.div_ceil(2)..WIDTH)
+            }
+
+            /// The set of tiles in the quadrant of the grid containing `corner`, as given by [`Tile::is_in_quadrant`].
+            /// Unlike [`Self::quadrant_mask`], which starts at a given tile, this splits the grid about its center.
+            pub const fn corner_quadrant_mask(corner: Corner) -> Self {
+                let (vertical, horizontal) = match corner {
+                    Corner::NorthWest => (Self::north_half_mask(), Self::west_half_mask()),
+                    Corner::NorthEast => (Self::north_half_mask(), Self::east_half_mask()),
+                    Corner::SouthWest => (Self::south_half_mask(), Self::west_half_mask()),
+                    Corner::SouthEast => (Self::south_half_mask(), Self::east_half_mask()),
+                };
+                vertical.intersect(&horizontal)
+            }
+
+            /// The set of tiles reached by repeatedly moving from `tile` by `vector` (not including `tile`).
+            /// Returns the empty set if `vector` is zero.
+            pub const fn ray_mask(tile: Tile<WIDTH, HEIGHT>, vector: Vector) -> Self {
+                let mut result = Self::EMPTY;
+                if vector.is_zero() {
+                    return result;
+                }
+                let mut t = tile.const_add(&vector);
+
+                while let Some(current) = t {
+                    result.set_bit(&current, true);
+                    t = current.const_add(&vector);
+                }
+                result
+            }
+
             #[must_use]
             #[inline]
             pub fn enumerate(
@@ -241,12 +533,175 @@ macro_rules! tile_set {
                 $true_iter_name::new(self)
             }
 
+            /// Iterate through the tiles which are present in the set, column by column
+            #[must_use]
+            pub fn iter_true_tiles_by_col(
+                &self,
+            ) -> impl core::iter::FusedIterator<Item = Tile<WIDTH, HEIGHT>>
+                   + Clone
+                   + DoubleEndedIterator {
+                let set = *self;
+                (0..WIDTH).flat_map(move |x| set.intersect(&Self::col_mask(x)).iter_true_tiles())
+            }
+
+            /// Iterate through the rows of the set from north to south, as bits.
+            /// Bit `x` of each row is set if the tile in column `x` is present.
+            /// # Panics
+            /// If `WIDTH` is more than 64
+            #[must_use]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+            pub fn rows_as_bits(
+                &self,
+            ) -> impl ExactSizeIterator<Item = u64>
+                   + core::iter::FusedIterator
+                   + DoubleEndedIterator
+                   + Clone {
+                assert!(WIDTH <= 64, "Rows must fit in a u64");
+                let set = *self;
+                (0..HEIGHT).map(move |y| {
+                    ((set.0 >> (u32::from(y) * u32::from(WIDTH))) & Self::ROW_ZERO_MASK) as u64
+                })
+            }
+
+            /// Create a set from the bits of each row, from north to south, in the format used by `rows_as_bits`.
+            /// Bits outside the grid and rows after the last one are ignored.
+            #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+            pub fn from_row_bits(rows: impl IntoIterator<Item = u64>) -> Self {
+                Self::assert_legal();
+                let mut inner: $inner = 0;
+                for (y, row) in (0..HEIGHT).zip(rows) {
+                    inner |= ((row as $inner) & Self::ROW_ZERO_MASK)
+                        << (u32::from(y) * u32::from(WIDTH));
+                }
+                Self(inner)
+            }
+
             #[must_use]
             #[inline]
             pub const fn count(&self) -> u32 {
                 self.0.count_ones()
             }
 
+            /// The set of all tiles inside a rectangle.
+            /// Parts of the rectangle outside the grid are ignored.
+            #[allow(clippy::cast_sign_loss)]
+            #[allow(clippy::cast_possible_truncation)]
+            pub const fn rectangle_mask(rectangle: &Rectangle) -> Self {
+                let mut result = Self::EMPTY;
+                let min_x = max_i16(rectangle.north_west.0.x as i16, 0);
+                let min_y = max_i16(rectangle.north_west.0.y as i16, 0);
+                let max_x = min_i16(
+                    rectangle.north_west.0.x as i16 + rectangle.width as i16,
+                    WIDTH as i16,
+                );
+                let max_y = min_i16(
+                    rectangle.north_west.0.y as i16 + rectangle.height as i16,
+                    HEIGHT as i16,
+                );
+
+                let mut y = min_y;
+                while y < max_y {
+                    let mut x = min_x;
+                    while x < max_x {
+                        result.set_bit(&Tile::new_unchecked(x as u8, y as u8), true);
+                        x += 1;
+                    }
+                    y += 1;
+                }
+                result
+            }
+
+            /// The number of tiles in this set which are inside a rectangle
+            #[must_use]
+            pub const fn count_in(&self, rectangle: &Rectangle) -> u32 {
+                self.count_intersect(&Self::rectangle_mask(rectangle))
+            }
+
+            /// The number of tiles in both this set and `mask`
+            #[must_use]
+            #[inline]
+            pub const fn count_intersect(&self, mask: &Self) -> u32 {
+                (self.0 & mask.0).count_ones()
+            }
+
+            /// The number of tiles in this set in a particular row
+            #[must_use]
+            #[inline]
+            pub const fn count_in_row(&self, y: u8) -> u32 {
+                self.count_intersect(&Self::row_mask(y))
+            }
+
+            /// The number of tiles in this set in a particular column
+            #[must_use]
+            #[inline]
+            pub const fn count_in_col(&self, x: u8) -> u32 {
+                self.count_intersect(&Self::col_mask(x))
+            }
+
+            /// The second moments of area of the tiles about their centroid, as `(Ixx, Iyy, Ixy)`.
+            /// `Ixx` is the integral of `(y - ȳ)²` over the tiles, `Iyy` of `(x - x̄)²` and `Ixy` of `(x - x̄)(y - ȳ)`.
+            #[must_use]
+            pub fn second_moments(&self) -> (f32, f32, f32) {
+                self.tile_sums().second_moments()
+            }
+
+            /// The angle in radians of the principal axis through the centroid, along which the tiles are most spread out.
+            /// Measured from east and, as y increases to the south, positive angles are clockwise. In the range (-π/2, π/2].
+            /// `None` if every axis has the same moment, such as for a square block of tiles.
+            /// Requires `std` or `libm`
+            #[cfg(any(test, feature = "std", feature = "libm"))]
+            #[must_use]
+            pub fn principal_axis_angle(&self) -> Option<f32> {
+                self.tile_sums().principal_axis_angle()
+            }
+
+            fn tile_sums(&self) -> TileSums {
+                TileSums::new(
+                    self.iter_true_tiles()
+                        .map(|tile| (i64::from(tile.x()), i64::from(tile.y()))),
+                )
+            }
+
+            /// Scale this set up to a larger grid, so that each tile becomes a square block of tiles.
+            /// # Panics
+            /// If `W2` and `H2` are not the same multiple of `WIDTH` and `HEIGHT`
+            pub fn upscale<const W2: u8, const H2: u8, S: FromIterator<Tile<W2, H2>>>(&self) -> S {
+                let scale = if WIDTH == 0 { 0 } else { W2 / WIDTH };
+                assert!(
+                    scale > 0 && W2 == WIDTH * scale && HEIGHT.checked_mul(scale) == Some(H2),
+                    "The new grid must be a multiple of the size of the old grid"
+                );
+                Tile::<W2, H2>::iter_by_row()
+                    .filter(|tile| {
+                        self.get_bit(&Tile::new_unchecked(tile.x() / scale, tile.y() / scale))
+                    })
+                    .collect()
+            }
+
+            /// Scale this set down to a smaller grid, so that each square block of tiles becomes a single tile.
+            /// A tile in the new set is present if at least `threshold` tiles in its block are present.
+            /// # Panics
+            /// If `WIDTH` and `HEIGHT` are not the same multiple of `W2` and `H2`
+            pub fn downscale<const W2: u8, const H2: u8, S: FromIterator<Tile<W2, H2>>>(
+                &self,
+                threshold: u32,
+            ) -> S {
+                let scale = if W2 == 0 { 0 } else { WIDTH / W2 };
+                assert!(
+                    scale > 0 && WIDTH == W2 * scale && H2.checked_mul(scale) == Some(HEIGHT),
+                    "The old grid must be a multiple of the size of the new grid"
+                );
+                Tile::<W2, H2>::iter_by_row()
+                    .filter(|tile| {
+                        #[allow(clippy::cast_possible_wrap)]
+                        let north_west =
+                            Vector::new((tile.x() * scale) as i8, (tile.y() * scale) as i8);
+                        let block = Rectangle::new(north_west.into(), scale, scale);
+                        self.count_in(&block) >= threshold
+                    })
+                    .collect()
+            }
+
             /// Get the scale to make the grid take up as much as possible of a given area
             #[must_use]
             #[inline]
@@ -300,6 +755,87 @@ macro_rules! tile_set {
                 Self(!self.0 & Self::ALL.0)
             }
 
+            /// Returns the tiles in this set whose orthogonal neighbours are all in the set.
+            /// Tiles on the edge of the grid are never in the interior.
+            #[inline]
+            pub const fn interior(&self) -> Self {
+                self.intersect(&self.shift_north(1))
+                    .intersect(&self.shift_south(1))
+                    .intersect(&self.shift_east())
+                    .intersect(&self.shift_west())
+            }
+
+            /// Returns the tiles in this set which are orthogonally adjacent to a tile not in the set or to the edge of the grid.
+            #[inline]
+            pub const fn boundary(&self) -> Self {
+                self.except(&self.interior())
+            }
+
+            /// Returns the number of tile edges which separate a tile in this set from a tile not in the set or from the edge of the grid.
+            #[must_use]
+            #[inline]
+            pub const fn perimeter_length(&self) -> u32 {
+                self.except(&self.shift_south(1)).count()
+                    + self.except(&self.shift_north(1)).count()
+                    + self.except(&self.shift_east()).count()
+                    + self.except(&self.shift_west()).count()
+            }
+
+            /// Returns the tiles in this set which are orthogonally connected to `seed`, using iterative bit-dilation
+            #[inline]
+            const fn dilate_within(&self, seed: Self) -> Self {
+                let mut current = seed.intersect(self);
+                loop {
+                    let next = current
+                        .union(&current.shift_north(1))
+                        .union(&current.shift_south(1))
+                        .union(&current.shift_east())
+                        .union(&current.shift_west())
+                        .intersect(self);
+                    if next.0 == current.0 {
+                        return current;
+                    }
+                    current = next;
+                }
+            }
+
+            /// Whether all tiles in this set are orthogonally connected.
+            /// The empty set is considered connected.
+            #[must_use]
+            #[inline]
+            pub const fn is_connected(&self) -> bool {
+                let Some(first) = self.first() else {
+                    return true;
+                };
+                let component = self.dilate_within(Self::EMPTY.with_bit_set(&first, true));
+                component.0 == self.0
+            }
+
+            /// Label each connected component of this set with a distinct number, starting from 1, in the order of their first tiles.
+            /// Tiles which are not in the set are labelled 0.
+            /// Returns the labels and the number of components.
+            /// If `include_diagonals` is true, diagonally adjacent tiles are connected.
+            pub fn label_components(
+                &self,
+                include_diagonals: bool,
+            ) -> (TileMap<u8, WIDTH, HEIGHT, SIZE>, u8) {
+                crate::tile_set_trait::label_components(self, include_diagonals)
+            }
+
+            /// The number of orthogonally connected components in this set
+            #[must_use]
+            #[inline]
+            pub const fn count_components(&self) -> u32 {
+                let mut remaining = *self;
+                let mut count = 0;
+                while let Some(first) = remaining.first() {
+                    let component = remaining.dilate_within(Self::EMPTY.with_bit_set(&first, true));
+                    remaining = remaining.except(&component);
+                    count += 1;
+                }
+                count
+            }
+
             /// The first tile in this set
             #[must_use]
             #[inline]
@@ -364,7 +900,8 @@ macro_rules! tile_set {
                 }
             }
 
-            /// Returns the nth tile in the set, if it is present
+            /// Returns the nth tile in the set, if it is present.
+            /// This is the inverse of `tiles_before` for tiles in the set.
             #[must_use]
             #[inline]
             #[allow(clippy::cast_possible_truncation)]
@@ -394,6 +931,243 @@ macro_rules! tile_set {
                     chunk_size /= 2;
                 }
             }
+
+            /// The set of all tiles within `radius` Manhattan distance of `center` (including `center`)
+            pub const fn manhattan_disc(center: Tile<WIDTH, HEIGHT>, radius: u8) -> Self {
+                let mut result = Self::EMPTY;
+                let mut tile = Some(Tile::<WIDTH, HEIGHT>::NORTH_WEST);
+
+                while let Some(t) = tile {
+                    if t.manhattan_distance(&center) <= radius {
+                        result.set_bit(&t, true);
+                    }
+                    tile = t.try_next();
+                }
+                result
+            }
+
+            /// Greedily deconstruct the set into rectangles which exactly cover the true tiles.
+            /// Each rectangle starts at the first remaining tile and is extended east as far as possible, then south as far as possible.
+            #[allow(clippy::cast_possible_wrap)]
+            pub fn deconstruct_into_rectangles(
+                &self,
+            ) -> impl core::iter::FusedIterator<Item = Rectangle> + Clone {
+                let mut remaining = *self;
+                core::iter::from_fn(move || {
+                    let first = remaining.first()?;
+
+                    let mut width = 1;
+                    while first.x() + width < WIDTH
+                        && remaining.get_bit(&Tile::new_unchecked(first.x() + width, first.y()))
+                    {
+                        width += 1;
+                    }
+
+                    let row_mask =
+                        (<$inner>::MAX >> (<$inner>::BITS - u32::from(width))) << first.inner();
+
+                    let mut height = 1;
+                    while first.y() + height < HEIGHT {
+                        let mask = row_mask << (height * WIDTH);
+                        if remaining.0 & mask != mask {
+                            break;
+                        }
+                        height += 1;
+                    }
+
+                    for y in 0..height {
+                        remaining.0 &= !(row_mask << (y * WIDTH));
+                    }
+
+                    Some(Rectangle::new(
+                        Vector::new(first.x() as i8, first.y() as i8).into(),
+                        width,
+                        height,
+                    ))
+                })
+                .fuse()
+            }
+        }
+
+        impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> $name<WIDTH, HEIGHT, SIZE> {
+            /// Write the set as run-length encoded string e.g. `3,2,4`.
+            /// The runs alternate between missing and present tiles, starting with missing.
+            /// Requires `std`
+            #[cfg(any(test, feature = "std"))]
+            #[must_use]
+            pub fn to_rle_string(&self) -> String {
+                use itertools::Itertools;
+                let mut result = String::new();
+                for (count, bit) in self.iter().dedup_with_count() {
+                    if result.is_empty() {
+                        if bit {
+                            result.push_str("0,");
+                        }
+                    } else {
+                        result.push(',');
+                    }
+                    result.push_str(&count.to_string());
+                }
+                result
+            }
+
+            /// Read a set from a run-length encoded string e.g. `3,2,4`.
+            /// The runs alternate between missing and present tiles, starting with missing.
+            ///
+            /// # Errors
+            /// If a run length cannot be parsed or the runs do not contain exactly `SIZE` tiles.
+            pub fn from_rle_str(s: &str) -> Result<Self, ParseRleError> {
+                let mut result = Self::EMPTY;
+                let mut index: usize = 0;
+                let mut bit = false;
+
+                for (run_index, run) in s.split_terminator(',').enumerate() {
+                    let count: usize = run
+                        .parse()
+                        .map_err(|_| ParseRleError::InvalidRunLength { run: run_index })?;
+                    let end = match index.checked_add(count) {
+                        Some(end) if end <= SIZE => end,
+                        _ => return Err(ParseRleError::TooManyTiles),
+                    };
+                    if bit {
+                        for i in index..end {
+                            if let Some(tile) = Tile::try_from_usize(i) {
+                                result.set_bit(&tile, true);
+                            }
+                        }
+                    }
+                    index = end;
+                    bit = !bit;
+                }
+
+                if index != SIZE {
+                    return Err(ParseRleError::NotEnoughTiles {
+                        expected: SIZE,
+                        found: index,
+                    });
+                }
+                Ok(result)
+            }
+        }
+
+        impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Flip for $name<WIDTH, HEIGHT, SIZE> {
+            fn flip(&self, axes: FlipAxes) -> Self {
+                let mut result = Self::EMPTY;
+                let mut remaining = *self;
+
+                while let Some(tile) = remaining.pop() {
+                    result.set_bit(&tile.flip(axes), true);
+                }
+                result
+            }
+        }
+
+        impl<const L: u8, const SIZE: usize> Rotate for $name<L, L, SIZE> {
+            fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+                self.with_transform(&quarter_turns.into())
+            }
+        }
+
+        impl<const L: u8, const SIZE: usize> $name<L, L, SIZE> {
+            /// Apply a combined flip and rotation to every tile in the set
+            pub const fn with_transform(&self, transform: &Dihedral) -> Self {
+                let mut result = Self::EMPTY;
+                let mut remaining = *self;
+
+                while let Some(tile) = remaining.pop() {
+                    result.set_bit(&transform.apply_to_tile(&tile), true);
+                }
+                result
+            }
+
+            /// Whether some combination of flips and rotations transforms this set into `other`
+            #[must_use]
+            pub const fn is_congruent_to(&self, other: &Self) -> bool {
+                let mut index = 0;
+                while index < Dihedral::ALL.len() {
+                    if self.with_transform(&Dihedral::ALL[index]).0 == other.0 {
+                        return true;
+                    }
+                    index += 1;
+                }
+                false
+            }
+        }
+
+        impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSetTrait<WIDTH, HEIGHT>
+            for $name<WIDTH, HEIGHT, SIZE>
+        {
+            type TrueTilesIter = $true_iter_name<WIDTH, HEIGHT, SIZE>;
+
+            const EMPTY: Self = Self::EMPTY;
+
+            fn all() -> Self {
+                Self::ALL
+            }
+
+            fn get_bit(&self, tile: &Tile<WIDTH, HEIGHT>) -> bool {
+                $name::get_bit(self, tile)
+            }
+
+            fn set_bit(&mut self, tile: &Tile<WIDTH, HEIGHT>, bit: bool) {
+                $name::set_bit(self, tile, bit);
+            }
+
+            fn is_empty(&self) -> bool {
+                $name::is_empty(*self)
+            }
+
+            fn count(&self) -> u32 {
+                $name::count(self)
+            }
+
+            fn iter_true_tiles(&self) -> Self::TrueTilesIter {
+                $true_iter_name::new(self)
+            }
+
+            fn first(&self) -> Option<Tile<WIDTH, HEIGHT>> {
+                $name::first(self)
+            }
+
+            fn tiles_before(&self, tile: Tile<WIDTH, HEIGHT>) -> u32 {
+                $name::tiles_before(self, tile)
+            }
+
+            fn union(&self, rhs: &Self) -> Self {
+                $name::union(self, rhs)
+            }
+
+            fn intersect(&self, rhs: &Self) -> Self {
+                $name::intersect(self, rhs)
+            }
+
+            fn negate(&self) -> Self {
+                $name::negate(self)
+            }
+
+            fn except(&self, rhs: &Self) -> Self {
+                $name::except(self, rhs)
+            }
+
+            fn shift_north(&self, rows: u8) -> Self {
+                $name::shift_north(self, rows)
+            }
+
+            fn shift_south(&self, rows: u8) -> Self {
+                $name::shift_south(self, rows)
+            }
+
+            fn shift_east(&self) -> Self {
+                $name::shift_east(self)
+            }
+
+            fn shift_west(&self) -> Self {
+                $name::shift_west(self)
+            }
+
+            fn translate(&self, vector: Vector) -> Self {
+                $name::translate(self, vector)
+            }
         }
 
         impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> FromIterator<Tile<WIDTH, HEIGHT>>
@@ -413,8 +1187,35 @@ macro_rules! tile_set {
             }
         }
 
+        impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> IntoIterator
+            for $name<WIDTH, HEIGHT, SIZE>
+        {
+            type Item = Tile<WIDTH, HEIGHT>;
+            type IntoIter = $true_iter_name<WIDTH, HEIGHT, SIZE>;
+
+            /// Iterate through the tiles which are present in the set
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                $true_iter_name::new(&self)
+            }
+        }
+
+        impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> IntoIterator
+            for &$name<WIDTH, HEIGHT, SIZE>
+        {
+            type Item = Tile<WIDTH, HEIGHT>;
+            type IntoIter = $true_iter_name<WIDTH, HEIGHT, SIZE>;
+
+            /// Iterate through the tiles which are present in the set
+            #[inline]
+            fn into_iter(self) -> Self::IntoIter {
+                $true_iter_name::new(self)
+            }
+        }
+
+        /// An iterator through the tiles which are present in a set
         #[derive(Clone, Debug)]
-        struct $true_iter_name<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> {
+        pub struct $true_iter_name<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> {
             inner: $name<WIDTH, HEIGHT, SIZE>,
         }
 
@@ -575,17 +1376,25 @@ macro_rules! tile_set {
             }
         }
 
+        /// Iterates through `remaining` bits, starting at `index` and moving `STEP` bits at a time
         #[derive(Clone, Debug)]
         pub struct $iter_name<const STEP: u8> {
             inner: $inner,
-            bottom_index: usize,
-            top_index: usize,
+            index: usize,
+            remaining: usize,
+        }
+
+        impl<const STEP: u8> $iter_name<STEP> {
+            #[inline]
+            const fn bit(&self, index: usize) -> bool {
+                (self.inner >> index) & 1 == 1
+            }
         }
 
         impl<const STEP: u8> ExactSizeIterator for $iter_name<STEP> {
             #[inline]
             fn len(&self) -> usize {
-                self.clone().count()
+                self.remaining
             }
         }
 
@@ -594,19 +1403,18 @@ macro_rules! tile_set {
 
             #[inline]
             fn next(&mut self) -> Option<Self::Item> {
-                if self.bottom_index >= self.top_index {
-                    None
-                } else {
-                    let r = (self.inner >> self.bottom_index) & 1 == 1;
-                    self.bottom_index += (STEP as usize);
-                    Some(r)
+                if self.remaining == 0 {
+                    return None;
                 }
+                let r = self.bit(self.index);
+                self.index += STEP as usize;
+                self.remaining -= 1;
+                Some(r)
             }
 
             #[inline]
             fn size_hint(&self) -> (usize, Option<usize>) {
-                let count = self.clone().count();
-                (count, Some(count))
+                (self.remaining, Some(self.remaining))
             }
 
             #[inline]
@@ -614,27 +1422,58 @@ macro_rules! tile_set {
             where
                 Self: Sized,
             {
-                let distance = (self.top_index.saturating_sub(self.bottom_index)) as usize;
-                let count = (distance / STEP as usize);
-                count
+                self.remaining
+            }
+
+            #[inline]
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                if n >= self.remaining {
+                    self.remaining = 0;
+                    return None;
+                }
+                self.index += n * STEP as usize;
+                self.remaining -= n;
+                self.next()
             }
         }
 
         impl<const STEP: u8> DoubleEndedIterator for $iter_name<STEP> {
             #[inline]
-            fn next_back(&mut self) -> Option<Self::Item> {
-                if self.top_index == 0 {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.remaining == 0 {
+                    return None;
+                }
+                self.remaining -= 1;
+                Some(self.bit(self.index + self.remaining * STEP as usize))
+            }
+
+            #[inline]
+            fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+                if n >= self.remaining {
+                    self.remaining = 0;
                     return None;
                 }
-                let next_index = self.top_index.saturating_sub(STEP as usize);
-                if self.bottom_index > next_index {
-                    None
-                } else {
-                    self.top_index = next_index;
-                    let r = (self.inner >> self.top_index) & 1 == 1;
+                self.remaining -= n;
+                self.next_back()
+            }
+        }
+
+        impl<const STEP: u8> core::iter::FusedIterator for $iter_name<STEP> {}
 
-                    Some(r)
+        #[cfg(any(test, feature = "serde"))]
+        impl<'de, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Deserialize<'de>
+            for $name<WIDTH, HEIGHT, SIZE>
+        {
+            /// Deserializes the inner value in the same format as it is serialized, failing if any bits beyond `SIZE` are set
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner: $inner =
+                    crate::serde_newtype::deserialize_newtype(deserializer, stringify!($name))?;
+                if inner & !Self::ALL.0 != 0 {
+                    return Err(serde::de::Error::custom(format_args!(
+                        "tile set value {inner} has tiles outside a {WIDTH}x{HEIGHT} grid"
+                    )));
                 }
+                Ok(Self(inner))
             }
         }
 
@@ -659,6 +1498,37 @@ macro_rules! tile_set {
             }
         }
 
+        impl<const W: u8, const H: u8, const SIZE: usize> core::str::FromStr for $name<W, H, SIZE> {
+            type Err = ParseShapeError;
+
+            /// Parses ascii in the same format as `try_from_ascii`
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Self::try_from_ascii(s)
+            }
+        }
+
+        impl<const W: u8, const H: u8, const SIZE: usize> fmt::Debug for $name<W, H, SIZE> {
+            /// Writes the dimensions and the grid, with rows separated by `/`.
+            /// The alternate form puts each row on its own line.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}<{W}, {H}>(", stringify!($name))?;
+                let separator = if f.alternate() { '\n' } else { '/' };
+                if f.alternate() {
+                    f.write_char(separator)?;
+                }
+                for (i, e) in self.iter().enumerate() {
+                    if i > 0 && i % (W as usize) == 0 {
+                        f.write_char(separator)?;
+                    }
+                    f.write_char(if e { '*' } else { '_' })?;
+                }
+                if f.alternate() {
+                    f.write_char(separator)?;
+                }
+                f.write_char(')')
+            }
+        }
+
         impl<const W: u8, const H: u8, const SIZE: usize> fmt::Binary for $name<W, H, SIZE> {
             fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 fmt::Binary::fmt(&self.0, f)
@@ -678,6 +1548,43 @@ mod tests {
     use super::*;
     use itertools::Itertools;
 
+    #[test]
+    fn test_debug() {
+        let grid: TileSet16<3, 2, 6> = TileSet16::from_fn(|x| x.inner() % 2 == 0);
+
+        assert_eq!(format!("{grid:?}"), "TileSet16<3, 2>(*_*/_*_)");
+        assert_eq!(format!("{grid:#?}"), "TileSet16<3, 2>(\n*_*\n_*_\n)");
+    }
+
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        let set: TileSet16<3, 3, 9> = TileSet16::from_inner(0b1_0000_0101);
+        assert_tokens(
+            &set,
+            &[Token::NewtypeStruct { name: "TileSet16" }, Token::U16(261)],
+        );
+        assert_de_tokens_error::<TileSet16<3, 3, 9>>(
+            &[Token::NewtypeStruct { name: "TileSet16" }, Token::U16(512)],
+            "tile set value 512 has tiles outside a 3x3 grid",
+        );
+    }
+
+    #[test]
+    fn test_display_with() {
+        let grid: TileSet16<3, 3, 9> = TileSet16::from_fn(|x| x.inner() % 2 == 0);
+
+        assert_eq!(grid.display_with('#', '.').to_string(), "#.#\n.#.\n#.#");
+        assert_eq!(
+            grid.display_with('#', '.')
+                .with_separator('|')
+                .with_headers(true)
+                .to_string(),
+            " |0|1|2\n0|#|.|#\n1|.|#|.\n2|#|.|#"
+        );
+    }
+
     #[test]
     fn basic_tests() {
         let mut grid: TileSet16<3, 3, 9> = TileSet16::from_fn(|x| x.inner() % 2 == 0);
@@ -688,7 +1595,7 @@ mod tests {
         assert_eq!(grid.count(), 5);
 
         for tile in Tile::<3, 3>::iter_by_row() {
-            assert_eq!(grid.get_bit(&tile), tile.inner() % 2 == 0)
+            assert_eq!(grid.get_bit(&tile), tile.inner() % 2 == 0);
         }
 
         grid.set_bit(&Tile::CENTER, false);
@@ -744,7 +1651,7 @@ mod tests {
             "___\n\
              _*_\n\
              ___"
-        )
+        );
     }
 
     #[test]
@@ -755,7 +1662,7 @@ mod tests {
 
         fn test_all_tiles(set: TileSet16<4, 4, 16>) {
             for tile in Tile::<4, 4>::iter_by_row() {
-                let expected = tiles_before_slow(tile, set) as u32;
+                let expected = u32::try_from(tiles_before_slow(tile, set)).unwrap();
                 let actual = set.tiles_before(tile);
 
                 assert_eq!(
@@ -785,7 +1692,7 @@ mod tests {
             "***\n\
          *__\n\
          *__"
-        )
+        );
     }
 
     #[test]
@@ -798,7 +1705,7 @@ mod tests {
             "_**\n\
          *__\n\
          *__"
-        )
+        );
     }
 
     #[test]
@@ -826,19 +1733,46 @@ mod tests {
         assert_eq!(
             TileSet16::<3, 3, 9>::from_inner(3).to_string(),
             "**_\n___\n___"
-        )
+        );
     }
 
     #[test]
-    fn test_from_iter() {
-        let grid = TileSet16::<3, 3, 9>::from_iter(
-            [
-                Tile::try_from_inner(0).unwrap(),
-                Tile::try_from_inner(1).unwrap(),
-            ]
-            .into_iter(),
+    fn test_from_ascii() {
+        const SET: TileSet16<3, 3, 9> = TileSet16::from_ascii("**_\n_ _ *\n");
+        assert_eq!(SET.to_string(), "**_\n__*\n___");
+        assert_eq!(SET.to_string().parse(), Ok(SET));
+
+        assert_eq!(
+            TileSet16::<3, 3, 9>::try_from_ascii("*__*"),
+            Err(ParseShapeError::OutOfBounds { x: 3, y: 0 })
+        );
+        assert_eq!(
+            TileSet16::<3, 3, 9>::try_from_ascii("\n\n\n_"),
+            Err(ParseShapeError::OutOfBounds { x: 0, y: 3 })
         );
-        assert_eq!(grid.to_string(), "**_\n___\n___")
+        assert_eq!(
+            "*#".parse::<TileSet16<3, 3, 9>>(),
+            Err(ParseShapeError::UnexpectedCharacter {
+                byte: b'#',
+                index: 1
+            })
+        );
+        assert_eq!(
+            TileSet16::<3, 3, 9>::try_from_ascii_with(
+                "#..",
+                AsciiOptions::DEFAULT.with_flip(FlipAxes::Horizontal)
+            ),
+            Ok(TileSet16::from_inner(0b100))
+        );
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let grid = TileSet16::<3, 3, 9>::from_iter([
+            Tile::try_from_inner(0).unwrap(),
+            Tile::try_from_inner(1).unwrap(),
+        ]);
+        assert_eq!(grid.to_string(), "**_\n___\n___");
     }
 
     #[test]
@@ -848,7 +1782,7 @@ mod tests {
         assert_eq!(
             grid.iter()
                 .rev()
-                .map(|x| x.then(|| "*").unwrap_or("_"))
+                .map(|x| if x { "*" } else { "_" })
                 .join(""),
             "******______"
         );
@@ -859,39 +1793,46 @@ mod tests {
         let grid = TileSet16::<4, 3, 12>::from_fn(|x| x.inner() % 3 == 1);
 
         assert_eq!(
-            grid.row(0).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.row(0).map(|x| if x { "*" } else { "_" }).join(""),
             "_*__"
         );
         assert_eq!(
-            grid.row(1).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.row(1).map(|x| if x { "*" } else { "_" }).join(""),
             "*__*"
         );
         assert_eq!(
-            grid.row(2).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.row(2).map(|x| if x { "*" } else { "_" }).join(""),
             "__*_"
         );
     }
 
     #[test]
     fn test_col() {
-        let grid = TileSet16::<4, 3, 12>::from_fn(|x| x.inner() % 2 == 1);
+        let grid = TileSet16::<4, 3, 12>::from_fn(|x| x.inner() % 3 == 1);
 
         assert_eq!(
-            grid.col(0).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.col(0).map(|x| if x { "*" } else { "_" }).join(""),
             "_*_"
         );
         assert_eq!(
-            grid.col(1).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
-            "*_*"
+            grid.col(1).map(|x| if x { "*" } else { "_" }).join(""),
+            "*__"
         );
         assert_eq!(
-            grid.col(2).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
-            "_*_"
+            grid.col(2).map(|x| if x { "*" } else { "_" }).join(""),
+            "__*"
         );
 
         assert_eq!(
-            grid.col(3).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
-            "*_*"
+            grid.col(3).map(|x| if x { "*" } else { "_" }).join(""),
+            "_*_"
+        );
+        assert_eq!(
+            grid.col(2)
+                .rev()
+                .map(|x| if x { "*" } else { "_" })
+                .join(""),
+            "*__"
         );
     }
 
@@ -901,7 +1842,7 @@ mod tests {
 
         assert_eq!(
             grid.enumerate()
-                .map(|(t, x)| t.inner().to_string() + x.then(|| "*").unwrap_or("_"))
+                .map(|(t, x)| t.inner().to_string() + if x { "*" } else { "_" })
                 .join(""),
             "0_1_2_3_4_5*6_7_8_"
         );
@@ -926,6 +1867,117 @@ mod tests {
 
     }
 
+    #[test]
+    fn test_rows_as_bits() {
+        let set: TileSet32<4, 3, 12> = TileSet32::from_fn(|tile| tile.x() <= tile.y());
+
+        assert_eq!(set.rows_as_bits().collect_vec(), [0b0001, 0b0011, 0b0111]);
+        assert_eq!(TileSet32::from_row_bits(set.rows_as_bits()), set);
+        assert_eq!(
+            TileSet32::<4, 3, 12>::from_row_bits([0b1_1000, 0b0110]).to_string(),
+            "___*\n_**_\n____"
+        );
+
+        let wide: TileSet128<64, 2, 128> = TileSet128::from_row_bits([u64::MAX, 1]);
+        assert_eq!(wide.rows_as_bits().collect_vec(), [u64::MAX, 1]);
+        assert_eq!(wide.count(), 65);
+    }
+
+    #[test]
+    fn test_try_from_inner() {
+        assert_eq!(
+            TileSet16::<4, 3, 12>::try_from_inner(0b101),
+            Ok(TileSet16::from_inner(0b101))
+        );
+        assert_eq!(
+            TileSet16::<4, 3, 9>::try_from_inner(0),
+            Err(DimensionError::SizeMismatch {
+                expected: 12,
+                found: 9
+            })
+        );
+        assert_eq!(
+            TileSet8::<3, 3, 9>::try_from_inner(0),
+            Err(DimensionError::TooLarge { size: 9, max: 8 })
+        );
+        assert_eq!(
+            TileSet8::<2, 2, 4>::try_from_inner(u8::MAX).map(|s| s.count()),
+            Ok(4)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "SIZE does not match the grid dimensions")]
+    fn test_from_inner_wrong_size() {
+        let _ = TileSet16::<4, 3, 9>::from_inner(0);
+    }
+
+    #[test]
+    fn test_iter_true_tiles_by_col() {
+        let grid: TileSet16<3, 3, 9> = TileSet16::from_fn(|x| x.inner() % 2 == 0);
+
+        assert_eq!(
+            grid.iter_true_tiles_by_col().join("|"),
+            "(0,0)|(0,2)|(1,1)|(2,0)|(2,2)"
+        );
+        assert_eq!(
+            grid.iter_true_tiles_by_col().rev().join("|"),
+            "(2,2)|(2,0)|(1,1)|(0,2)|(0,0)"
+        );
+        assert_eq!(
+            TileSet16::<3, 3, 9>::EMPTY.iter_true_tiles_by_col().count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_range_masks() {
+        type Grid = TileSet16<4, 3, 12>;
+        type Full = TileSet64<8, 8, 64>;
+        const CORE: TileSet16<4, 4, 16> = TileSet16::interior_mask();
+        assert_eq!(CORE.count(), 4);
+
+        assert_eq!(Grid::rows_mask(1..3).to_string(), "____\n****\n****");
+        assert_eq!(Grid::rows_mask(2..7).to_string(), "____\n____\n****");
+        assert_eq!(Grid::rows_mask(2..2), Grid::EMPTY);
+        assert_eq!(Grid::cols_mask(0..2).to_string(), "**__\n**__\n**__");
+        assert_eq!(Grid::border_mask().to_string(), "****\n*__*\n****");
+        assert_eq!(Grid::interior_mask().to_string(), "____\n_**_\n____");
+        assert_eq!(Grid::interior_mask().union(&Grid::border_mask()), Grid::ALL);
+
+        assert_eq!(Full::rows_mask(0..8), Full::ALL);
+        assert_eq!(Full::cols_mask(0..8), Full::ALL);
+        assert_eq!(Full::rows_mask(7..9), Full::row_mask(7));
+        assert_eq!(Full::cols_mask(7..9), Full::col_mask(7));
+    }
+
+    #[test]
+    fn test_translate() {
+        let grid: TileSet16<4, 3, 12> = [
+            Tile::new_const::<0, 0>(),
+            Tile::new_const::<3, 0>(),
+            Tile::new_const::<1, 2>(),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(grid.translate(Vector::ZERO), grid);
+        assert_eq!(
+            grid.translate(Vector::new(1, 1)).to_string(),
+            "____\n_*__\n____"
+        );
+        assert_eq!(
+            grid.translate(Vector::new(-1, -2)).to_string(),
+            "*___\n____\n____"
+        );
+        assert_eq!(
+            grid.translate(Vector::new(2, 0)).to_string(),
+            "__*_\n____\n___*"
+        );
+        assert_eq!(grid.translate(Vector::new(4, 0)), TileSet16::EMPTY);
+        assert_eq!(grid.translate(Vector::new(0, -3)), TileSet16::EMPTY);
+    }
+
     #[test]
     fn test_row_mask() {
         type Grid = TileSet16<4, 3, 12>;
@@ -944,6 +1996,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::float_cmp)]
     fn test_get_scale() {
         type Grid = TileSet16<4, 3, 12>;
 
@@ -951,7 +2004,7 @@ mod tests {
         let scale_rect = Grid::get_scale(100.0, 50.0);
 
         assert_eq!(scale_square, 25.0);
-        assert_eq!(scale_rect, 16.666_666)
+        assert_eq!(scale_rect, 16.666_666);
     }
 
     #[test]
@@ -959,14 +2012,14 @@ mod tests {
         type Grid = TileSet16<4, 3, 12>;
         let all = Grid::ALL;
 
-        assert_eq!("****\n****\n****", all.to_string().as_str())
+        assert_eq!("****\n****\n****", all.to_string().as_str());
     }
 
     #[test]
     fn test_is_empty() {
         type Grid = TileSet16<4, 3, 12>;
         assert!(Grid::EMPTY.is_empty());
-        assert!(!Grid::EMPTY.with_bit_set(&Tile::NORTH_EAST, true).is_empty())
+        assert!(!Grid::EMPTY.with_bit_set(&Tile::NORTH_EAST, true).is_empty());
     }
 
     #[test]
@@ -988,14 +2041,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_neighbour_masks() {
+        type Set = TileSet16<4, 3, 12>;
+        for tile in Tile::<4, 3>::iter_by_row() {
+            let index = usize::from(tile.inner());
+            assert_eq!(
+                Set::ADJACENT_MASKS[index],
+                tile.iter_adjacent().collect::<Set>()
+            );
+            assert_eq!(
+                Set::CONTIGUOUS_MASKS[index],
+                tile.iter_contiguous().collect::<Set>()
+            );
+        }
+        assert_eq!(
+            Set::neighbourhood_mask(&Tile::NORTH_WEST, Neighbourhood::KNIGHT).to_string(),
+            "____\n__*_\n_*__"
+        );
+    }
+
+    #[test]
+    #[allow(clippy::iter_skip_next)]
+    fn test_row_and_col_iterators() {
+        let grid = TileSet32::<5, 4, 20>::from_fn(|x| x.inner() % 3 == 0 || x.inner() == 7);
+
+        for x in 0..5 {
+            let expected = (0..4)
+                .map(|y| grid.get_bit(&Tile::try_new(x, y).unwrap()))
+                .collect_vec();
+            assert_eq!(grid.col(x).collect_vec(), expected);
+            assert!(grid.col(x).rev().eq(expected.iter().rev().copied()));
+            for n in 0..=4 {
+                let mut iter = grid.col(x);
+                assert_eq!(iter.nth(n), expected.get(n).copied());
+                assert_eq!(iter.len(), 4usize.saturating_sub(n + 1));
+                assert_eq!(grid.col(x).nth_back(n), grid.col(x).rev().skip(n).next());
+            }
+        }
+
+        for y in 0..4 {
+            let mut iter = grid.row(y);
+            assert_eq!(iter.len(), 5);
+            assert_eq!(
+                iter.next_back(),
+                Some(grid.get_bit(&Tile::try_new(4, y).unwrap()))
+            );
+            assert_eq!(
+                iter.nth(1),
+                Some(grid.get_bit(&Tile::try_new(1, y).unwrap()))
+            );
+            assert_eq!(iter.len(), 2);
+            assert_eq!(iter.nth_back(2), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        assert_eq!(grid.iter().len(), 20);
+        assert!(grid
+            .iter()
+            .rev()
+            .eq(grid.iter().collect_vec().into_iter().rev()));
+    }
+
     #[test]
     fn test_iter_length_and_count() {
         type Iter = TileSetIter16<2>;
 
         let iter = Iter {
             inner: 0,
-            bottom_index: 0,
-            top_index: 12,
+            index: 0,
+            remaining: 6,
         };
 
         let len = iter.len();
@@ -1107,7 +2222,7 @@ mod tests {
         for n in 0..=17usize {
             let actual = set.iter_true_tiles().nth(n);
 
-            assert_eq!(actual, Tile::try_from_usize(n))
+            assert_eq!(actual, Tile::try_from_usize(n));
         }
     }
 
@@ -1131,6 +2246,31 @@ mod tests {
         assert_eq!(set_iter.len(), vec_iter.len());
     }
 
+    #[test]
+    fn test_rank_select_all_widths() {
+        macro_rules! check {
+            ($set:ty, $inner: ty) => {
+                for seed in [1u64, 0x5555_5555_5555_5555, 0x0123_4567_89ab_cdef, u64::MAX] {
+                    #[allow(clippy::cast_possible_truncation)]
+                    let inner = (u128::from(seed) | (u128::from(seed) << 64)) as $inner;
+                    let set = <$set>::from_inner(inner & <$set>::ALL.into_inner());
+                    for (rank, tile) in set.iter_true_tiles().enumerate() {
+                        let rank = u32::try_from(rank).unwrap();
+                        assert_eq!(set.nth(rank), Some(tile));
+                        assert_eq!(set.tiles_before(tile), rank);
+                    }
+                    assert_eq!(set.nth(set.count()), None);
+                }
+            };
+        }
+
+        check!(TileSet8<4, 2, 8>, u8);
+        check!(TileSet16<4, 4, 16>, u16);
+        check!(TileSet32<5, 6, 30>, u32);
+        check!(TileSet64<8, 8, 64>, u64);
+        check!(TileSet128<11, 11, 121>, u128);
+    }
+
     #[test]
     fn test_nth_u64() {
         let set: TileSet64<9, 7, 63> = TileSet64::<9, 7, 63>::from_fn(|tile| tile.x() == 0);
@@ -1138,12 +2278,12 @@ mod tests {
         let nth_elements = (0..8u32).map(|n| set.nth(n)).collect_vec();
         let expected = set
             .iter_true_tiles()
-            .map(|x| Some(x))
+            .map(Some)
             .chain(std::iter::repeat(None))
             .take(8)
             .collect_vec();
 
-        assert_eq!(nth_elements, expected)
+        assert_eq!(nth_elements, expected);
     }
 
     #[test]
@@ -1153,11 +2293,377 @@ mod tests {
         let nth_elements = (0..8u32).map(|n| set.nth(n)).collect_vec();
         let expected = set
             .iter_true_tiles()
-            .map(|x| Some(x))
+            .map(Some)
             .chain(std::iter::repeat(None))
             .take(8)
             .collect_vec();
 
-        assert_eq!(nth_elements, expected)
+        assert_eq!(nth_elements, expected);
+    }
+
+    #[test]
+    fn test_deconstruct_into_rectangles() {
+        let set = TileSet16::<4, 3, 12>::from_fn(|tile| tile.x() < 2 || tile.y() == 2);
+
+        let rectangles = set.deconstruct_into_rectangles().collect_vec();
+
+        assert_eq!(
+            rectangles,
+            [
+                Rectangle::new(Vector::new(0, 0).into(), 2, 3),
+                Rectangle::new(Vector::new(2, 2).into(), 2, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deconstruct_into_rectangles_covers_set() {
+        let set = TileSet64::<8, 8, 64>::from_fn(|tile| (tile.x() * 3 + tile.y() * 5) % 7 < 4);
+
+        let mut covered = TileSet64::<8, 8, 64>::EMPTY;
+        for rectangle in set.deconstruct_into_rectangles() {
+            for dx in 0..i8::try_from(rectangle.width).unwrap() {
+                for dy in 0..i8::try_from(rectangle.height).unwrap() {
+                    let tile = Tile::try_from_dynamic(
+                        rectangle
+                            .north_west
+                            .get_tile(&Corner::SouthEast)
+                            .const_add(Vector::new(dx, dy)),
+                    )
+                    .unwrap();
+                    assert!(covered.insert(&tile), "Rectangles overlap at {tile}");
+                }
+            }
+        }
+
+        assert_eq!(covered, set);
+        assert_eq!(
+            TileSet64::<8, 8, 64>::ALL
+                .deconstruct_into_rectangles()
+                .count(),
+            1
+        );
+        assert_eq!(
+            TileSet64::<8, 8, 64>::EMPTY
+                .deconstruct_into_rectangles()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() == tile.y());
+        assert_eq!((&set).into_iter().join("|"), "(0,0)|(1,1)|(2,2)|(3,3)");
+        assert_eq!(set.into_iter().rev().join("|"), "(3,3)|(2,2)|(1,1)|(0,0)");
+        assert_eq!(set.into_iter().collect::<TileSet16<4, 4, 16>>(), set);
+    }
+
+    #[test]
+    fn test_rle() {
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.y() == 1 || tile == Tile::SOUTH_EAST);
+        let rle = set.to_rle_string();
+        assert_eq!(rle, "4,4,7,1");
+        assert_eq!(TileSet16::from_rle_str(&rle), Ok(set));
+
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() == 0);
+        let rle = set.to_rle_string();
+        assert_eq!(rle, "0,1,3,1,3,1,3,1,3");
+        assert_eq!(TileSet16::from_rle_str(&rle), Ok(set));
+
+        assert_eq!(TileSet16::<4, 4, 16>::EMPTY.to_rle_string(), "16");
+        assert_eq!(TileSet16::<4, 4, 16>::ALL.to_rle_string(), "0,16");
+        assert_eq!(
+            TileSet16::<4, 4, 16>::from_rle_str("0,16"),
+            Ok(TileSet16::ALL)
+        );
+
+        assert_eq!(
+            TileSet16::<4, 4, 16>::from_rle_str("4,4"),
+            Err(ParseRleError::NotEnoughTiles {
+                expected: 16,
+                found: 8
+            })
+        );
+        assert_eq!(
+            TileSet16::<4, 4, 16>::from_rle_str("4,13"),
+            Err(ParseRleError::TooManyTiles)
+        );
+        assert_eq!(
+            TileSet16::<4, 4, 16>::from_rle_str("1,18446744073709551615,4"),
+            Err(ParseRleError::TooManyTiles)
+        );
+        assert_eq!(
+            TileSet16::<4, 4, 16>::from_rle_str("4,x"),
+            Err(ParseRleError::InvalidRunLength { run: 1 })
+        );
+    }
+
+    #[test]
+    fn test_with_transform() {
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.y() == 0 || tile == Tile::CENTER);
+        assert_eq!(set.to_string(), "****\n____\n__*_\n____");
+
+        for transform in Dihedral::ALL {
+            let transformed = set.with_transform(&transform);
+            assert_eq!(transformed.count(), set.count());
+            for tile in set.iter_true_tiles() {
+                assert!(transformed.get_bit(&transform.apply_to_tile(&tile)));
+            }
+            assert!(transformed.is_congruent_to(&set));
+        }
+
+        assert_eq!(
+            set.with_transform(&QuarterTurns::One.into()).to_string(),
+            "___*\n___*\n_*_*\n___*"
+        );
+        assert!(!set.is_congruent_to(&TileSet16::from_fn(|tile| tile.y() == 0)));
+    }
+
+    #[test]
+    fn test_manhattan_disc() {
+        let disc = TileSet32::<5, 5, 25>::manhattan_disc(Tile::CENTER, 2);
+        assert_eq!(disc.to_string(), "__*__\n_***_\n*****\n_***_\n__*__");
+
+        let disc = TileSet32::<5, 5, 25>::manhattan_disc(Tile::NORTH_EAST, 1);
+        assert_eq!(disc.to_string(), "___**\n____*\n_____\n_____\n_____");
+
+        for tile in Tile::<5, 5>::iter_by_row() {
+            let set = TileSet32::<5, 5, 25>::manhattan_disc(tile, 3);
+            assert!(set.iter_true_tiles().eq(tile.iter_within_manhattan(3)));
+        }
+    }
+
+    #[test]
+    fn test_boundary_and_interior() {
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() < 3);
+
+        assert_eq!(set.interior().to_string(), "____\n_*__\n_*__\n____");
+        assert_eq!(set.boundary().to_string(), "***_\n*_*_\n*_*_\n***_");
+
+        assert_eq!(
+            TileSet16::<4, 4, 16>::ALL.interior().to_string(),
+            "____\n_**_\n_**_\n____"
+        );
+        assert!(TileSet16::<4, 4, 16>::EMPTY.boundary().is_empty());
+    }
+
+    #[test]
+    fn test_perimeter_length() {
+        assert_eq!(TileSet16::<4, 4, 16>::EMPTY.perimeter_length(), 0);
+        assert_eq!(TileSet16::<4, 4, 16>::ALL.perimeter_length(), 16);
+        assert_eq!(
+            TileSet16::<4, 4, 16>::from_fn(|tile| tile == Tile::CENTER).perimeter_length(),
+            4
+        );
+        assert_eq!(
+            TileSet16::<4, 4, 16>::from_fn(|tile| (tile.x() + tile.y()) % 2 == 0)
+                .perimeter_length(),
+            32
+        );
+    }
+
+    #[test]
+    fn test_connected_components() {
+        assert!(TileSet16::<4, 4, 16>::EMPTY.is_connected());
+        assert_eq!(TileSet16::<4, 4, 16>::EMPTY.count_components(), 0);
+        assert!(TileSet16::<4, 4, 16>::ALL.is_connected());
+        assert_eq!(TileSet16::<4, 4, 16>::ALL.count_components(), 1);
+
+        let checkerboard = TileSet16::<4, 4, 16>::from_fn(|tile| (tile.x() + tile.y()) % 2 == 0);
+        assert!(!checkerboard.is_connected());
+        assert_eq!(checkerboard.count_components(), 8);
+
+        // Tiles at the end of one row and the start of the next are not adjacent
+        let wrapping = TileSet64::<5, 5, 25>::from_fn(|tile| {
+            tile == Tile::new_const::<4, 0>() || tile == Tile::new_const::<0, 1>()
+        });
+        assert_eq!(wrapping.count_components(), 2);
+
+        let spiral = TileSet128::<5, 5, 25>::from_fn(|tile| {
+            tile.y() == 0
+                || tile.x() == 4
+                || tile.y() == 4
+                || (tile.x() == 0 && tile.y() >= 2)
+                || (tile.y() == 2 && tile.x() == 2)
+        });
+        assert!(!spiral.is_connected());
+        assert_eq!(spiral.count_components(), 2);
+    }
+
+    #[test]
+    fn test_label_components() {
+        let checkerboard = TileSet16::<4, 4, 16>::from_fn(|tile| (tile.x() + tile.y()) % 2 == 0);
+        let (labels, count) = checkerboard.label_components(false);
+        assert_eq!(count, 8);
+        assert_eq!(labels.to_string(), "1|0|2|0\n0|3|0|4\n5|0|6|0\n0|7|0|8");
+
+        let (labels, count) = checkerboard.label_components(true);
+        assert_eq!(count, 1);
+        assert_eq!(labels.to_string(), "1|0|1|0\n0|1|0|1\n1|0|1|0\n0|1|0|1");
+
+        let islands = TileSet64::<5, 3, 15>::from_fn(|tile| tile.x() != 2 && tile.y() != 1);
+        let (labels, count) = islands.label_components(true);
+        assert_eq!(count, 4);
+        assert_eq!(labels.to_string(), "1|1|0|2|2\n0|0|0|0|0\n3|3|0|4|4");
+
+        let (labels, count) = TileSet32::<3, 3, 9>::EMPTY.label_components(false);
+        assert_eq!(count, 0);
+        assert!(labels.iter().all(|x| *x == 0));
+    }
+
+    #[test]
+    fn test_line_masks() {
+        type Set = TileSet16<4, 3, 12>;
+        assert_eq!(Set::diagonal_mask(0).to_string(), "*___\n_*__\n__*_");
+        assert_eq!(Set::diagonal_mask(2).to_string(), "__*_\n___*\n____");
+        assert_eq!(Set::diagonal_mask(-2).to_string(), "____\n____\n*___");
+        assert!(Set::diagonal_mask(-3).is_empty());
+
+        assert_eq!(Set::anti_diagonal_mask(0).to_string(), "*___\n____\n____");
+        assert_eq!(Set::anti_diagonal_mask(3).to_string(), "___*\n__*_\n_*__");
+        assert!(Set::anti_diagonal_mask(6).is_empty());
+
+        assert_eq!(
+            Set::quadrant_mask(Tile::new_const::<1, 1>()).to_string(),
+            "____\n_***\n_***"
+        );
+        assert_eq!(Set::quadrant_mask(Tile::NORTH_WEST), Set::ALL);
+
+        assert_eq!(
+            Set::ray_mask(Tile::new_const::<0, 2>(), Vector::NORTH_EAST).to_string(),
+            "__*_\n_*__\n____"
+        );
+        assert_eq!(
+            Set::ray_mask(Tile::NORTH_WEST, Vector::new(2, 1)).to_string(),
+            "____\n__*_\n____"
+        );
+        assert!(Set::ray_mask(Tile::NORTH_WEST, Vector::ZERO).is_empty());
+    }
+
+    #[test]
+    fn test_second_moments() {
+        let set: TileSet16<4, 4, 16> = TileSet16::from_fn(|tile| tile.y() == 2 && tile.x() < 3);
+        let (ixx, iyy, ixy) = set.second_moments();
+        assert!((ixx - 0.25).abs() < 1e-6);
+        assert!((iyy - 2.25).abs() < 1e-6);
+        assert!(ixy.abs() < 1e-6);
+        assert_eq!(set.principal_axis_angle(), Some(0.0));
+
+        let s = set.translate(Vector::new(1, -2));
+        assert_eq!(s.second_moments(), set.second_moments());
+
+        let square =
+            TileSet16::<4, 4, 16>::rectangle_mask(&Rectangle::new(Vector::ZERO.into(), 2, 2));
+        assert_eq!(square.principal_axis_angle(), None);
+        assert_eq!(
+            TileSet16::<4, 4, 16>::EMPTY.second_moments(),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(TileSet16::<4, 4, 16>::EMPTY.principal_axis_angle(), None);
+
+        let shape = Polyomino::F_PENTOMINO;
+        let set = TileSet32::<5, 5, 25>::from_fn(|tile| shape.tiles().contains(&tile.into()));
+        assert_eq!(set.second_moments(), shape.second_moments());
+        assert_eq!(set.principal_axis_angle(), shape.principal_axis_angle());
+    }
+
+    #[test]
+    fn test_count_in() {
+        let set = TileSet32::<5, 4, 20>::from_fn(|tile| (tile.x() + tile.y()) % 2 == 0);
+
+        let rectangle = Rectangle::new(Vector::new(1, 1).into(), 3, 2);
+        assert_eq!(
+            TileSet32::<5, 4, 20>::rectangle_mask(&rectangle).to_string(),
+            "_____\n_***_\n_***_\n_____"
+        );
+        assert_eq!(set.count_in(&rectangle), 3);
+
+        let overhanging = Rectangle::new(Vector::new(-2, 2).into(), 4, 5);
+        assert_eq!(
+            TileSet32::<5, 4, 20>::rectangle_mask(&overhanging).to_string(),
+            "_____\n_____\n**___\n**___"
+        );
+        assert_eq!(set.count_in(&overhanging), 2);
+
+        let outside = Rectangle::new(Vector::new(5, 0).into(), 2, 2);
+        assert_eq!(set.count_in(&outside), 0);
+
+        assert_eq!(set.count_intersect(&set.negate()), 0);
+        assert_eq!(set.count_in_row(0), 3);
+        assert_eq!(set.count_in_row(1), 2);
+        assert_eq!(set.count_in_col(4), 2);
+        assert_eq!(
+            (0..4).map(|y| set.count_in_row(y)).sum::<u32>(),
+            set.count()
+        );
+    }
+
+    #[test]
+    fn test_upscale() {
+        let set = TileSet8::<2, 2, 4>::from_fn(|tile| tile.x() == tile.y());
+
+        let doubled: TileSet16<4, 4, 16> = set.upscale();
+        assert_eq!(doubled.to_string(), "**__\n**__\n__**\n__**");
+
+        let tripled: TileSet64<6, 6, 36> = set.upscale();
+        assert_eq!(tripled.count(), 18);
+        assert!(tripled.get_bit(&Tile::new_const::<2, 2>()));
+        assert!(!tripled.get_bit(&Tile::new_const::<3, 2>()));
+
+        let same: TileSet8<2, 2, 4> = set.upscale();
+        assert_eq!(same, set);
+    }
+
+    #[test]
+    #[should_panic(expected = "The new grid must be a multiple of the size of the old grid")]
+    fn test_upscale_height_overflow() {
+        // 2 * 128 wraps to 0 in a u8
+        let _: Vec<Tile<128, 0>> = TileSet8::<1, 2, 2>::ALL.upscale();
+    }
+
+    #[test]
+    fn test_downscale() {
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() <= tile.y());
+        assert_eq!(set.to_string(), "*___\n**__\n***_\n****");
+
+        let any: TileSet8<2, 2, 4> = set.downscale(1);
+        assert_eq!(any.to_string(), "*_\n**");
+        let most: TileSet8<2, 2, 4> = set.downscale(3);
+        assert_eq!(most.to_string(), "*_\n**");
+        let all: TileSet8<2, 2, 4> = set.downscale(4);
+        assert_eq!(all.to_string(), "__\n*_");
+
+        let round_trip: TileSet8<2, 2, 4> = any.upscale::<4, 4, TileSet16<4, 4, 16>>().downscale(4);
+        assert_eq!(round_trip, any);
+    }
+
+    #[test]
+    fn test_half_and_quadrant_masks() {
+        type Set = TileSet32<5, 3, 15>;
+        type Even = TileSet16<4, 4, 16>;
+        assert_eq!(Set::north_half_mask().to_string(), "*****\n_____\n_____");
+        assert_eq!(Set::south_half_mask().to_string(), "_____\n_____\n*****");
+        assert_eq!(Set::west_half_mask().to_string(), "**___\n**___\n**___");
+        assert_eq!(Set::east_half_mask().to_string(), "___**\n___**\n___**");
+        assert_eq!(
+            Set::corner_quadrant_mask(Corner::SouthEast).to_string(),
+            "_____\n_____\n___**"
+        );
+        assert_eq!(
+            Set::corner_quadrant_mask(Corner::NorthWest),
+            Set::north_half_mask().intersect(&Set::west_half_mask())
+        );
+
+        assert_eq!(Even::south_half_mask(), Even::rows_mask(2..4));
+        assert_eq!(Even::east_half_mask(), Even::cols_mask(2..4));
+        for corner in Corner::ALL {
+            for tile in Tile::<4, 4>::iter_by_row() {
+                assert_eq!(
+                    Even::corner_quadrant_mask(corner).get_bit(&tile),
+                    tile.is_in_quadrant(corner)
+                );
+            }
+        }
     }
 }
@@ -4,17 +4,19 @@ use core::{
     ops::{Shl, Shr},
 };
 
+use crate::ascii_options::AsciiGridReader;
+use crate::moments::TileSums;
 use crate::prelude::*;
+use crate::tile_set::{max_i16, min_i16};
 use ethnum::U256;
 #[cfg(any(test, feature = "serde"))]
 use serde::{Deserialize, Serialize};
 
-//todo  all new methods from tile_set
 /// A grid
 /// A map from tiles to bools. Can contain
 #[must_use]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize))]
 pub struct TileSet256<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>(U256);
 
 impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Default
@@ -33,14 +35,41 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
     };
 
     /// The set where all tiles are present
-    pub fn all() -> Self {
-        Self::EMPTY.negate()
+    #[allow(clippy::cast_possible_truncation)]
+    pub const ALL: Self = {
+        Self::assert_legal();
+        if SIZE == 0 {
+            Self::EMPTY
+        } else if SIZE > 128 {
+            Self(U256::from_words(
+                u128::MAX >> (256 - SIZE as u32),
+                u128::MAX,
+            ))
+        } else {
+            Self(U256::from_words(0, u128::MAX >> (128 - SIZE as u32)))
+        }
+    };
+
+    /// The set where all tiles are present
+    pub const fn all() -> Self {
+        Self::ALL
+    }
+
+    /// The set containing only `tile`
+    const fn single(tile: Tile<WIDTH, HEIGHT>) -> U256 {
+        match tile.inner().checked_sub(128) {
+            Some(i) => U256::from_words(1u128 << i, 0),
+            None => U256::from_words(0, 1u128 << tile.inner()),
+        }
     }
 
     #[inline]
     const fn assert_legal() {
-        debug_assert!(SIZE == (WIDTH as usize * HEIGHT as usize));
-        debug_assert!(SIZE <= <U256>::BITS as usize);
+        DimensionError::assert(
+            WIDTH as usize * HEIGHT as usize,
+            SIZE,
+            <U256>::BITS as usize,
+        );
     }
 
     pub fn from_fn<F: FnMut(Tile<WIDTH, HEIGHT>) -> bool>(mut cb: F) -> Self {
@@ -96,12 +125,101 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
         Self(a)
     }
 
+    /// The tiles in `neighbourhood` of `tile` which are in the grid
+    pub const fn neighbourhood_mask(
+        tile: &Tile<WIDTH, HEIGHT>,
+        neighbourhood: Neighbourhood<'_>,
+    ) -> Self {
+        let offsets = neighbourhood.offsets();
+        let mut result = Self::EMPTY;
+        let mut index = 0;
+        while index < offsets.len() {
+            if let Some(neighbour) = tile.const_add(&offsets[index]) {
+                result = result.with_bit_set(&neighbour, true);
+            }
+            index += 1;
+        }
+        result
+    }
+
+    /// The neighbourhood mask of every tile, indexed by the tile's inner value
+    const fn neighbourhood_masks(neighbourhood: Neighbourhood<'_>) -> [Self; SIZE] {
+        let mut result = [Self::EMPTY; SIZE];
+        let mut index = 0;
+        while index < SIZE {
+            if let Some(tile) = Tile::try_from_usize(index) {
+                result[index] = Self::neighbourhood_mask(&tile, neighbourhood);
+            }
+            index += 1;
+        }
+        result
+    }
+
+    /// The tiles adjacent to each tile, including diagonals, indexed by the tile's inner value.
+    /// Computed at compile time
+    pub const ADJACENT_MASKS: [Self; SIZE] = Self::neighbourhood_masks(Neighbourhood::MOORE);
+
+    /// The tiles orthogonally adjacent to each tile, indexed by the tile's inner value.
+    /// Computed at compile time
+    pub const CONTIGUOUS_MASKS: [Self; SIZE] =
+        Self::neighbourhood_masks(Neighbourhood::VON_NEUMANN);
+
+    /// Parse a set from an ascii grid, where tiles are `*` and empty spaces are `_` as in `Display`.
+    /// Rows are separated by newlines and may be shorter than the grid. Other whitespace is ignored.
+    /// # Errors
+    /// If there are unexpected characters, or tiles or empty spaces outside the grid
+    pub const fn try_from_ascii(s: &str) -> Result<Self, ParseShapeError> {
+        Self::try_from_ascii_with(s, AsciiOptions::TILE_SET)
+    }
+
+    /// Parse a set from an ascii grid, using `options` to interpret the characters
+    /// # Errors
+    /// If there are unexpected characters, or tiles or empty spaces outside the grid
+    pub const fn try_from_ascii_with(
+        s: &str,
+        options: AsciiOptions,
+    ) -> Result<Self, ParseShapeError> {
+        let mut reader = AsciiGridReader::new(s, options);
+        let mut result = Self::EMPTY;
+        loop {
+            match reader.next_tile() {
+                Ok(Some(tile)) => result.set_bit(&tile, true),
+                Ok(None) => return Ok(result),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Parse a set from an ascii grid in the same format as `try_from_ascii`.
+    /// Used by the `tiles!` macro to check grids at compile time.
+    /// # Panics
+    /// If the grid cannot be parsed
+    pub const fn from_ascii(s: &str) -> Self {
+        match Self::try_from_ascii(s) {
+            Ok(set) => set,
+            Err(e) => const_panic::concat_panic!(e.message()),
+        }
+    }
+
     #[inline]
     pub const fn from_inner(inner: U256) -> Self {
         Self::assert_legal();
         Self(inner)
     }
 
+    /// Create a set from its inner value, checking that `SIZE` matches the dimensions
+    /// # Errors
+    /// If `SIZE` is not `WIDTH * HEIGHT` or is more than 256
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn try_from_inner(inner: U256) -> Result<Self, DimensionError> {
+        DimensionError::check(
+            WIDTH as usize * HEIGHT as usize,
+            SIZE,
+            <U256>::BITS as usize,
+        )?;
+        Ok(Self(inner & (<U256>::MAX >> (<U256>::BITS - SIZE as u32))))
+    }
+
     #[must_use]
     #[inline]
     pub const fn into_inner(self) -> U256 {
@@ -116,56 +234,66 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
     }
 
     #[inline]
-    pub fn set_bit(&mut self, tile: &Tile<WIDTH, HEIGHT>, bit: bool) {
-        if bit {
-            self.0 |= U256::ONE.shl(tile.inner());
-        } else {
-            self.0 &= !(U256::ONE.shl(tile.inner()));
-        }
+    pub const fn set_bit(&mut self, tile: &Tile<WIDTH, HEIGHT>, bit: bool) {
+        *self = self.with_bit_set(tile, bit);
+    }
+
+    /// Display this set with the given characters for present and absent tiles.
+    /// The returned adapter can be further customized with separators and headers.
+    pub fn display_with(
+        &self,
+        on: char,
+        off: char,
+    ) -> DisplayAdapter<
+        impl Fn(Tile<WIDTH, HEIGHT>, &mut fmt::Formatter<'_>) -> fmt::Result,
+        WIDTH,
+        HEIGHT,
+    > {
+        let set = *self;
+        DisplayAdapter::new(move |tile, f| f.write_char(if set.get_bit(&tile) { on } else { off }))
     }
 
     #[must_use]
     #[inline]
-    pub fn get_bit(&self, tile: &Tile<WIDTH, HEIGHT>) -> bool {
-        (self.0.shr(tile.inner())) & U256::ONE == U256::ONE
+    pub const fn get_bit(&self, tile: &Tile<WIDTH, HEIGHT>) -> bool {
+        !self.intersect(&Self(Self::single(*tile))).is_empty()
     }
 
     /// Returns a copy of self with the bit at `tile` set to `bit`
     #[inline]
-    pub fn with_bit_set(&self, tile: &Tile<WIDTH, HEIGHT>, bit: bool) -> Self {
-        let inner = if bit {
-            self.0 | U256::ONE.shl(tile.inner())
+    pub const fn with_bit_set(&self, tile: &Tile<WIDTH, HEIGHT>, bit: bool) -> Self {
+        let single = Self(Self::single(*tile));
+        if bit {
+            self.union(&single)
         } else {
-            self.0 & !(U256::ONE.shl(tile.inner()))
-        };
-
-        Self(inner)
+            self.except(&single)
+        }
     }
 
     #[must_use]
     pub const fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
         TileSetIter256::<1> {
-            bottom_index: 0,
-            top_index: SIZE,
             inner: self.0,
+            index: 0,
+            remaining: SIZE,
         }
     }
 
     #[must_use]
     pub const fn row(&self, y: u8) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
         TileSetIter256::<1> {
-            bottom_index: (y * WIDTH) as usize,
-            top_index: ((y + 1) * WIDTH) as usize,
             inner: self.0,
+            index: (y * WIDTH) as usize,
+            remaining: WIDTH as usize,
         }
     }
 
     #[must_use]
     pub const fn col(&self, x: u8) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator {
-        TileSetIter256::<HEIGHT> {
-            bottom_index: x as usize,
-            top_index: ((WIDTH * (HEIGHT - 1)) + x + 1) as usize,
+        TileSetIter256::<WIDTH> {
             inner: self.0,
+            index: x as usize,
+            remaining: HEIGHT as usize,
         }
     }
 
@@ -187,18 +315,111 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
         TrueTilesIter256::new(self)
     }
 
+    /// Iterate through the tiles which are present in the set, column by column
+    #[must_use]
+    pub fn iter_true_tiles_by_col(
+        &self,
+    ) -> impl FusedIterator<Item = Tile<WIDTH, HEIGHT>> + Clone + DoubleEndedIterator {
+        let set = *self;
+        (0..WIDTH).flat_map(move |x| TrueTilesIter256::new(&set.intersect(&Self::col_mask(x))))
+    }
+
+    /// Iterate through the rows of the set from north to south, as bits.
+    /// Bit `x` of each row is set if the tile in column `x` is present.
+    /// # Panics
+    /// If `WIDTH` is more than 64
+    #[must_use]
+    pub fn rows_as_bits(
+        &self,
+    ) -> impl ExactSizeIterator<Item = u64> + FusedIterator + DoubleEndedIterator + Clone {
+        assert!(WIDTH <= 64, "Rows must fit in a u64");
+        let set = *self;
+        let row_zero = Self::row_mask(0).0;
+        (0..HEIGHT).map(move |y| ((set.0 >> (u32::from(y) * u32::from(WIDTH))) & row_zero).as_u64())
+    }
+
+    /// Create a set from the bits of each row, from north to south, in the format used by `rows_as_bits`.
+    /// Bits outside the grid and rows after the last one are ignored.
+    pub fn from_row_bits(rows: impl IntoIterator<Item = u64>) -> Self {
+        Self::assert_legal();
+        let row_zero = Self::row_mask(0).0;
+        let mut inner = U256::new(0);
+        for (y, row) in (0..HEIGHT).zip(rows) {
+            inner |= (U256::new(u128::from(row)) & row_zero) << (u32::from(y) * u32::from(WIDTH));
+        }
+        Self(inner)
+    }
+
     #[must_use]
     #[allow(clippy::missing_panics_doc)]
     pub const fn count(&self) -> usize {
         self.0.count_ones() as usize
     }
 
+    /// The set of all tiles inside a rectangle.
+    /// Parts of the rectangle outside the grid are ignored.
+    #[allow(clippy::cast_sign_loss)]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_lossless)]
+    pub const fn rectangle_mask(rectangle: &Rectangle) -> Self {
+        let mut result = Self::EMPTY;
+        let min_x = max_i16(rectangle.north_west.0.x as i16, 0);
+        let min_y = max_i16(rectangle.north_west.0.y as i16, 0);
+        let max_x = min_i16(
+            rectangle.north_west.0.x as i16 + rectangle.width as i16,
+            WIDTH as i16,
+        );
+        let max_y = min_i16(
+            rectangle.north_west.0.y as i16 + rectangle.height as i16,
+            HEIGHT as i16,
+        );
+
+        let mut y = min_y;
+        while y < max_y {
+            let mut x = min_x;
+            while x < max_x {
+                result.set_bit(&Tile::new_unchecked(x as u8, y as u8), true);
+                x += 1;
+            }
+            y += 1;
+        }
+        result
+    }
+
+    /// The number of tiles in this set which are inside a rectangle
+    #[must_use]
+    pub const fn count_in(&self, rectangle: &Rectangle) -> u32 {
+        self.count_intersect(&Self::rectangle_mask(rectangle))
+    }
+
+    /// The number of tiles in both this set and `mask`
+    #[must_use]
+    #[inline]
+    pub const fn count_intersect(&self, mask: &Self) -> u32 {
+        self.intersect(mask).0.count_ones()
+    }
+
+    /// The number of tiles in this set in a particular row
+    #[must_use]
+    #[inline]
+    pub const fn count_in_row(&self, y: u8) -> u32 {
+        self.count_intersect(&Self::row_mask(y))
+    }
+
+    /// The number of tiles in this set in a particular column
+    #[must_use]
+    #[inline]
+    pub const fn count_in_col(&self, x: u8) -> u32 {
+        self.count_intersect(&Self::col_mask(x))
+    }
+
     /// Returns the number of tiles in the set which are less than this tile.
     /// Note that it returns the same result whether or not the given tile is in the set
+    #[must_use]
     pub fn tiles_before(&self, tile: Tile<WIDTH, HEIGHT>) -> u32 {
         let s = self.0;
 
-        let shift = U256::BITS - tile.inner() as u32;
+        let shift = U256::BITS - u32::from(tile.inner());
 
         match s.checked_shl(shift) {
             Some(x) => x.count_ones(),
@@ -206,6 +427,30 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
         }
     }
 
+    /// The second moments of area of the tiles about their centroid, as `(Ixx, Iyy, Ixy)`.
+    /// `Ixx` is the integral of `(y - ȳ)²` over the tiles, `Iyy` of `(x - x̄)²` and `Ixy` of `(x - x̄)(y - ȳ)`.
+    #[must_use]
+    pub fn second_moments(&self) -> (f32, f32, f32) {
+        self.tile_sums().second_moments()
+    }
+
+    /// The angle in radians of the principal axis through the centroid, along which the tiles are most spread out.
+    /// Measured from east and, as y increases to the south, positive angles are clockwise. In the range (-π/2, π/2].
+    /// `None` if every axis has the same moment, such as for a square block of tiles.
+    /// Requires `std` or `libm`
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn principal_axis_angle(&self) -> Option<f32> {
+        self.tile_sums().principal_axis_angle()
+    }
+
+    fn tile_sums(&self) -> TileSums {
+        TileSums::new(
+            self.iter_true_tiles()
+                .map(|tile| (i64::from(tile.x()), i64::from(tile.y()))),
+        )
+    }
+
     /// Get the scale to make the grid take up as much as possible of a given area
     #[must_use]
     pub fn get_scale(total_width: f32, total_height: f32) -> f32 {
@@ -225,6 +470,78 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
         Self(U256::from_words(high, low))
     }
 
+    /// The set of all tiles in the rows in `range`.
+    /// Rows outside the grid are ignored.
+    #[allow(clippy::cast_lossless)]
+    pub const fn rows_mask(range: core::ops::Range<u8>) -> Self {
+        let end = if range.end < HEIGHT {
+            range.end
+        } else {
+            HEIGHT
+        };
+        if range.start >= end {
+            return Self::EMPTY;
+        }
+        Self::index_range_mask(range.start as u32 * WIDTH as u32, end as u32 * WIDTH as u32)
+    }
+
+    /// The set of all tiles in the columns in `range`.
+    /// Columns outside the grid are ignored.
+    #[allow(clippy::cast_lossless)]
+    pub const fn cols_mask(range: core::ops::Range<u8>) -> Self {
+        let end = if range.end < WIDTH { range.end } else { WIDTH };
+        let mut result = Self::EMPTY;
+        if range.start >= end {
+            return result;
+        }
+        let mut y = 0;
+        while y < HEIGHT {
+            let row_start = y as u32 * WIDTH as u32;
+            result = result.union(&Self::index_range_mask(
+                row_start + range.start as u32,
+                row_start + end as u32,
+            ));
+            y += 1;
+        }
+        result
+    }
+
+    /// The set of tiles whose indices are in `start..end`, where `start < end <= 256`
+    const fn index_range_mask(start: u32, end: u32) -> Self {
+        const fn word(start: u32, end: u32) -> u128 {
+            if start >= end {
+                0
+            } else {
+                (u128::MAX >> (128 - (end - start))) << start
+            }
+        }
+        const fn clamp(index: u32) -> u32 {
+            if index < 128 {
+                index
+            } else {
+                128
+            }
+        }
+
+        Self(U256::from_words(
+            word(start.saturating_sub(128), end.saturating_sub(128)),
+            word(clamp(start), clamp(end)),
+        ))
+    }
+
+    /// The set of tiles on the edge of the grid
+    pub const fn border_mask() -> Self {
+        Self::row_mask(0)
+            .union(&Self::row_mask(HEIGHT - 1))
+            .union(&Self::col_mask(0))
+            .union(&Self::col_mask(WIDTH - 1))
+    }
+
+    /// The set of tiles which are not on the edge of the grid
+    pub const fn interior_mask() -> Self {
+        Self::rows_mask(1..HEIGHT - 1).intersect(&Self::cols_mask(1..WIDTH - 1))
+    }
+
     /// Return the set of tiles in either self or `rhs` or both.
     pub const fn union(&self, rhs: &Self) -> Self {
         let (left_high, left_low) = self.0.into_words();
@@ -254,14 +571,23 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
     }
 
     /// Returns a new set containing all elements which belong to one set but not both
-    pub fn symmetric_difference(&self, rhs: &Self) -> Self {
-        Self(self.0 ^ rhs.0)
+    pub const fn symmetric_difference(&self, rhs: &Self) -> Self {
+        let (left_high, left_low) = self.0.into_words();
+        let (right_high, right_low) = rhs.0.into_words();
+
+        Self(U256::from_words(
+            left_high ^ right_high,
+            left_low ^ right_low,
+        ))
     }
 
-    #[allow(clippy::cast_possible_truncation)]
-    pub fn negate(&self) -> Self {
-        let mask: U256 = <U256>::MAX >> (<U256>::BITS - SIZE as u32);
-        Self(!self.0 & mask)
+    pub const fn negate(&self) -> Self {
+        let (high, low) = self.0.into_words();
+        Self(U256::from_words(!high, !low)).intersect(&Self::ALL)
+    }
+
+    pub const fn except(&self, rhs: &Self) -> Self {
+        self.intersect(&rhs.negate())
     }
 
     #[allow(clippy::cast_possible_truncation)]
@@ -278,19 +604,184 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSet256<WIDTH, HEI
         Self(a & mask)
     }
 
+    pub fn shift_east(&self) -> Self {
+        let a = self.0.shl(1u8) & !Self::col_mask(0).0;
+        Self(a).intersect(&Self::all())
+    }
+
+    pub fn shift_west(&self) -> Self {
+        let a = self.0.shr(1u8) & !Self::col_mask(WIDTH - 1).0;
+        Self(a)
+    }
+
+    /// Translate every tile in the set by `vector`.
+    /// Tiles which would leave the grid are discarded.
+    pub fn translate(&self, vector: Vector) -> Self {
+        let dx = vector.x.unsigned_abs();
+        let dy = vector.y.unsigned_abs();
+        if dx >= WIDTH || dy >= HEIGHT {
+            return Self::EMPTY;
+        }
+
+        let mut result = *self;
+        for _ in 0..dx {
+            result = if vector.x < 0 {
+                result.shift_west()
+            } else {
+                result.shift_east()
+            };
+        }
+
+        if vector.y < 0 {
+            result.shift_north(dy)
+        } else {
+            result.shift_south(dy)
+        }
+    }
+
+    /// Label each connected component of this set with a distinct number, starting from 1, in the order of their first tiles.
+    /// Tiles which are not in the set are labelled 0.
+    /// Returns the labels and the number of components.
+    /// If `include_diagonals` is true, diagonally adjacent tiles are connected.
+    pub fn label_components(
+        &self,
+        include_diagonals: bool,
+    ) -> (TileMap<u8, WIDTH, HEIGHT, SIZE>, u8) {
+        crate::tile_set_trait::label_components(self, include_diagonals)
+    }
+
     /// The first tile in this set
     #[must_use]
-    pub fn first(&self) -> Option<Tile<WIDTH, HEIGHT>> {
-        Tile::<WIDTH, HEIGHT>::try_from_usize(self.0.trailing_zeros() as usize)
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn first(&self) -> Option<Tile<WIDTH, HEIGHT>> {
+        let (high, low) = self.0.into_words();
+        if low != 0 {
+            Tile::<WIDTH, HEIGHT>::try_from_inner(low.trailing_zeros() as u8)
+        } else if high != 0 {
+            Tile::<WIDTH, HEIGHT>::try_from_inner(128 + high.trailing_zeros() as u8)
+        } else {
+            None
+        }
     }
 
     /// The last tile in this set
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
-    pub fn last(&self) -> Option<Tile<WIDTH, HEIGHT>> {
-        let index = (U256::BITS - 1).checked_sub(self.0.leading_zeros())?;
+    pub const fn last(&self) -> Option<Tile<WIDTH, HEIGHT>> {
+        let (high, low) = self.0.into_words();
+        if high != 0 {
+            Tile::<WIDTH, HEIGHT>::try_from_inner(255 - high.leading_zeros() as u8)
+        } else if low != 0 {
+            Tile::<WIDTH, HEIGHT>::try_from_inner(127 - low.leading_zeros() as u8)
+        } else {
+            None
+        }
+    }
 
-        Tile::<WIDTH, HEIGHT>::try_from_inner(index as u8)
+    /// Removes the first tile in this set and returns it
+    /// Returns `None` if the set is empty
+    #[must_use]
+    pub const fn pop(&mut self) -> Option<Tile<WIDTH, HEIGHT>> {
+        let Some(tile) = self.first() else {
+            return None;
+        };
+        self.set_bit(&tile, false);
+        Some(tile)
+    }
+
+    /// Removes the last tile in this set and returns it
+    /// Returns `None` if the set is empty
+    #[must_use]
+    pub const fn pop_last(&mut self) -> Option<Tile<WIDTH, HEIGHT>> {
+        let Some(tile) = self.last() else {
+            return None;
+        };
+        self.set_bit(&tile, false);
+        Some(tile)
+    }
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TileSetTrait<WIDTH, HEIGHT>
+    for TileSet256<WIDTH, HEIGHT, SIZE>
+{
+    type TrueTilesIter = TrueTilesIter256<WIDTH, HEIGHT, SIZE>;
+
+    const EMPTY: Self = Self::EMPTY;
+
+    fn all() -> Self {
+        TileSet256::all()
+    }
+
+    fn get_bit(&self, tile: &Tile<WIDTH, HEIGHT>) -> bool {
+        TileSet256::get_bit(self, tile)
+    }
+
+    fn set_bit(&mut self, tile: &Tile<WIDTH, HEIGHT>, bit: bool) {
+        TileSet256::set_bit(self, tile, bit);
+    }
+
+    fn is_empty(&self) -> bool {
+        TileSet256::is_empty(*self)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn count(&self) -> u32 {
+        TileSet256::count(self) as u32
+    }
+
+    fn iter_true_tiles(&self) -> Self::TrueTilesIter {
+        TrueTilesIter256::new(self)
+    }
+
+    fn first(&self) -> Option<Tile<WIDTH, HEIGHT>> {
+        TileSet256::first(self)
+    }
+
+    fn tiles_before(&self, tile: Tile<WIDTH, HEIGHT>) -> u32 {
+        TileSet256::tiles_before(self, tile)
+    }
+
+    fn union(&self, rhs: &Self) -> Self {
+        TileSet256::union(self, rhs)
+    }
+
+    fn intersect(&self, rhs: &Self) -> Self {
+        TileSet256::intersect(self, rhs)
+    }
+
+    fn negate(&self) -> Self {
+        TileSet256::negate(self)
+    }
+
+    fn shift_north(&self, rows: u8) -> Self {
+        TileSet256::shift_north(self, rows)
+    }
+
+    fn shift_south(&self, rows: u8) -> Self {
+        TileSet256::shift_south(self, rows)
+    }
+
+    fn shift_east(&self) -> Self {
+        TileSet256::shift_east(self)
+    }
+
+    fn shift_west(&self) -> Self {
+        TileSet256::shift_west(self)
+    }
+
+    fn translate(&self, vector: Vector) -> Self {
+        TileSet256::translate(self, vector)
+    }
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> core::str::FromStr
+    for TileSet256<WIDTH, HEIGHT, SIZE>
+{
+    type Err = ParseShapeError;
+
+    /// Parses ascii in the same format as `try_from_ascii`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_ascii(s)
     }
 }
 
@@ -307,11 +798,28 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> FromIterator<Tile<WID
     }
 }
 
+/// An iterator through the tiles which are present in a set
 #[derive(Clone, Debug)]
-struct TrueTilesIter256<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> {
+pub struct TrueTilesIter256<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> {
     inner: TileSet256<WIDTH, HEIGHT, SIZE>,
 }
 
+impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Flip
+    for TileSet256<WIDTH, HEIGHT, SIZE>
+{
+    fn flip(&self, axes: FlipAxes) -> Self {
+        self.iter_true_tiles().map(|tile| tile.flip(axes)).collect()
+    }
+}
+
+impl<const L: u8, const SIZE: usize> Rotate for TileSet256<L, L, SIZE> {
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        self.iter_true_tiles()
+            .map(|tile| tile.rotate(quarter_turns))
+            .collect()
+    }
+}
+
 impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> FusedIterator
     for TrueTilesIter256<WIDTH, HEIGHT, SIZE>
 {
@@ -352,65 +860,109 @@ impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> DoubleEndedIterator
 }
 
 impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> TrueTilesIter256<WIDTH, HEIGHT, SIZE> {
+    #[must_use]
     pub fn new(set: &TileSet256<WIDTH, HEIGHT, SIZE>) -> Self {
         Self { inner: *set }
     }
 }
 
+/// Iterates through `remaining` bits, starting at `index` and moving `STEP` bits at a time
 #[derive(Clone, Debug)]
 pub struct TileSetIter256<const STEP: u8> {
     inner: U256,
-    bottom_index: usize,
-    top_index: usize,
+    index: usize,
+    remaining: usize,
+}
+
+impl<const STEP: u8> TileSetIter256<STEP> {
+    #[inline]
+    fn bit(&self, index: usize) -> bool {
+        (self.inner >> index) & 1 == 1
+    }
 }
 
 impl<const STEP: u8> ExactSizeIterator for TileSetIter256<STEP> {
+    #[inline]
     fn len(&self) -> usize {
-        self.clone().count()
+        self.remaining
     }
 }
 
 impl<const STEP: u8> Iterator for TileSetIter256<STEP> {
     type Item = bool;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        if self.bottom_index >= self.top_index {
-            None
-        } else {
-            let r = (self.inner >> self.bottom_index) & 1 == 1;
-            self.bottom_index += STEP as usize;
-            Some(r)
+        if self.remaining == 0 {
+            return None;
         }
+        let r = self.bit(self.index);
+        self.index += STEP as usize;
+        self.remaining -= 1;
+        Some(r)
     }
 
+    #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let count = self.clone().count();
-        (count, Some(count))
+        (self.remaining, Some(self.remaining))
     }
 
+    #[inline]
     fn count(self) -> usize
     where
         Self: Sized,
     {
-        let distance = self.top_index.saturating_sub(self.bottom_index);
-        distance / STEP as usize
+        self.remaining
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
+        }
+        self.index += n * STEP as usize;
+        self.remaining -= n;
+        self.next()
     }
 }
 
 impl<const STEP: u8> DoubleEndedIterator for TileSetIter256<STEP> {
+    #[inline]
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.top_index == 0 {
+        if self.remaining == 0 {
             return None;
         }
-        let next_index = self.top_index.saturating_sub(STEP as usize);
-        if self.bottom_index > next_index {
-            None
-        } else {
-            self.top_index = next_index;
-            let r = (self.inner >> self.top_index) & 1 == 1;
+        self.remaining -= 1;
+        Some(self.bit(self.index + self.remaining * STEP as usize))
+    }
 
-            Some(r)
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if n >= self.remaining {
+            self.remaining = 0;
+            return None;
         }
+        self.remaining -= n;
+        self.next_back()
+    }
+}
+
+impl<const STEP: u8> core::iter::FusedIterator for TileSetIter256<STEP> {}
+
+#[cfg(any(test, feature = "serde"))]
+impl<'de, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Deserialize<'de>
+    for TileSet256<WIDTH, HEIGHT, SIZE>
+{
+    /// Deserializes the inner value in the same format as it is serialized, failing if any bits beyond `SIZE` are set
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner: U256 = crate::serde_newtype::deserialize_newtype(deserializer, "TileSet256")?;
+        if inner & !Self::ALL.0 != 0 {
+            return Err(serde::de::Error::custom(format_args!(
+                "tile set value {inner} has tiles outside a {WIDTH}x{HEIGHT} grid"
+            )));
+        }
+        Ok(Self(inner))
     }
 }
 
@@ -433,11 +985,96 @@ impl<const W: u8, const H: u8, const SIZE: usize> fmt::Display for TileSet256<W,
     }
 }
 
+impl<const W: u8, const H: u8, const SIZE: usize> fmt::Debug for TileSet256<W, H, SIZE> {
+    /// Writes the dimensions and the grid, with rows separated by `/`.
+    /// The alternate form puts each row on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TileSet256<{W}, {H}>(")?;
+        let separator = if f.alternate() { '\n' } else { '/' };
+        if f.alternate() {
+            f.write_char(separator)?;
+        }
+        for (i, e) in self.iter().enumerate() {
+            if i > 0 && i % (W as usize) == 0 {
+                f.write_char(separator)?;
+            }
+            f.write_char(if e { '*' } else { '_' })?;
+        }
+        if f.alternate() {
+            f.write_char(separator)?;
+        }
+        f.write_char(')')
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use itertools::Itertools;
 
+    #[test]
+    fn test_counts() {
+        type Set = TileSet256<16, 16, 256>;
+        let set = Set::from_fn(|tile| (tile.x() + tile.y()) % 2 == 0);
+
+        assert_eq!(set.count_in_row(0), 8);
+        assert_eq!(set.count_in_col(15), 8);
+        assert_eq!(set.count_intersect(&Set::ALL), 128);
+        assert_eq!(set.count_intersect(&Set::EMPTY), 0);
+
+        let rectangle = Rectangle::new(Vector::new(-2, 13).into(), 5, 10);
+        let mask = Set::rectangle_mask(&rectangle);
+        assert_eq!(mask.count(), 9);
+        assert!(mask.get_bit(&Tile::new_const::<2, 15>()));
+        assert!(!mask.get_bit(&Tile::new_const::<3, 15>()));
+        assert_eq!(set.count_in(&rectangle), 4);
+        assert_eq!(
+            Set::rectangle_mask(&Rectangle::new(Vector::ZERO.into(), 16, 16)),
+            Set::ALL
+        );
+    }
+
+    #[test]
+    fn test_range_masks() {
+        type Set = TileSet256<16, 16, 256>;
+        assert_eq!(Set::rows_mask(0..16), Set::ALL);
+        assert_eq!(Set::cols_mask(0..16), Set::ALL);
+
+        for start in 0..17 {
+            for end in start..18 {
+                let rows =
+                    (start..end.min(16)).fold(Set::EMPTY, |set, y| set.union(&Set::row_mask(y)));
+                let cols =
+                    (start..end.min(16)).fold(Set::EMPTY, |set, x| set.union(&Set::col_mask(x)));
+                assert_eq!(Set::rows_mask(start..end), rows);
+                assert_eq!(Set::cols_mask(start..end), cols);
+            }
+        }
+    }
+
+    #[test]
+    fn test_neighbour_masks() {
+        type Set = TileSet256<12, 12, 144>;
+        for tile in Tile::<12, 12>::iter_by_row() {
+            let index = usize::from(tile.inner());
+            assert_eq!(
+                Set::ADJACENT_MASKS[index],
+                tile.iter_adjacent().collect::<Set>()
+            );
+            assert_eq!(
+                Set::CONTIGUOUS_MASKS[index],
+                tile.iter_contiguous().collect::<Set>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_debug() {
+        let grid: TileSet256<3, 2, 6> = TileSet256::from_fn(|x| x.inner() % 2 == 0);
+
+        assert_eq!(format!("{grid:?}"), "TileSet256<3, 2>(*_*/_*_)");
+    }
+
     #[test]
     #[allow(unused_variables)]
     fn test_possible() {
@@ -449,13 +1086,42 @@ mod tests {
         let mut grid: TileSet256<3, 3, 9> = TileSet256::from_fn(|x| x.inner() % 2 == 0);
 
         assert_eq!(grid.to_string(), "*_*\n_*_\n*_*");
+        assert_eq!(grid.display_with('#', '.').to_string(), "#.#\n.#.\n#.#");
+        assert_eq!(TileSet256::try_from_inner(grid.into_inner()), Ok(grid));
+        assert_eq!(
+            TileSet256::<3, 3, 9>::border_mask().to_string(),
+            "***\n*_*\n***"
+        );
+        assert_eq!(
+            TileSet256::<3, 3, 9>::interior_mask().to_string(),
+            "___\n_*_\n___"
+        );
+        assert_eq!(
+            TileSet256::<3, 3, 9>::rows_mask(0..2).to_string(),
+            "***\n***\n___"
+        );
+        assert_eq!(
+            TileSet256::<3, 3, 9>::cols_mask(2..3).to_string(),
+            "__*\n__*\n__*"
+        );
+        assert_eq!(
+            grid.iter_true_tiles_by_col().join("|"),
+            "(0,0)|(0,2)|(1,1)|(2,0)|(2,2)"
+        );
+        assert!(TileSet256::<3, 3, 8>::try_from_inner(U256::ZERO).is_err());
+        assert_eq!(grid.rows_as_bits().collect_vec(), [0b101, 0b010, 0b101]);
+        assert_eq!(TileSet256::from_row_bits(grid.rows_as_bits()), grid);
+        assert_eq!(
+            TileSet256::<3, 3, 9>::from_row_bits([0b1111, 0b001]).to_string(),
+            "***\n*__\n___"
+        );
 
         assert_eq!(format!("{grid:#}"), "*_*_*_*_*");
 
         assert_eq!(grid.count(), 5);
 
         for tile in Tile::<3, 3>::iter_by_row() {
-            assert_eq!(grid.get_bit(&tile), tile.inner() % 2 == 0)
+            assert_eq!(grid.get_bit(&tile), tile.inner() % 2 == 0);
         }
 
         grid.set_bit(&Tile::CENTER, false);
@@ -485,7 +1151,7 @@ mod tests {
         assert_eq!(
             grid_left.intersect(&grid_right).to_string(),
             "___\n_*_\n___"
-        )
+        );
     }
 
     #[test]
@@ -493,7 +1159,7 @@ mod tests {
         let grid_left: TileSet256<3, 3, 9> = TileSet256::from_fn(|x| x.x() == 0);
         let grid_top: TileSet256<3, 3, 9> = TileSet256::from_fn(|x| x.y() == 0);
 
-        assert_eq!(grid_left.union(&grid_top).to_string(), "***\n*__\n*__")
+        assert_eq!(grid_left.union(&grid_top).to_string(), "***\n*__\n*__");
     }
 
     #[test]
@@ -506,7 +1172,7 @@ mod tests {
             "_**\n\
          *__\n\
          *__"
-        )
+        );
     }
 
     #[test]
@@ -534,19 +1200,16 @@ mod tests {
         assert_eq!(
             TileSet256::<3, 3, 9>::from_inner(U256::from(3u128)).to_string(),
             "**_\n___\n___"
-        )
+        );
     }
 
     #[test]
     fn test_from_iter() {
-        let grid = TileSet256::<4, 3, 12>::from_iter(
-            [
-                Tile::try_from_inner(0).unwrap(),
-                Tile::try_from_inner(1).unwrap(),
-            ]
-            .into_iter(),
-        );
-        assert_eq!(grid.to_string(), "**__\n____\n____")
+        let grid = TileSet256::<4, 3, 12>::from_iter([
+            Tile::try_from_inner(0).unwrap(),
+            Tile::try_from_inner(1).unwrap(),
+        ]);
+        assert_eq!(grid.to_string(), "**__\n____\n____");
     }
 
     #[test]
@@ -556,7 +1219,7 @@ mod tests {
         assert_eq!(
             grid.iter()
                 .rev()
-                .map(|x| x.then(|| "*").unwrap_or("_"))
+                .map(|x| if x { "*" } else { "_" })
                 .join(""),
             "******______"
         );
@@ -567,39 +1230,46 @@ mod tests {
         let grid = TileSet256::<4, 3, 12>::from_fn(|x| x.inner() % 3 == 1);
 
         assert_eq!(
-            grid.row(0).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.row(0).map(|x| if x { "*" } else { "_" }).join(""),
             "_*__"
         );
         assert_eq!(
-            grid.row(1).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.row(1).map(|x| if x { "*" } else { "_" }).join(""),
             "*__*"
         );
         assert_eq!(
-            grid.row(2).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.row(2).map(|x| if x { "*" } else { "_" }).join(""),
             "__*_"
         );
     }
 
     #[test]
     fn test_col() {
-        let grid = TileSet256::<4, 3, 12>::from_fn(|x| x.inner() % 2 == 1);
+        let grid = TileSet256::<4, 3, 12>::from_fn(|x| x.inner() % 3 == 1);
 
         assert_eq!(
-            grid.col(0).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
+            grid.col(0).map(|x| if x { "*" } else { "_" }).join(""),
             "_*_"
         );
         assert_eq!(
-            grid.col(1).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
-            "*_*"
+            grid.col(1).map(|x| if x { "*" } else { "_" }).join(""),
+            "*__"
         );
         assert_eq!(
-            grid.col(2).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
-            "_*_"
+            grid.col(2).map(|x| if x { "*" } else { "_" }).join(""),
+            "__*"
         );
 
         assert_eq!(
-            grid.col(3).map(|x| x.then(|| "*").unwrap_or("_")).join(""),
-            "*_*"
+            grid.col(3).map(|x| if x { "*" } else { "_" }).join(""),
+            "_*_"
+        );
+        assert_eq!(
+            grid.col(2)
+                .rev()
+                .map(|x| if x { "*" } else { "_" })
+                .join(""),
+            "*__"
         );
     }
 
@@ -609,7 +1279,7 @@ mod tests {
 
         assert_eq!(
             grid.enumerate()
-                .map(|(t, x)| t.inner().to_string() + x.then(|| "*").unwrap_or("_"))
+                .map(|(t, x)| t.inner().to_string() + if x { "*" } else { "_" })
                 .join(""),
             "0_1_2_3_4_5*6_7_8_"
         );
@@ -627,6 +1297,9 @@ mod tests {
 
         assert_eq!(full_grid.shift_north(2).to_string(), "**\n__\n__");
         assert_eq!(full_grid.shift_south(2).to_string(), "__\n__\n**");
+
+        assert_eq!(full_grid.shift_east().to_string(), "_*\n_*\n_*");
+        assert_eq!(full_grid.shift_west().to_string(), "*_\n*_\n*_");
     }
 
     #[test]
@@ -647,6 +1320,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::float_cmp)]
     fn test_get_scale() {
         type Grid = TileSet256<4, 3, 12>;
 
@@ -654,7 +1328,7 @@ mod tests {
         let scale_rect = Grid::get_scale(100.0, 50.0);
 
         assert_eq!(scale_square, 25.0);
-        assert_eq!(scale_rect, 16.666666)
+        assert_eq!(scale_rect, 16.666_666);
     }
 
     #[test]
@@ -662,14 +1336,47 @@ mod tests {
         type Grid = TileSet256<4, 3, 12>;
         let all = Grid::all();
 
-        assert_eq!("****\n****\n****", all.to_string().as_str())
+        assert_eq!("****\n****\n****", all.to_string().as_str());
+    }
+
+    #[test]
+    fn test_const_parity() {
+        type Grid = TileSet256<15, 16, 240>;
+        const SET: Grid = Grid::EMPTY
+            .with_bit_set(&Tile::new_const::<3, 0>(), true)
+            .with_bit_set(&Tile::new_const::<14, 15>(), true)
+            .with_bit_set(&Tile::new_const::<1, 9>(), true);
+        const HAS_BIT: bool = SET.get_bit(&Tile::new_const::<1, 9>());
+        const { assert!(HAS_BIT) };
+
+        assert_eq!(Grid::ALL, Grid::EMPTY.negate());
+        assert_eq!(Grid::ALL.count(), 240);
+        assert_eq!(TileSet256::<4, 3, 12>::ALL.count(), 12);
+        assert_eq!(SET.first(), Some(Tile::new_const::<3, 0>()));
+        assert_eq!(SET.last(), Some(Tile::new_const::<14, 15>()));
+        assert_eq!(Grid::EMPTY.first(), None);
+        assert_eq!(Grid::EMPTY.last(), None);
+
+        let mut forwards = SET;
+        let mut popped = vec![];
+        while let Some(tile) = forwards.pop() {
+            popped.push(tile);
+        }
+        assert!(forwards.is_empty());
+        assert_eq!(popped, SET.iter_true_tiles().collect::<Vec<_>>());
+
+        let mut backwards = SET;
+        assert_eq!(backwards.pop_last(), Some(Tile::new_const::<14, 15>()));
+        assert_eq!(backwards.pop_last(), Some(Tile::new_const::<1, 9>()));
+        assert_eq!(backwards.count(), 1);
+        assert_eq!(SET.except(&backwards).count(), 2);
     }
 
     #[test]
     fn test_is_empty() {
         type Grid = TileSet256<4, 3, 12>;
         assert!(Grid::EMPTY.is_empty());
-        assert!(!Grid::EMPTY.with_bit_set(&Tile::NORTH_EAST, true).is_empty())
+        assert!(!Grid::EMPTY.with_bit_set(&Tile::NORTH_EAST, true).is_empty());
     }
 
     #[test]
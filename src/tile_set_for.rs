@@ -0,0 +1,130 @@
+use crate::prelude::*;
+
+/// Selects a tile set type by the number of bits it stores.
+/// Used by the `tile_set_for!` macro.
+pub struct TileSetSelector<const BITS: u32>;
+
+/// Maps a `TileSetSelector` to the tile set type with that many bits
+pub trait SelectTileSet {
+    type Set<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>;
+}
+
+impl SelectTileSet for TileSetSelector<8> {
+    type Set<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> = TileSet8<WIDTH, HEIGHT, SIZE>;
+}
+
+impl SelectTileSet for TileSetSelector<16> {
+    type Set<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> = TileSet16<WIDTH, HEIGHT, SIZE>;
+}
+
+impl SelectTileSet for TileSetSelector<32> {
+    type Set<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> = TileSet32<WIDTH, HEIGHT, SIZE>;
+}
+
+impl SelectTileSet for TileSetSelector<64> {
+    type Set<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> = TileSet64<WIDTH, HEIGHT, SIZE>;
+}
+
+impl SelectTileSet for TileSetSelector<128> {
+    type Set<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> =
+        TileSet128<WIDTH, HEIGHT, SIZE>;
+}
+
+/// Requires `u256`
+#[cfg(any(test, feature = "u256"))]
+impl SelectTileSet for TileSetSelector<256> {
+    type Set<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> =
+        TileSet256<WIDTH, HEIGHT, SIZE>;
+}
+
+/// The number of bits in the smallest tile set which can store `size` tiles
+/// # Panics
+/// Panics if `size` is greater than 256
+#[must_use]
+pub const fn tile_set_bits(size: usize) -> u32 {
+    match size {
+        0..=8 => 8,
+        9..=16 => 16,
+        17..=32 => 32,
+        33..=64 => 64,
+        65..=128 => 128,
+        129..=256 => 256,
+        _ => panic!("No tile set can store more than 256 tiles"),
+    }
+}
+
+/// The smallest tile set type for a grid of the given width and height.
+/// `tile_set_for!(9, 7)` is `TileSet64<9, 7, 63>`
+#[macro_export]
+macro_rules! tile_set_for {
+    ($width:expr, $height:expr) => {
+        <$crate::tile_set_for::TileSetSelector<
+            { $crate::tile_set_for::tile_set_bits(($width as usize) * ($height as usize)) },
+        > as $crate::tile_set_for::SelectTileSet>::Set<
+            { $width },
+            { $height },
+            { ($width as usize) * ($height as usize) },
+        >
+    };
+}
+
+/// A tile set literal, checked at compile time.
+/// Takes the width and height, then the rows from north to south, where tiles are `*` and empty spaces are `_`.
+/// The rows may also be a single string, starting on its first line and separated by newlines.
+/// The set is the smallest type for the grid, as given by `tile_set_for!`.
+/// `tiles!(3, 2; "*_*", "_*_")` contains the tiles `(0,0)`, `(2,0)` and `(1,1)`
+#[macro_export]
+macro_rules! tiles {
+    ($width:expr, $height:expr; $($row:literal),+ $(,)?) => {{
+        const SET: $crate::tile_set_for!($width, $height) =
+            <$crate::tile_set_for!($width, $height)>::from_ascii(concat!($($row, "\n"),+));
+        SET
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_tile_set_for() {
+        let set: tile_set_for!(9, 7) = TileSet64::<9, 7, 63>::ALL;
+        assert_eq!(set.count(), 63);
+
+        let _: TileSet8<2, 4, 8> = <tile_set_for!(2, 4)>::EMPTY;
+        let _: TileSet16<3, 3, 9> = <tile_set_for!(3, 3)>::EMPTY;
+        let _: TileSet32<4, 8, 32> = <tile_set_for!(4, 8)>::EMPTY;
+        let _: TileSet128<11, 11, 121> = <tile_set_for!(11, 11)>::EMPTY;
+        let _: TileSet256<16, 16, 256> = <tile_set_for!(16, 16)>::EMPTY;
+    }
+
+    #[test]
+    fn test_tiles_macro() {
+        let set = tiles!(3, 2; "*_*", "_*_");
+        let _: TileSet8<3, 2, 6> = set;
+        assert_eq!(set.iter_true_tiles().join("|"), "(0,0)|(2,0)|(1,1)");
+        assert_eq!(set.to_string(), "*_*\n_*_");
+
+        let multiline = tiles!(3, 2; "*_*
+_*_");
+        assert_eq!(multiline, set);
+
+        let short_rows = tiles!(9, 7; "", "__*", "*");
+        let _: TileSet64<9, 7, 63> = short_rows;
+        assert_eq!(short_rows.iter_true_tiles().join("|"), "(2,1)|(0,2)");
+
+        let big = tiles!(15, 15; "_*");
+        let _: TileSet256<15, 15, 225> = big;
+        assert_eq!(big.iter_true_tiles().join("|"), "(1,0)");
+    }
+
+    #[test]
+    fn test_tile_set_bits() {
+        assert_eq!(tile_set_bits(1), 8);
+        assert_eq!(tile_set_bits(8), 8);
+        assert_eq!(tile_set_bits(9), 16);
+        assert_eq!(tile_set_bits(100), 128);
+        assert_eq!(tile_set_bits(129), 256);
+    }
+}
@@ -0,0 +1,235 @@
+use core::iter::FusedIterator;
+
+use crate::prelude::*;
+
+/// Functionality shared by tile sets of every width.
+/// Allows algorithms to be written once for any tile set.
+pub trait TileSetTrait<const WIDTH: u8, const HEIGHT: u8>:
+    Copy + Default + Eq + FromIterator<Tile<WIDTH, HEIGHT>>
+{
+    /// An iterator through the tiles which are present in the set
+    type TrueTilesIter: ExactSizeIterator<Item = Tile<WIDTH, HEIGHT>>
+        + DoubleEndedIterator
+        + FusedIterator
+        + Clone;
+
+    /// The set where all tiles are missing
+    const EMPTY: Self;
+
+    /// The set where all tiles are present
+    #[must_use]
+    fn all() -> Self;
+
+    #[must_use]
+    fn get_bit(&self, tile: &Tile<WIDTH, HEIGHT>) -> bool;
+
+    fn set_bit(&mut self, tile: &Tile<WIDTH, HEIGHT>, bit: bool);
+
+    #[must_use]
+    fn is_empty(&self) -> bool;
+
+    /// The number of tiles in the set
+    #[must_use]
+    fn count(&self) -> u32;
+
+    /// Iterate through the tiles which are present in the set
+    #[must_use]
+    fn iter_true_tiles(&self) -> Self::TrueTilesIter;
+
+    /// The first tile in the set
+    #[must_use]
+    fn first(&self) -> Option<Tile<WIDTH, HEIGHT>>;
+
+    /// The number of tiles in the set which come before `tile`
+    #[must_use]
+    fn tiles_before(&self, tile: Tile<WIDTH, HEIGHT>) -> u32;
+
+    #[must_use]
+    fn union(&self, rhs: &Self) -> Self;
+
+    #[must_use]
+    fn intersect(&self, rhs: &Self) -> Self;
+
+    #[must_use]
+    fn negate(&self) -> Self;
+
+    #[must_use]
+    fn except(&self, rhs: &Self) -> Self {
+        self.intersect(&rhs.negate())
+    }
+
+    #[must_use]
+    fn shift_north(&self, rows: u8) -> Self;
+
+    #[must_use]
+    fn shift_south(&self, rows: u8) -> Self;
+
+    #[must_use]
+    fn shift_east(&self) -> Self;
+
+    #[must_use]
+    fn shift_west(&self) -> Self;
+
+    /// Translate every tile in the set by `vector`, discarding tiles which leave the grid
+    #[must_use]
+    fn translate(&self, vector: Vector) -> Self;
+
+    /// The first tile in this set reached by casting a ray from `origin` in `direction`.
+    /// The origin itself is not checked. See `Tile::iter_ray`.
+    #[must_use]
+    fn ray_hits(
+        &self,
+        origin: Tile<WIDTH, HEIGHT>,
+        direction: Vector,
+    ) -> Option<Tile<WIDTH, HEIGHT>> {
+        origin.iter_ray(direction).find(|tile| self.get_bit(tile))
+    }
+
+    /// Copy this set into a set with different dimensions, which may have a different backing width.
+    /// Tiles keep their coordinates, so the set is anchored at the north west corner.
+    /// Tiles outside the target grid are discarded and new tiles are missing.
+    #[must_use]
+    fn resize_into<const W2: u8, const H2: u8, Target: TileSetTrait<W2, H2>>(&self) -> Target {
+        self.iter_true_tiles()
+            .filter_map(|tile| Tile::try_new(tile.x(), tile.y()))
+            .collect()
+    }
+}
+
+/// Label each connected component of `set`, in the order of their first tiles.
+/// Used to implement `label_components` for every tile set width.
+pub(crate) fn label_components<
+    const WIDTH: u8,
+    const HEIGHT: u8,
+    const SIZE: usize,
+    S: TileSetTrait<WIDTH, HEIGHT>,
+>(
+    set: &S,
+    include_diagonals: bool,
+) -> (TileMap<u8, WIDTH, HEIGHT, SIZE>, u8) {
+    let mut labels = TileMap::from_fn(|_| 0);
+    let mut remaining = *set;
+    let mut count: u8 = 0;
+
+    while let Some(first) = remaining.first() {
+        count += 1;
+        let mut component = S::EMPTY;
+        component.set_bit(&first, true);
+        loop {
+            let mut next = component
+                .union(&component.shift_east())
+                .union(&component.shift_west());
+            next = if include_diagonals {
+                next.union(&next.shift_north(1)).union(&next.shift_south(1))
+            } else {
+                next.union(&component.shift_north(1))
+                    .union(&component.shift_south(1))
+            };
+            next = next.intersect(&remaining);
+            if next == component {
+                break;
+            }
+            component = next;
+        }
+
+        for tile in component.iter_true_tiles() {
+            labels[tile] = count;
+        }
+        remaining = remaining.except(&component);
+    }
+
+    (labels, count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The tiles orthogonally connected to the first tile of the set
+    fn flood_fill<const W: u8, const H: u8, S: TileSetTrait<W, H>>(set: &S) -> S {
+        let mut current = S::EMPTY;
+        if let Some(first) = set.first() {
+            current.set_bit(&first, true);
+        }
+        loop {
+            let next = current
+                .union(&current.shift_north(1))
+                .union(&current.shift_south(1))
+                .union(&current.shift_east())
+                .union(&current.shift_west())
+                .intersect(set);
+            if next == current {
+                return current;
+            }
+            current = next;
+        }
+    }
+
+    fn check_generic<const W: u8, const H: u8, S: TileSetTrait<W, H> + core::fmt::Debug>() {
+        let set: S = Tile::<W, H>::iter_by_row()
+            .filter(|tile| tile.x() == 0 || tile.y() == H - 1 || tile.x() == W - 1)
+            .collect();
+        assert_eq!(set.count(), u32::from(W + H + H - 2));
+        assert_eq!(flood_fill(&set), set);
+
+        let split = set.except(&S::all().shift_south(H - 1));
+        assert_eq!(flood_fill(&split).count(), u32::from(H - 1));
+        assert!(S::EMPTY.is_empty());
+        assert_eq!(S::all().negate(), S::EMPTY);
+        assert_eq!(set.iter_true_tiles().len(), set.count() as usize);
+
+        let moved = set.translate(Vector::new(1, -1));
+        assert_eq!(moved.count(), u32::from(W + H - 3));
+        let kept: S = set
+            .iter_true_tiles()
+            .filter(|tile| tile.x() < W - 1 && tile.y() > 0)
+            .collect();
+        assert_eq!(moved.translate(Vector::new(-1, 1)), kept);
+        assert_eq!(set.translate(Vector::new(0, 0)), set);
+        assert!(set.translate(Vector::new(-16, 0)).is_empty());
+    }
+
+    #[test]
+    fn test_ray_hits() {
+        let mut blockers = TileSet64::<8, 8, 64>::EMPTY;
+        blockers.set_bit(&Tile::new_const::<5, 2>(), true);
+        blockers.set_bit(&Tile::new_const::<2, 2>(), true);
+        let origin = Tile::new_const::<2, 2>();
+
+        assert_eq!(
+            blockers.ray_hits(origin, Vector::EAST),
+            Some(Tile::new_const::<5, 2>())
+        );
+        assert_eq!(blockers.ray_hits(origin, Vector::WEST), None);
+        assert_eq!(
+            blockers.ray_hits(Tile::new_const::<1, 0>(), Vector::new(2, 1)),
+            Some(Tile::new_const::<5, 2>())
+        );
+        assert_eq!(blockers.ray_hits(origin, Vector::ZERO), None);
+    }
+
+    #[test]
+    fn test_resize_into() {
+        let small = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() == tile.y());
+        let large: TileSet64<8, 8, 64> = small.resize_into();
+        assert_eq!(large.count(), 4);
+        assert!(large.get_bit(&Tile::new_const::<3, 3>()));
+        assert!(!large.get_bit(&Tile::new_const::<4, 4>()));
+
+        let back: TileSet16<4, 4, 16> = large.resize_into();
+        assert_eq!(back, small);
+
+        let cropped: TileSet8<3, 2, 6> = TileSet256::<15, 16, 240>::all().resize_into();
+        assert_eq!(cropped, TileSet8::ALL);
+    }
+
+    #[test]
+    fn test_generic() {
+        check_generic::<3, 2, TileSet8<3, 2, 6>>();
+        check_generic::<4, 4, TileSet16<4, 4, 16>>();
+        check_generic::<5, 6, TileSet32<5, 6, 30>>();
+        check_generic::<8, 8, TileSet64<8, 8, 64>>();
+        check_generic::<10, 12, TileSet128<10, 12, 120>>();
+        check_generic::<15, 16, TileSet256<15, 16, 240>>();
+    }
+}
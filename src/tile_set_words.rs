@@ -0,0 +1,411 @@
+use core::{
+    fmt::{self, Write},
+    iter::FusedIterator,
+};
+
+use crate::prelude::*;
+
+/// A set of `Tile16` tiles, stored as bits in `WORDS` words of 64 bits.
+/// Use this instead of the other tile sets for grids with more than 256 tiles, such as 24x24 or 32x32 boards.
+/// `TileSet512` and `TileSet1024` are the common sizes.
+#[must_use]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TileSetWords<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize>(
+    [u64; WORDS],
+);
+
+/// A set of up to 512 `Tile16` tiles, such as a 20x20 board
+pub type TileSet512<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize> =
+    TileSetWords<WIDTH, HEIGHT, SIZE, 8>;
+
+/// A set of up to 1024 `Tile16` tiles, such as a 24x24 or 32x32 board
+pub type TileSet1024<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize> =
+    TileSetWords<WIDTH, HEIGHT, SIZE, 16>;
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize> Default
+    for TileSetWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+    fn default() -> Self {
+        Self::assert_legal();
+        Self::EMPTY
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize>
+    TileSetWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+    pub const EMPTY: Self = {
+        Self::assert_legal();
+        Self([0; WORDS])
+    };
+
+    /// The set where all tiles are present
+    pub const ALL: Self = {
+        Self::assert_legal();
+        let mut words = [0; WORDS];
+        let mut i = 0;
+        while i < WORDS {
+            let start = i * 64;
+            if start + 64 <= SIZE {
+                words[i] = u64::MAX;
+            } else if start < SIZE {
+                words[i] = u64::MAX >> (start + 64 - SIZE);
+            }
+            i += 1;
+        }
+        Self(words)
+    };
+
+    /// The set where all tiles are present
+    pub const fn all() -> Self {
+        Self::ALL
+    }
+
+    #[inline]
+    const fn assert_legal() {
+        DimensionError::assert(WIDTH as usize * HEIGHT as usize, SIZE, WORDS * 64);
+    }
+
+    /// The word containing the bit for `tile`, and the mask of that bit within the word
+    const fn position(tile: Tile16<WIDTH, HEIGHT>) -> (usize, u64) {
+        let index = tile.inner() as usize;
+        (index / 64, 1 << (index % 64))
+    }
+
+    pub fn from_fn<F: FnMut(Tile16<WIDTH, HEIGHT>) -> bool>(mut cb: F) -> Self {
+        Self::assert_legal();
+
+        let mut result = Self::default();
+        for tile in Tile16::<WIDTH, HEIGHT>::iter_by_row() {
+            if cb(tile) {
+                result.set_bit(&tile, true);
+            }
+        }
+
+        result
+    }
+
+    #[must_use]
+    pub const fn into_inner(self) -> [u64; WORDS] {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn get_bit(&self, tile: &Tile16<WIDTH, HEIGHT>) -> bool {
+        let (word, mask) = Self::position(*tile);
+        self.0[word] & mask != 0
+    }
+
+    pub fn set_bit(&mut self, tile: &Tile16<WIDTH, HEIGHT>, bit: bool) {
+        let (word, mask) = Self::position(*tile);
+        if bit {
+            self.0[word] |= mask;
+        } else {
+            self.0[word] &= !mask;
+        }
+    }
+
+    pub fn with_bit_set(&self, tile: &Tile16<WIDTH, HEIGHT>, bit: bool) -> Self {
+        let mut result = *self;
+        result.set_bit(tile, bit);
+        result
+    }
+
+    /// Add `tile` to the set, returning whether it was newly added
+    pub fn insert(&mut self, tile: &Tile16<WIDTH, HEIGHT>) -> bool {
+        let added = !self.get_bit(tile);
+        self.set_bit(tile, true);
+        added
+    }
+
+    /// Remove `tile` from the set, returning whether it was present
+    pub fn remove(&mut self, tile: &Tile16<WIDTH, HEIGHT>) -> bool {
+        let removed = self.get_bit(tile);
+        self.set_bit(tile, false);
+        removed
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        let mut i = 0;
+        while i < WORDS {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    /// The number of tiles in the set
+    #[must_use]
+    pub const fn count(&self) -> u32 {
+        let mut count = 0;
+        let mut i = 0;
+        while i < WORDS {
+            count += self.0[i].count_ones();
+            i += 1;
+        }
+        count
+    }
+
+    /// The first tile in the set
+    #[must_use]
+    pub fn first(&self) -> Option<Tile16<WIDTH, HEIGHT>> {
+        self.iter_true_tiles().next()
+    }
+
+    /// The last tile in the set
+    #[must_use]
+    pub fn last(&self) -> Option<Tile16<WIDTH, HEIGHT>> {
+        self.iter_true_tiles().next_back()
+    }
+
+    /// Iterate through whether each tile is present, in row order
+    #[must_use]
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = bool> + DoubleEndedIterator + Clone + '_ {
+        Tile16::<WIDTH, HEIGHT>::iter_by_row().map(|tile| self.get_bit(&tile))
+    }
+
+    /// Iterate through the tiles which are present in the set, in row order
+    #[must_use]
+    pub const fn iter_true_tiles(&self) -> TrueTilesIterWords<WIDTH, HEIGHT, SIZE, WORDS> {
+        TrueTilesIterWords { remaining: self.0 }
+    }
+
+    /// The tiles which are in either set
+    pub const fn union(&self, rhs: &Self) -> Self {
+        let mut words = self.0;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] |= rhs.0[i];
+            i += 1;
+        }
+        Self(words)
+    }
+
+    /// The tiles which are in both sets
+    pub const fn intersect(&self, rhs: &Self) -> Self {
+        let mut words = self.0;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] &= rhs.0[i];
+            i += 1;
+        }
+        Self(words)
+    }
+
+    /// The tiles which are in this set but not `rhs`
+    pub const fn except(&self, rhs: &Self) -> Self {
+        let mut words = self.0;
+        let mut i = 0;
+        while i < WORDS {
+            words[i] &= !rhs.0[i];
+            i += 1;
+        }
+        Self(words)
+    }
+
+    /// The tiles which are not in this set
+    pub const fn negate(&self) -> Self {
+        Self::ALL.except(self)
+    }
+
+    #[must_use]
+    pub const fn is_subset(&self, rhs: &Self) -> bool {
+        self.except(rhs).is_empty()
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize>
+    FromIterator<Tile16<WIDTH, HEIGHT>> for TileSetWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+    fn from_iter<T: IntoIterator<Item = Tile16<WIDTH, HEIGHT>>>(iter: T) -> Self {
+        Self::assert_legal();
+        let mut r = Self::default();
+        for x in iter {
+            r.set_bit(&x, true);
+        }
+        r
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize> IntoIterator
+    for TileSetWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+    type Item = Tile16<WIDTH, HEIGHT>;
+    type IntoIter = TrueTilesIterWords<WIDTH, HEIGHT, SIZE, WORDS>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_true_tiles()
+    }
+}
+
+/// Iterator through the tiles of a `TileSetWords`, in row order
+#[derive(Clone, Debug)]
+pub struct TrueTilesIterWords<
+    const WIDTH: u16,
+    const HEIGHT: u16,
+    const SIZE: usize,
+    const WORDS: usize,
+> {
+    remaining: [u64; WORDS],
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize> Iterator
+    for TrueTilesIterWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+    type Item = Tile16<WIDTH, HEIGHT>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (index, word) = self
+            .remaining
+            .iter_mut()
+            .enumerate()
+            .find(|(_, word)| **word != 0)?;
+        let bit = word.trailing_zeros();
+        *word &= *word - 1;
+        Tile16::try_from_usize(index * 64 + bit as usize)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = self
+            .remaining
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+        (count, Some(count))
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize> DoubleEndedIterator
+    for TrueTilesIterWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (index, word) = self
+            .remaining
+            .iter_mut()
+            .enumerate()
+            .rfind(|(_, word)| **word != 0)?;
+        let bit = 63 - word.leading_zeros();
+        *word &= !(1 << bit);
+        Tile16::try_from_usize(index * 64 + bit as usize)
+    }
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize> ExactSizeIterator
+    for TrueTilesIterWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+}
+
+impl<const WIDTH: u16, const HEIGHT: u16, const SIZE: usize, const WORDS: usize> FusedIterator
+    for TrueTilesIterWords<WIDTH, HEIGHT, SIZE, WORDS>
+{
+}
+
+impl<const W: u16, const H: u16, const SIZE: usize, const WORDS: usize> fmt::Display
+    for TileSetWords<W, H, SIZE, WORDS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.iter().enumerate() {
+            if i > 0 && i % (W as usize) == 0 && !f.alternate() {
+                f.write_char('\n')?;
+            }
+            f.write_char(if e { '*' } else { '_' })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<const W: u16, const H: u16, const SIZE: usize, const WORDS: usize> fmt::Debug
+    for TileSetWords<W, H, SIZE, WORDS>
+{
+    /// Writes the dimensions and the grid, with rows separated by `/`.
+    /// The alternate form puts each row on its own line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TileSetWords<{W}, {H}>(")?;
+        let separator = if f.alternate() { '\n' } else { '/' };
+        if f.alternate() {
+            f.write_char(separator)?;
+        }
+        for (i, e) in self.iter().enumerate() {
+            if i > 0 && i % (W as usize) == 0 {
+                f.write_char(separator)?;
+            }
+            f.write_char(if e { '*' } else { '_' })?;
+        }
+        if f.alternate() {
+            f.write_char(separator)?;
+        }
+        f.write_char(')')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_32x32() {
+        type Set = TileSet1024<32, 32, 1024>;
+        let diagonal = Set::from_fn(|tile| tile.x() == tile.y());
+        assert_eq!(diagonal.count(), 32);
+        assert_eq!(diagonal.first(), Some(Tile16::NORTH_WEST));
+        assert_eq!(diagonal.last(), Some(Tile16::SOUTH_EAST));
+        assert!(diagonal
+            .iter_true_tiles()
+            .eq((0..32).map(|i| Tile16::try_new(i, i).unwrap())));
+        assert!(diagonal
+            .iter_true_tiles()
+            .rev()
+            .eq((0..32).rev().map(|i| Tile16::try_new(i, i).unwrap())));
+        assert_eq!(diagonal.iter_true_tiles().len(), 32);
+
+        assert_eq!(Set::ALL.count(), 1024);
+        assert_eq!(diagonal.negate().count(), 992);
+        assert_eq!(diagonal.union(&diagonal.negate()), Set::ALL);
+        assert!(diagonal.intersect(&diagonal.negate()).is_empty());
+        assert!(diagonal.is_subset(&Set::ALL));
+    }
+
+    #[test]
+    fn test_partial_words() {
+        type Set = TileSet1024<24, 24, 576>;
+        type Small = TileSet512<20, 20, 400>;
+        assert_eq!(Set::ALL.count(), 576);
+        assert_eq!(Set::EMPTY.negate(), Set::ALL);
+        assert_eq!(Set::ALL.last(), Some(Tile16::SOUTH_EAST));
+
+        assert_eq!(Small::ALL.count(), 400);
+        assert_eq!(Small::ALL.iter_true_tiles().count(), 400);
+        assert!(Small::ALL
+            .iter_true_tiles()
+            .eq(Tile16::<20, 20>::iter_by_row()));
+    }
+
+    #[test]
+    fn test_insert_and_remove() {
+        let mut set = TileSet512::<20, 20, 400>::EMPTY;
+        let tile = Tile16::new_const::<19, 12>();
+        assert!(set.insert(&tile));
+        assert!(!set.insert(&tile));
+        assert!(set.get_bit(&tile));
+        assert_eq!(set.into_iter().collect_vec(), [tile]);
+        assert!(set.remove(&tile));
+        assert!(!set.remove(&tile));
+        assert!(set.is_empty());
+
+        let set: TileSet512<20, 20, 400> =
+            [Tile16::CENTER, Tile16::NORTH_EAST].into_iter().collect();
+        assert_eq!(set.iter_true_tiles().join(";"), "(19,0);(10,10)");
+    }
+
+    #[test]
+    fn test_display() {
+        let set = TileSet512::<3, 2, 6>::from_fn(|tile| tile.x() == tile.y());
+        assert_eq!(set.to_string(), "*__\n_*_");
+        assert_eq!(format!("{set:?}"), "TileSetWords<3, 2>(*__/_*_)");
+    }
+}
@@ -0,0 +1,156 @@
+use crate::polyomino::{next_outline_vertex, next_rectangle, validate_shape_tiles};
+use crate::prelude::*;
+
+/// A shape made of an arbitrary number of tiles.
+/// Allows tile collections to be treated as a `Shape` without building a `Polyomino` of a specific size.
+/// The tiles must be distinct and orthogonally connected.
+/// Requires `std`
+#[must_use]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TileSlice<'a>(pub &'a [DynamicTile]);
+
+impl<'a> From<&'a [DynamicTile]> for TileSlice<'a> {
+    fn from(tiles: &'a [DynamicTile]) -> Self {
+        Self(tiles)
+    }
+}
+
+impl<'a> IntoIterator for TileSlice<'a> {
+    type Item = DynamicTile;
+    type IntoIter = core::iter::Copied<core::slice::Iter<'a, DynamicTile>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().copied()
+    }
+}
+
+impl<'a> Shape for TileSlice<'a> {
+    type OutlineIter = SliceOutlineIter<'a>;
+
+    type RectangleIter = SliceRectangleIter;
+
+    fn draw_outline(&self) -> Self::OutlineIter {
+        let first = self.0.iter().min().copied();
+        SliceOutlineIter {
+            tiles: self.0,
+            first: first.unwrap_or_default(),
+            next: first.map(|first| (first, Corner::NorthWest)),
+        }
+    }
+
+    fn deconstruct_into_rectangles(&self) -> Self::RectangleIter {
+        let mut remaining_tiles = self.0.to_vec();
+        // Match the order used by polyominos
+        remaining_tiles.sort();
+        SliceRectangleIter { remaining_tiles }
+    }
+
+    #[cfg(any(test, feature = "glam"))]
+    fn contains_point(&self, point: glam::f32::Vec2, scale: f32) -> bool {
+        crate::polyomino::contains_point(self.0, point, scale)
+    }
+}
+
+impl<'a> TileSlice<'a> {
+    /// Draw the outline of this shape, first checking that its tiles are distinct and connected.
+    /// `draw_outline` may panic for shapes which fail this check.
+    /// # Errors
+    /// If the slice is empty, contains duplicate tiles or is not connected
+    pub fn try_draw_outline(&self) -> Result<SliceOutlineIter<'a>, ShapeError> {
+        validate_shape_tiles(
+            &mut self.0.to_vec(),
+            &mut vec![false; self.0.len()],
+            &mut vec![0; self.0.len()],
+        )?;
+        Ok(self.draw_outline())
+    }
+}
+
+/// Iterator for the outline of a `TileSlice`
+#[derive(Clone, Debug)]
+pub struct SliceOutlineIter<'a> {
+    tiles: &'a [DynamicTile],
+    first: DynamicTile,
+    next: Option<(DynamicTile, Corner)>,
+}
+
+impl Iterator for SliceOutlineIter<'_> {
+    type Item = DynamicVertex;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        next_outline_vertex(self.tiles, self.first, &mut self.next)
+    }
+}
+
+/// Iterator for deconstructing a `TileSlice` to rectangles
+#[derive(Clone, Debug)]
+pub struct SliceRectangleIter {
+    remaining_tiles: Vec<DynamicTile>,
+}
+
+impl Iterator for SliceRectangleIter {
+    type Item = Rectangle;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut len = self.remaining_tiles.len();
+        let result = next_rectangle(&mut self.remaining_tiles, &mut len);
+        self.remaining_tiles.truncate(len);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_matches_polyomino() {
+        for shape in Polyomino::ALL_PENTOMINOS {
+            let mut tiles = shape.tiles().to_vec();
+            // The order of the tiles should not matter
+            tiles.reverse();
+            let slice = TileSlice(&tiles);
+
+            assert_eq!(
+                slice.draw_outline().collect_vec(),
+                shape.draw_outline().collect_vec()
+            );
+            assert_eq!(
+                slice.deconstruct_into_rectangles().collect_vec(),
+                shape.deconstruct_into_rectangles().collect_vec()
+            );
+            assert_eq!(slice.into_iter().count(), 5);
+            assert!(slice.try_draw_outline().is_ok());
+
+            for tile in shape.tiles() {
+                let center = tile.get_center(3.0);
+                assert!(slice.contains_point(center, 3.0));
+                assert!(!slice.contains_point(center + glam::f32::Vec2::new(9.0, 9.0), 3.0));
+            }
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        let slice = TileSlice(&[]);
+        assert_eq!(slice.draw_outline().count(), 0);
+        assert_eq!(slice.try_draw_outline().err(), Some(ShapeError::Empty));
+        assert_eq!(slice.deconstruct_into_rectangles().count(), 0);
+    }
+
+    #[test]
+    fn test_validate_large_slice() {
+        let mut tiles = (0..100)
+            .flat_map(|y| (0..100).map(move |x| DynamicTile(Vector::new(x, y))))
+            .rev()
+            .collect_vec();
+        assert!(TileSlice(&tiles).try_draw_outline().is_ok());
+
+        tiles.retain(|tile| tile.x != 50);
+        assert_eq!(
+            TileSlice(&tiles).try_draw_outline().err(),
+            Some(ShapeError::Disconnected)
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use core::ops::{Deref, Index, IndexMut};
+
+use crate::prelude::*;
+
+/// A `TileMap` which records which tiles have been mutably accessed.
+/// Read access is through `Deref`.
+/// Any access through `IndexMut` marks the tile as dirty, whether or not the value actually changes.
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrackedTileMap<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> {
+    map: TileMap<T, WIDTH, HEIGHT, SIZE>,
+    dirty: TileMap<bool, WIDTH, HEIGHT, SIZE>,
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    TrackedTileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    /// Wrap a map. No tiles will be dirty.
+    pub fn new(map: TileMap<T, WIDTH, HEIGHT, SIZE>) -> Self {
+        Self {
+            map,
+            dirty: TileMap::default(),
+        }
+    }
+
+    pub fn into_inner(self) -> TileMap<T, WIDTH, HEIGHT, SIZE> {
+        self.map
+    }
+
+    /// Whether the tile has been mutably accessed since the dirty tiles were last taken
+    #[must_use]
+    pub fn is_dirty(&self, tile: Tile<WIDTH, HEIGHT>) -> bool {
+        self.dirty[tile]
+    }
+
+    /// Iterate through the dirty tiles, without clearing them
+    pub fn iter_dirty(&self) -> impl Iterator<Item = Tile<WIDTH, HEIGHT>> + '_ {
+        self.dirty
+            .enumerate()
+            .filter(|(_, dirty)| **dirty)
+            .map(|(tile, _)| tile)
+    }
+
+    /// Get the tiles which have been mutably accessed since this was last called, and mark all tiles as clean
+    pub fn take_dirty<S: FromIterator<Tile<WIDTH, HEIGHT>>>(&mut self) -> S {
+        let result = self.iter_dirty().collect();
+        self.dirty = TileMap::default();
+        result
+    }
+
+    /// Set the value at a tile, marking it as dirty
+    pub fn set(&mut self, tile: Tile<WIDTH, HEIGHT>, value: T) {
+        self[tile] = value;
+    }
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> From<TileMap<T, WIDTH, HEIGHT, SIZE>>
+    for TrackedTileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    fn from(map: TileMap<T, WIDTH, HEIGHT, SIZE>) -> Self {
+        Self::new(map)
+    }
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Deref
+    for TrackedTileMap<T, WIDTH, HEIGHT, SIZE>
+{
+    type Target = TileMap<T, WIDTH, HEIGHT, SIZE>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.map
+    }
+}
+
+impl<T, const W: u8, const H: u8, const SIZE: usize> Index<Tile<W, H>>
+    for TrackedTileMap<T, W, H, SIZE>
+{
+    type Output = T;
+
+    fn index(&self, index: Tile<W, H>) -> &Self::Output {
+        &self.map[index]
+    }
+}
+
+impl<T, const W: u8, const H: u8, const SIZE: usize> IndexMut<Tile<W, H>>
+    for TrackedTileMap<T, W, H, SIZE>
+{
+    fn index_mut(&mut self, index: Tile<W, H>) -> &mut Self::Output {
+        self.dirty[index] = true;
+        &mut self.map[index]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_take_dirty() {
+        let mut map: TrackedTileMap<u8, 3, 3, 9> = TileMap::default().into();
+
+        assert_eq!(map.iter_dirty().count(), 0);
+
+        map[Tile::CENTER] = 4;
+        map.set(Tile::NORTH_WEST, 1);
+        map[Tile::CENTER] += 1;
+
+        assert!(map.is_dirty(Tile::CENTER));
+        assert!(!map.is_dirty(Tile::SOUTH_EAST));
+        assert_eq!(map[Tile::CENTER], 5);
+        assert_eq!(map.to_string(), "1|0|0\n0|5|0\n0|0|0");
+
+        let dirty: TileSet16<3, 3, 9> = map.take_dirty();
+        assert_eq!(dirty.iter_true_tiles().join("|"), "(0,0)|(1,1)");
+
+        let dirty: TileSet16<3, 3, 9> = map.take_dirty();
+        assert_eq!(dirty, TileSet16::EMPTY);
+
+        let _ = map[Tile::SOUTH_EAST];
+        assert_eq!(map.iter_dirty().count(), 0);
+
+        assert_eq!(map.into_inner()[Tile::NORTH_WEST], 1);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::prelude::*;
+
+/// Something which can be flipped in place.
+/// Implement this for your own grid types so they can be transformed by generic code.
+pub trait Flippable {
+    /// Flip this in place, in the given axes
+    fn flip(&mut self, axes: FlipAxes);
+}
+
+/// Something which can be rotated in place.
+/// Implement this for your own grid types so they can be transformed by generic code.
+pub trait Rotatable {
+    /// Rotate this in place, clockwise by the given number of quarter turns
+    fn rotate(&mut self, quarter_turns: QuarterTurns);
+}
+
+/// Something which can be flipped, returning a copy.
+/// Types which are transformed in place, such as `TileMap`, implement `Flippable` instead.
+pub trait Flip {
+    /// A copy of this, flipped in the given axes
+    #[must_use]
+    fn flip(&self, axes: FlipAxes) -> Self;
+}
+
+/// Something which can be rotated, returning a copy.
+/// Types which are transformed in place, such as `TileMap`, implement `Rotatable` instead.
+pub trait Rotate {
+    /// A copy of this, rotated clockwise by the given number of quarter turns
+    #[must_use]
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+    use strum::IntoEnumIterator;
+
+    fn transform<T: Flippable + Rotatable>(mut value: T, dihedral: Dihedral) -> T {
+        value.rotate(dihedral.quarter_turns);
+        if dihedral.flipped {
+            value.flip(FlipAxes::Horizontal);
+        }
+        value
+    }
+
+    fn check_by_value<T: Flip + Rotate + Clone + PartialEq + core::fmt::Debug>(value: &T) {
+        for quarter_turns in QuarterTurns::iter() {
+            let rotated = (0..4).fold(value.clone(), |v, _| v.rotate(quarter_turns));
+            assert_eq!(rotated, *value);
+        }
+        for axes in FlipAxes::iter() {
+            assert_eq!(value.flip(axes).flip(axes), *value);
+        }
+    }
+
+    #[test]
+    fn test_by_value() {
+        check_by_value(&Tile::<5, 5>::new_const::<1, 3>());
+        check_by_value(&Vertex::<5, 5>::new_const::<1, 3>());
+        check_by_value(&Vector::new(2, -3));
+        check_by_value(&DynamicTile(Vector::new(2, -3)));
+        check_by_value(&DynamicVertex(Vector::new(2, -3)));
+        check_by_value(&Polyomino::F_PENTOMINO);
+
+        let set = TileSet16::<4, 4, 16>::from_fn(|tile| tile.x() == 0 || tile.y() == 1);
+        check_by_value(&set);
+        assert_eq!(
+            Rotate::rotate(&set, QuarterTurns::One),
+            set.iter_true_tiles()
+                .map(|tile| tile.rotate(QuarterTurns::One))
+                .collect()
+        );
+        assert_eq!(
+            Flip::flip(&set, FlipAxes::Vertical),
+            TileSet16::from_fn(|tile| tile.x() == 0 || tile.y() == 2)
+        );
+
+        let set = TileSet256::<12, 12, 144>::from_fn(|tile| tile.x() < tile.y());
+        check_by_value(&set);
+    }
+
+    #[test]
+    fn test_generic_transform() {
+        let map: TileMap<u8, 3, 3, 9> = TileMap::from_fn(|tile| tile.inner());
+        let dihedral = Dihedral::new(QuarterTurns::Two, true);
+
+        assert_eq!(
+            transform(map, dihedral).iter().join("|"),
+            map.with_rotate(QuarterTurns::Two)
+                .with_flip(FlipAxes::Horizontal)
+                .iter()
+                .join("|")
+        );
+
+        let map16: TileMap16<u16, 3, 3, 9> = TileMap16::from_fn(|tile| tile.inner());
+        assert_eq!(
+            transform(map16, dihedral),
+            map16
+                .with_rotate(QuarterTurns::Two)
+                .with_flip(FlipAxes::Horizontal)
+        );
+    }
+}
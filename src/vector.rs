@@ -6,6 +6,7 @@ use core::{
 #[cfg(any(test, feature = "serde"))]
 use serde::{Deserialize, Serialize};
 
+use crate::parse_coordinates_error::{parse_coordinate, parse_coordinates};
 use crate::prelude::*;
 
 /// A 2d vector.
@@ -40,6 +41,44 @@ impl core::fmt::Display for Vector {
     }
 }
 
+impl core::str::FromStr for Vector {
+    type Err = ParseCoordinatesError;
+
+    /// Parses vectors of the form `(x,y)` or `x,y`, as well as the formats produced by `Display`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s == "Zero" {
+            return Ok(Vector::ZERO);
+        }
+        if let Some(index) = Vector::UNIT_NAMES.iter().position(|name| *name == s) {
+            return Ok(Vector::UNITS[index]);
+        }
+        if let Some(fields) = s
+            .strip_prefix("Vector")
+            .and_then(|rest| rest.trim().strip_prefix('{'))
+            .and_then(|rest| rest.strip_suffix('}'))
+        {
+            let (x, y) = fields
+                .split_once(',')
+                .ok_or(ParseCoordinatesError::InvalidFormat)?;
+            let x = x
+                .trim()
+                .strip_prefix("x:")
+                .ok_or(ParseCoordinatesError::InvalidFormat)?;
+            let y = y
+                .trim()
+                .strip_prefix("y:")
+                .ok_or(ParseCoordinatesError::InvalidFormat)?;
+            return Ok(Vector {
+                x: parse_coordinate(x)?,
+                y: parse_coordinate(y)?,
+            });
+        }
+        let (x, y) = parse_coordinates(s)?;
+        Ok(Vector { x, y })
+    }
+}
+
 impl Vector {
     pub const ZERO: Self = Self { x: 0, y: 0 };
     pub const NORTH: Self = Self { x: 0, y: -1 };
@@ -80,6 +119,12 @@ impl Vector {
         Self { x, y }
     }
 
+    /// The unit vector in a direction
+    #[inline]
+    pub const fn from_direction(direction: Direction) -> Self {
+        direction.vector()
+    }
+
     pub const fn const_neg(&self) -> Self {
         self.const_mul(-1)
     }
@@ -151,6 +196,12 @@ impl Vector {
         self.x != 0 && self.y != 0
     }
 
+    #[must_use]
+    /// Returns true if this is a non-zero vector along a single axis
+    pub const fn is_orthogonal(&self) -> bool {
+        (self.x == 0) ^ (self.y == 0)
+    }
+
     pub const fn horizontal_component(&self) -> Self {
         Self { x: self.x, y: 0 }
     }
@@ -175,6 +226,52 @@ impl Vector {
             self.y > other.y
         }
     }
+
+    /// The angle of this vector in radians, measured from east.
+    /// As y increases to the south, positive angles are clockwise.
+    /// Requires `std` or `libm`
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn angle(&self) -> f32 {
+        crate::float_math::atan2(f32::from(self.y), f32::from(self.x))
+    }
+
+    /// The unit vector whose angle is closest to `radians`.
+    /// Uses the same convention as `angle`.
+    /// Angles which are not finite, or too large to be precise, give an unspecified unit vector.
+    /// Requires `std` or `libm`
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[allow(clippy::cast_possible_truncation)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn from_angle_snapped(radians: f32) -> Self {
+        let octant = crate::float_math::round(radians / core::f32::consts::FRAC_PI_4) as i64;
+        // `UNITS` starts at north, which is two octants anticlockwise of east.
+        // The octant is reduced first, as it saturates for huge angles.
+        let index = (octant.rem_euclid(8) + 2) % 8;
+        Self::UNITS[index as usize]
+    }
+
+    /// The euclidean length of this vector.
+    /// Requires `std` or `libm`
+    #[cfg(any(test, feature = "std", feature = "libm"))]
+    #[must_use]
+    pub fn length(&self) -> f32 {
+        let x = f32::from(self.x);
+        let y = f32::from(self.y);
+        crate::float_math::sqrt(x * x + y * y)
+    }
+}
+
+impl Flip for Vector {
+    fn flip(&self, axes: FlipAxes) -> Self {
+        Vector::flip(self, axes)
+    }
+}
+
+impl Rotate for Vector {
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        Vector::rotate(self, quarter_turns)
+    }
 }
 
 impl Neg for Vector {
@@ -247,7 +344,6 @@ impl Mul<usize> for Vector {
 
 #[cfg(any(test, feature = "glam"))]
 impl HasCenter for Vector {
-    #[must_use]
     fn get_center(&self, scale: f32) -> glam::f32::Vec2 {
         let x = scale * f32::from(self.x);
         let y = scale * f32::from(self.y);
@@ -256,11 +352,58 @@ impl HasCenter for Vector {
     }
 }
 
+#[cfg(any(test, feature = "mint"))]
+impl From<Vector> for mint::Vector2<i8> {
+    fn from(value: Vector) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl From<mint::Vector2<i8>> for Vector {
+    fn from(value: mint::Vector2<i8>) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+#[cfg(any(test, feature = "mint"))]
+impl mint::IntoMint for Vector {
+    type MintType = mint::Vector2<i8>;
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     type V = Vector;
 
+    #[test]
+    fn test_mint() {
+        let vector = V::new(3, -2);
+        let converted: mint::Vector2<i8> = vector.into();
+        assert_eq!(converted, mint::Vector2 { x: 3, y: -2 });
+        assert_eq!(V::from(converted), vector);
+
+        let center: mint::Vector2<f32> = vector.get_center(2.0).into();
+        assert_eq!(center, mint::Vector2 { x: 6.0, y: -4.0 });
+    }
+
+    #[test]
+    fn test_from_str() {
+        for vector in [V::ZERO, V::NORTH, V::SOUTH_WEST, V::new(2, -3)] {
+            assert_eq!(vector.to_string().parse(), Ok(vector));
+        }
+        assert_eq!("(-1,5)".parse(), Ok(V::new(-1, 5)));
+        assert_eq!("4,0".parse(), Ok(V::new(4, 0)));
+        assert_eq!(
+            "Vector { x: 1 }".parse::<V>(),
+            Err(ParseCoordinatesError::InvalidFormat)
+        );
+        assert_eq!("Up".parse::<V>(), Err(ParseCoordinatesError::InvalidFormat));
+    }
+
     #[test]
     pub fn test_display() {
         assert_eq!("Zero", Vector::new(0, 0).to_string());
@@ -299,9 +442,14 @@ mod tests {
         assert!(!V::ZERO.is_diagonal());
         assert!(!V::NORTH.is_diagonal());
         assert!(V::NORTH_EAST.is_diagonal());
+        assert!(!V::ZERO.is_orthogonal());
+        assert!(V::NORTH.is_orthogonal());
+        assert!(V::new(-3, 0).is_orthogonal());
+        assert!(!V::NORTH_EAST.is_orthogonal());
     }
 
     #[test]
+    #[allow(clippy::op_ref)]
     pub fn test_functions() {
         assert_eq!(V::NORTH.neg(), V::SOUTH);
         assert_eq!((&V::NORTH).neg(), V::SOUTH);
@@ -317,11 +465,50 @@ mod tests {
         assert_eq!(V::NORTH * 2usize, V::new(0, -2));
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    pub fn test_angle() {
+        use core::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        assert_eq!(V::EAST.angle(), 0.0);
+        assert_eq!(V::SOUTH.angle(), FRAC_PI_2);
+        assert_eq!(V::NORTH.angle(), -FRAC_PI_2);
+        assert_eq!(V::WEST.angle(), PI);
+
+        for unit in V::UNITS {
+            assert_eq!(V::from_angle_snapped(unit.angle()), unit);
+            assert_eq!(V::from_angle_snapped(unit.angle() + 0.3), unit);
+            assert_eq!(V::from_angle_snapped(unit.angle() - 0.3), unit);
+            assert_eq!(V::from_angle_snapped(unit.angle() + 2.0 * PI), unit);
+        }
+
+        assert_eq!(V::from_angle_snapped(FRAC_PI_4 * 0.6), V::SOUTH_EAST);
+        assert_eq!(V::from_angle_snapped(V::new(5, -1).angle()), V::EAST);
+
+        for radians in [
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::MAX,
+            f32::MIN,
+            f32::NAN,
+        ] {
+            assert!(V::UNITS.contains(&V::from_angle_snapped(radians)));
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    pub fn test_length() {
+        assert_eq!(V::ZERO.length(), 0.0);
+        assert_eq!(V::NORTH.length(), 1.0);
+        assert_eq!(V::new(-3, 4).length(), 5.0);
+        assert_eq!(V::SOUTH_WEST.length(), core::f32::consts::SQRT_2);
+    }
+
     #[test]
     pub fn test_centre() {
         assert_eq!(
             V::SOUTH_WEST.get_center(2.0),
             glam::f32::Vec2::new(-2.0, 2.0)
-        )
+        );
     }
 }
@@ -8,7 +8,7 @@ use crate::prelude::*;
 /// A vertex in 2d space
 #[must_use]
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize))]
 pub struct Vertex<const WIDTH: u8, const HEIGHT: u8>(u8);
 
 impl<const WIDTH: u8, const HEIGHT: u8> From<Vertex<WIDTH, HEIGHT>> for DynamicVertex {
@@ -35,6 +35,16 @@ impl<const W: u8, const H: u8> fmt::Display for Vertex<W, H> {
     }
 }
 
+impl<const W: u8, const H: u8> core::str::FromStr for Vertex<W, H> {
+    type Err = ParseCoordinatesError;
+
+    /// Parses vertices of the form `(x,y)` or `x,y`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (x, y) = crate::parse_coordinates_error::parse_coordinates(s)?;
+        Self::try_new(x, y).ok_or(ParseCoordinatesError::OutOfBounds)
+    }
+}
+
 impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
     const COLUMNS: u8 = WIDTH;
     const HEIGHT: u8 = HEIGHT;
@@ -50,6 +60,23 @@ impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
     const MAX_COL: u8 = WIDTH;
     const MAX_ROW: u8 = HEIGHT;
 
+    /// Every vertex in the grid in row order, as an array that can be built and used in const contexts.
+    /// `SIZE` must be `COUNT`; `try_from_usize` gets a single vertex by index.
+    ///
+    /// # Panics
+    /// If `SIZE` is not the number of vertices in the grid
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn all<const SIZE: usize>() -> [Self; SIZE] {
+        DimensionError::assert(Self::COUNT, SIZE, u8::MAX as usize + 1);
+        let mut result = [Self(0); SIZE];
+        let mut index = 0;
+        while index < SIZE {
+            result[index] = Self(index as u8);
+            index += 1;
+        }
+        result
+    }
+
     pub const fn new_const<const X: u8, const Y: u8>() -> Self {
         Self::new_unchecked(X, Y)
     }
@@ -76,6 +103,10 @@ impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
         }
     }
 
+    pub(crate) const fn from_inner_unchecked(inner: u8) -> Self {
+        Self(inner)
+    }
+
     #[must_use]
     #[allow(clippy::cast_possible_truncation)]
     pub const fn try_from_usize(value: usize) -> Option<Self> {
@@ -127,6 +158,18 @@ impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
         self.0 / (Self::COLUMNS + 1)
     }
 
+    /// The vertex in the same row with column `x`, if it is in the grid
+    #[must_use]
+    pub const fn try_with_x(&self, x: u8) -> Option<Self> {
+        Self::try_new(x, self.y())
+    }
+
+    /// The vertex in the same column with row `y`, if it is in the grid
+    #[must_use]
+    pub const fn try_with_y(&self, y: u8) -> Option<Self> {
+        Self::try_new(self.x(), y)
+    }
+
     pub const fn flip(&self, axes: FlipAxes) -> Self {
         match axes {
             FlipAxes::None => *self,
@@ -138,6 +181,18 @@ impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
         }
     }
 
+    /// This vertex in the grid rotated one quarter turn clockwise, which has its width and height swapped.
+    /// For square grids this is the same as `rotate(QuarterTurns::One)`.
+    pub const fn rotate_clockwise(&self) -> Vertex<HEIGHT, WIDTH> {
+        Vertex::new_unchecked(HEIGHT - self.y(), self.x())
+    }
+
+    /// This vertex in the grid rotated one quarter turn anticlockwise, which has its width and height swapped.
+    /// For square grids this is the same as `rotate(QuarterTurns::Three)`.
+    pub const fn rotate_anticlockwise(&self) -> Vertex<HEIGHT, WIDTH> {
+        Vertex::new_unchecked(self.y(), WIDTH - self.x())
+    }
+
     #[must_use]
     pub const fn try_next(&self) -> Option<Self> {
         let Some(next) = self.inner().checked_add(1) else {
@@ -147,10 +202,28 @@ impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
     }
 
     #[must_use]
-    pub fn iter_by_row() -> impl FusedIterator<Item = Self> + ExactSizeIterator + Clone {
+    pub const fn try_prev(&self) -> Option<Self> {
+        let Some(prev) = self.inner().checked_sub(1) else {
+            return None;
+        };
+        Self::try_from_inner(prev)
+    }
+
+    #[must_use]
+    pub fn iter_by_row(
+    ) -> impl FusedIterator<Item = Self> + ExactSizeIterator + Clone + DoubleEndedIterator {
         ((Self::NORTH_WEST.0)..=(Self::SOUTH_EAST.0)).map(Self)
     }
 
+    /// Iterate through the vertices from `from` to `to` (inclusive) in row order.
+    /// The iterator will be empty if `to` comes before `from`.
+    pub fn iter_range(
+        from: Self,
+        to: Self,
+    ) -> impl FusedIterator<Item = Self> + ExactSizeIterator + Clone + DoubleEndedIterator {
+        (from.0..=to.0).map(Self)
+    }
+
     #[must_use]
     pub const fn const_add(&self, vector: &Vector) -> Option<Self> {
         let Some(c) = self.x().checked_add_signed(vector.x) else {
@@ -190,11 +263,41 @@ impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
             Corner::SouthEast => Tile::try_new(self.x(), self.y()),
         }
     }
+
+    /// Iterate through the orthogonally adjacent vertices
+    #[must_use]
+    pub fn iter_adjacent_vertices(
+        self,
+    ) -> impl FusedIterator<Item = Self> + DoubleEndedIterator + Clone {
+        Vector::CARDINALS.into_iter().filter_map(move |v| self + v)
+    }
+
+    /// Iterate through the tiles which have this vertex as a corner (up to 4)
+    #[must_use]
+    pub fn adjacent_tiles(
+        self,
+    ) -> impl FusedIterator<Item = Tile<WIDTH, HEIGHT>> + DoubleEndedIterator + Clone {
+        Corner::ALL
+            .into_iter()
+            .filter_map(move |corner| self.get_tile(&corner))
+    }
+
+    /// Iterate through the edges which touch this vertex.
+    /// Each edge is given as a pair of vertices, smallest first.
+    #[must_use]
+    pub fn edges(self) -> impl FusedIterator<Item = (Self, Self)> + DoubleEndedIterator + Clone {
+        self.iter_adjacent_vertices().map(move |other| {
+            if self < other {
+                (self, other)
+            } else {
+                (other, self)
+            }
+        })
+    }
 }
 
 #[cfg(any(test, feature = "glam"))]
 impl<const WIDTH: u8, const HEIGHT: u8> HasCenter for Vertex<WIDTH, HEIGHT> {
-    #[must_use]
     fn get_center(&self, scale: f32) -> glam::f32::Vec2 {
         let x = scale * f32::from(self.x());
         let y = scale * f32::from(self.y());
@@ -203,6 +306,24 @@ impl<const WIDTH: u8, const HEIGHT: u8> HasCenter for Vertex<WIDTH, HEIGHT> {
     }
 }
 
+#[cfg(any(test, feature = "glam"))]
+impl<const WIDTH: u8, const HEIGHT: u8> Vertex<WIDTH, HEIGHT> {
+    /// Gets the nearest vertex to this point, if it is within the grid.
+    /// This is the inverse of `get_center`.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn try_from_center(point: glam::f32::Vec2, scale: f32) -> Option<Self> {
+        let x = (point.x / scale).round();
+        let y = (point.y / scale).round();
+
+        if !(x >= 0.0 && x <= f32::from(WIDTH) && y >= 0.0 && y <= f32::from(HEIGHT)) {
+            return None;
+        }
+
+        Self::try_new(x as u8, y as u8)
+    }
+}
+
 impl<const L: u8> Vertex<L, L> {
     pub const fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
         match quarter_turns {
@@ -214,6 +335,18 @@ impl<const L: u8> Vertex<L, L> {
     }
 }
 
+impl<const WIDTH: u8, const HEIGHT: u8> Flip for Vertex<WIDTH, HEIGHT> {
+    fn flip(&self, axes: FlipAxes) -> Self {
+        Vertex::flip(self, axes)
+    }
+}
+
+impl<const L: u8> Rotate for Vertex<L, L> {
+    fn rotate(&self, quarter_turns: QuarterTurns) -> Self {
+        Vertex::rotate(self, quarter_turns)
+    }
+}
+
 impl<const W: u8, const H: u8> From<Vertex<W, H>> for u8 {
     fn from(val: Vertex<W, H>) -> Self {
         val.0
@@ -238,6 +371,19 @@ impl<const W: u8, const H: u8> From<&Vertex<W, H>> for usize {
     }
 }
 
+#[cfg(any(test, feature = "serde"))]
+impl<'de, const WIDTH: u8, const HEIGHT: u8> Deserialize<'de> for Vertex<WIDTH, HEIGHT> {
+    /// Deserializes the index in the same format as it is serialized, failing if it is outside the grid
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner: u8 = crate::serde_newtype::deserialize_newtype(deserializer, "Vertex")?;
+        Self::try_from_inner(inner).ok_or_else(|| {
+            serde::de::Error::custom(format_args!(
+                "vertex index {inner} is outside a {WIDTH}x{HEIGHT} grid"
+            ))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,14 +398,28 @@ mod tests {
         assert_eq!(
             str,
             "(0,0)|(1,0)|(2,0)|(0,1)|(1,1)|(2,1)|(0,2)|(1,2)|(2,2)|(0,3)|(1,3)|(2,3)",
-        )
+        );
+    }
+
+    #[test]
+    fn test_all() {
+        const VERTICES: [Vertex<3, 2>; 12] = Vertex::all();
+
+        assert!(VERTICES.into_iter().eq(Vertex::<3, 2>::iter_by_row()));
+        assert_eq!(VERTICES[5], Vertex::new_const::<1, 1>());
+    }
+
+    #[test]
+    #[should_panic(expected = "SIZE does not match the grid dimensions")]
+    fn test_all_wrong_size() {
+        let _ = Vertex::<3, 2>::all::<11>();
     }
 
     #[test]
     fn test_from() {
         for tile in Vertex::<3, 4>::iter_by_row() {
             let n = Vertex::try_new(tile.x(), tile.y()).unwrap();
-            assert_eq!(tile, n)
+            assert_eq!(tile, n);
         }
     }
 
@@ -269,7 +429,19 @@ mod tests {
             .map(|x| x.flip(FlipAxes::Vertical))
             .join("|");
 
-        assert_eq!(str, "(0,2)|(1,2)|(2,2)|(0,1)|(1,1)|(2,1)|(0,0)|(1,0)|(2,0)")
+        assert_eq!(str, "(0,2)|(1,2)|(2,2)|(0,1)|(1,1)|(2,1)|(0,0)|(1,0)|(2,0)");
+    }
+
+    #[test]
+    fn test_from_str() {
+        for vertex in Vertex::<3, 4>::iter_by_row() {
+            assert_eq!(vertex.to_string().parse(), Ok(vertex));
+        }
+        assert_eq!("3,4".parse(), Ok(Vertex::<3, 4>::new_const::<3, 4>()));
+        assert_eq!(
+            "(4,4)".parse::<Vertex<3, 4>>(),
+            Err(ParseCoordinatesError::OutOfBounds)
+        );
     }
 
     #[cfg(any(test, feature = "serde"))]
@@ -281,19 +453,25 @@ mod tests {
             &tile,
             &[Token::NewtypeStruct { name: "Vertex" }, Token::U8(2)],
         );
+
+        // A 3x3 grid has 16 vertices
+        serde_test::assert_de_tokens_error::<Vertex<3, 3>>(
+            &[Token::NewtypeStruct { name: "Vertex" }, Token::U8(16)],
+            "vertex index 16 is outside a 3x3 grid",
+        );
     }
 
     #[test]
     fn test_add() {
         let vertex: Vertex<3, 3> = Vertex::new_const::<1, 1>();
-        assert_eq!(vertex + Vector::NORTH, Vertex::try_new(1, 0))
+        assert_eq!(vertex + Vector::NORTH, Vertex::try_new(1, 0));
     }
 
     #[test]
     fn test_add_gives_none() {
         let vertex: Vertex<4, 4> = Vertex::new_const::<4, 0>();
         let r = vertex + Vector::new(1, 0);
-        assert_eq!(r, None)
+        assert_eq!(r, None);
     }
 
     #[test]
@@ -317,6 +495,41 @@ mod tests {
         assert_eq!(tile.get_center(2.0), glam::f32::Vec2::new(2.0, 4.0));
     }
 
+    #[test]
+    fn test_try_from_center() {
+        type V = Vertex<2, 1>;
+
+        for vertex in V::iter_by_row() {
+            assert_eq!(
+                V::try_from_center(vertex.get_center(2.0), 2.0),
+                Some(vertex)
+            );
+        }
+
+        assert_eq!(
+            V::try_from_center(glam::f32::Vec2::new(4.9, -0.9), 2.0),
+            Some(Vertex::new_const::<2, 0>())
+        );
+        assert_eq!(
+            V::try_from_center(glam::f32::Vec2::new(5.0, 0.0), 2.0),
+            None
+        );
+        assert_eq!(
+            V::try_from_center(glam::f32::Vec2::new(0.0, -1.1), 2.0),
+            None
+        );
+    }
+
+    #[test]
+    fn test_try_with_x_and_y() {
+        let vertex: Vertex<3, 4> = Vertex::new_const::<1, 2>();
+
+        assert_eq!(vertex.try_with_x(3), Some(Vertex::new_const::<3, 2>()));
+        assert_eq!(vertex.try_with_x(4), None);
+        assert_eq!(vertex.try_with_y(4), Some(Vertex::new_const::<1, 4>()));
+        assert_eq!(vertex.try_with_y(5), None);
+    }
+
     #[test]
     fn test_try_next() {
         let mut tile = Vertex::<2, 2>(0);
@@ -333,6 +546,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_try_prev() {
+        let mut vertex = Vertex::<2, 2>::SOUTH_EAST;
+        let mut i = 8;
+        while let Some(prev) = vertex.try_prev() {
+            i -= 1;
+            vertex = prev;
+            assert_eq!(vertex.inner(), i);
+        }
+        assert_eq!(i, 0);
+    }
+
+    #[test]
+    fn test_iter_range() {
+        let from = Vertex::<2, 2>::new_const::<2, 0>();
+        let to = Vertex::<2, 2>::new_const::<1, 1>();
+        let range = Vertex::iter_range(from, to);
+        assert_eq!(range.len(), 3);
+        assert_eq!(range.rev().join("|"), "(1,1)|(0,1)|(2,0)");
+        assert_eq!(Vertex::iter_range(to, from).count(), 0);
+    }
+
     #[test]
     fn test_flip2() {
         let vertex: Vertex<3, 3> = Vertex::new_const::<1, 2>();
@@ -397,6 +632,27 @@ mod tests {
         assert_eq!(vertex.get_tile(&SouthEast), Some(Tile::new_const::<1, 1>()));
     }
 
+    #[test]
+    fn test_adjacency() {
+        let corner: Vertex<2, 2> = Vertex::new_const::<0, 0>();
+        assert_eq!(corner.iter_adjacent_vertices().join("|"), "(1,0)|(0,1)");
+        assert_eq!(corner.adjacent_tiles().join("|"), "(0,0)");
+        assert_eq!(
+            corner.edges().map(|(a, b)| format!("{a}-{b}")).join("|"),
+            "(0,0)-(1,0)|(0,0)-(0,1)"
+        );
+
+        let middle: Vertex<2, 2> = Vertex::new_const::<1, 1>();
+        assert_eq!(middle.iter_adjacent_vertices().count(), 4);
+        assert_eq!(middle.adjacent_tiles().join("|"), "(0,0)|(1,0)|(0,1)|(1,1)");
+        assert!(middle
+            .edges()
+            .all(|(a, b)| a < b && (a == middle || b == middle)));
+
+        let far: Vertex<2, 2> = Vertex::new_const::<2, 2>();
+        assert_eq!(far.adjacent_tiles().join("|"), "(1,1)");
+    }
+
     #[test]
     fn test_from_dynamic() {
         let pairs = [
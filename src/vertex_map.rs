@@ -0,0 +1,250 @@
+use core::{
+    fmt::{self, Write},
+    ops::{Index, IndexMut},
+};
+
+use crate::prelude::*;
+
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+/// A map from vertices to values.
+/// `SIZE` must be `(WIDTH + 1) * (HEIGHT + 1)`
+/// If the values are just booleans, use `VertexSet` instead
+#[must_use]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize, Deserialize))]
+pub struct VertexMap<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>(
+    #[cfg_attr(any(test, feature = "serde"), serde(with = "serde_arrays"))]
+    #[cfg_attr(any(test, feature = "serde"), serde(bound(serialize = "T: Serialize")))]
+    #[cfg_attr(
+        any(test, feature = "serde"),
+        serde(bound(deserialize = "T: Deserialize<'de>"))
+    )]
+    [T; SIZE],
+);
+
+impl<T: Default + Copy, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Default
+    for VertexMap<T, WIDTH, HEIGHT, SIZE>
+{
+    fn default() -> Self {
+        Self::assert_legal();
+        Self([T::default(); SIZE])
+    }
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> VertexMap<T, WIDTH, HEIGHT, SIZE> {
+    #[allow(clippy::missing_panics_doc)]
+    pub fn from_fn<F: FnMut(Vertex<WIDTH, HEIGHT>) -> T>(mut cb: F) -> Self {
+        Self::assert_legal();
+        let arr = core::array::from_fn(|i| cb(Vertex::try_from_usize(i).unwrap()));
+        Self(arr)
+    }
+
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> [T; SIZE] {
+        let Self(inner) = self;
+        inner
+    }
+
+    #[inline]
+    pub const fn from_inner(inner: [T; SIZE]) -> Self {
+        Self::assert_legal();
+        Self(inner)
+    }
+
+    /// Create a map from its values, checking that `SIZE` matches the dimensions
+    /// # Errors
+    /// If `SIZE` is not the number of vertices in the grid
+    pub fn try_from_inner(inner: [T; SIZE]) -> Result<Self, DimensionError> {
+        DimensionError::check(Vertex::<WIDTH, HEIGHT>::COUNT, SIZE, usize::MAX)?;
+        Ok(Self(inner))
+    }
+
+    /// Create a map by calling `cb` for each vertex, checking that `SIZE` matches the dimensions
+    /// # Errors
+    /// If `SIZE` is not the number of vertices in the grid
+    pub fn try_from_fn<F: FnMut(Vertex<WIDTH, HEIGHT>) -> T>(
+        cb: F,
+    ) -> Result<Self, DimensionError> {
+        DimensionError::check(Vertex::<WIDTH, HEIGHT>::COUNT, SIZE, usize::MAX)?;
+        Ok(Self::from_fn(cb))
+    }
+
+    /// Panics if `SIZE` is not the number of vertices in the grid
+    #[inline]
+    const fn assert_legal() {
+        DimensionError::assert(Vertex::<WIDTH, HEIGHT>::COUNT, SIZE, usize::MAX);
+    }
+
+    #[inline]
+    #[allow(clippy::missing_panics_doc)]
+    pub fn enumerate(&self) -> impl Iterator<Item = (Vertex<WIDTH, HEIGHT>, &'_ T)> {
+        self.0
+            .iter()
+            .enumerate()
+            .map(|(inner, x)| (Vertex::try_from_usize(inner).unwrap(), x))
+    }
+
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.0.iter()
+    }
+
+    #[inline]
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, T> {
+        self.0.iter_mut()
+    }
+
+    #[inline]
+    pub fn swap(&mut self, v1: Vertex<WIDTH, HEIGHT>, v2: Vertex<WIDTH, HEIGHT>) {
+        self.0.swap(v1.into(), v2.into());
+    }
+
+    /// The values at the four corners of a tile, in the order of `Corner::ALL`
+    pub fn tile_corners(&self, tile: Tile<WIDTH, HEIGHT>) -> [&T; 4] {
+        Corner::ALL.map(|corner| &self[tile.get_vertex_unchecked(corner)])
+    }
+}
+
+impl<T, const W: u8, const H: u8, const SIZE: usize> Index<Vertex<W, H>>
+    for VertexMap<T, W, H, SIZE>
+{
+    type Output = T;
+
+    fn index(&self, index: Vertex<W, H>) -> &Self::Output {
+        let u: usize = index.into();
+        &self.0[u]
+    }
+}
+
+impl<T, const W: u8, const H: u8, const SIZE: usize> IndexMut<Vertex<W, H>>
+    for VertexMap<T, W, H, SIZE>
+{
+    fn index_mut(&mut self, index: Vertex<W, H>) -> &mut Self::Output {
+        let u: usize = index.into();
+        &mut self.0[u]
+    }
+}
+
+impl<'a, T, const W: u8, const H: u8, const SIZE: usize> IntoIterator
+    for &'a VertexMap<T, W, H, SIZE>
+{
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'a, T, const W: u8, const H: u8, const SIZE: usize> IntoIterator
+    for &'a mut VertexMap<T, W, H, SIZE>
+{
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter_mut()
+    }
+}
+
+impl<T, const W: u8, const H: u8, const SIZE: usize> IntoIterator for VertexMap<T, W, H, SIZE> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, SIZE>;
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<T: fmt::Display, const W: u8, const H: u8, const SIZE: usize> fmt::Display
+    for VertexMap<T, W, H, SIZE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, e) in self.0.iter().enumerate() {
+            if i == 0 {
+            } else if !f.alternate() && i % (W as usize + 1) == 0 {
+                f.write_char('\n')?;
+            } else {
+                f.write_char('|')?;
+            }
+
+            e.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The `VertexMap` type for a grid of the given width and height, computing `SIZE`.
+/// `vertex_map!(u8, 4, 3)` is `VertexMap<u8, 4, 3, 20>`
+#[macro_export]
+macro_rules! vertex_map {
+    ($t:ty, $width:expr, $height:expr) => {
+        $crate::vertex_map::VertexMap<
+            $t,
+            { $width },
+            { $height },
+            { ($width as usize + 1) * ($height as usize + 1) },
+        >
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_test::{assert_tokens, Token};
+
+    #[test]
+    fn test_vertex_map_macro() {
+        let map: vertex_map!(u8, 4, 3) = VertexMap::default();
+        let _: VertexMap<u8, 4, 3, 20> = map;
+    }
+
+    #[test]
+    fn test_try_from_inner() {
+        assert!(VertexMap::<u8, 1, 1, 4>::try_from_inner([0; 4]).is_ok());
+        assert_eq!(
+            VertexMap::<u8, 1, 1, 1>::try_from_fn(|_| 0),
+            Err(DimensionError::SizeMismatch {
+                expected: 4,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let map: VertexMap<u8, 2, 1, 6> = VertexMap::from_fn(|v| v.x() + v.y());
+        assert_eq!(map.to_string(), "0|1|2\n1|2|3");
+        assert_eq!(format!("{map:#}"), "0|1|2|1|2|3");
+        assert_eq!(map[Vertex::SOUTH_EAST], 3);
+    }
+
+    #[test]
+    fn test_tile_corners() {
+        let mut map: VertexMap<u8, 2, 2, 9> = VertexMap::from_fn(|v| v.inner());
+        map[Vertex::CENTER] = 100;
+        assert_eq!(map.tile_corners(Tile::NORTH_EAST), [&1, &2, &100, &5]);
+        assert_eq!(map.tile_corners(Tile::SOUTH_WEST), [&3, &100, &6, &7]);
+    }
+
+    #[test]
+    fn test_serde() {
+        let map: VertexMap<u8, 1, 1, 4> = VertexMap::from_inner([1, 2, 3, 4]);
+        assert_tokens(
+            &map,
+            &[
+                Token::NewtypeStruct { name: "VertexMap" },
+                Token::Tuple { len: 4 },
+                Token::U8(1),
+                Token::U8(2),
+                Token::U8(3),
+                Token::U8(4),
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
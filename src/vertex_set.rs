@@ -0,0 +1,382 @@
+use core::{
+    fmt::{self, Write},
+    iter::FusedIterator,
+};
+
+use crate::prelude::*;
+
+#[cfg(any(test, feature = "serde"))]
+use serde::{Deserialize, Serialize};
+
+/// A set of vertices. Can store up to 128 vertices.
+/// `SIZE` must be `(WIDTH + 1) * (HEIGHT + 1)`
+#[must_use]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[cfg_attr(any(test, feature = "serde"), derive(Serialize))]
+pub struct VertexSet<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>(u128);
+
+impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Default
+    for VertexSet<WIDTH, HEIGHT, SIZE>
+{
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> VertexSet<WIDTH, HEIGHT, SIZE> {
+    /// The set where all vertices are missing
+    pub const EMPTY: Self = {
+        Self::assert_legal();
+        Self(0)
+    };
+
+    /// The set where all vertices are present
+    #[allow(clippy::cast_possible_truncation)]
+    pub const ALL: Self = Self(u128::MAX >> (u128::BITS - SIZE as u32));
+
+    #[inline]
+    const fn assert_legal() {
+        DimensionError::assert(Vertex::<WIDTH, HEIGHT>::COUNT, SIZE, u128::BITS as usize);
+    }
+
+    #[inline]
+    pub fn from_fn<F: FnMut(Vertex<WIDTH, HEIGHT>) -> bool>(mut cb: F) -> Self {
+        let mut result = Self::default();
+        for vertex in Vertex::<WIDTH, HEIGHT>::iter_by_row() {
+            if cb(vertex) {
+                result.set_bit(&vertex, true);
+            }
+        }
+        result
+    }
+
+    #[inline]
+    pub const fn from_inner(inner: u128) -> Self {
+        Self::assert_legal();
+        Self(inner)
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn into_inner(self) -> u128 {
+        self.0
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    #[inline]
+    pub const fn set_bit(&mut self, vertex: &Vertex<WIDTH, HEIGHT>, bit: bool) {
+        if bit {
+            self.0 |= 1 << vertex.inner();
+        } else {
+            self.0 &= !(1 << vertex.inner());
+        }
+    }
+
+    /// Insert a vertex, returning whether it was newly inserted
+    #[inline]
+    pub const fn insert(&mut self, vertex: &Vertex<WIDTH, HEIGHT>) -> bool {
+        let r = !self.get_bit(vertex);
+        self.set_bit(vertex, true);
+        r
+    }
+
+    /// Remove a vertex, returning whether it was present
+    #[inline]
+    pub const fn remove(&mut self, vertex: &Vertex<WIDTH, HEIGHT>) -> bool {
+        let r = self.get_bit(vertex);
+        self.set_bit(vertex, false);
+        r
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn get_bit(&self, vertex: &Vertex<WIDTH, HEIGHT>) -> bool {
+        (self.0 >> vertex.inner()) & 1 == 1
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn count(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// The set of all vertices in a particular row
+    pub const fn row_mask(y: u8) -> Self {
+        let row = u128::MAX >> (u128::BITS - (WIDTH as u32 + 1));
+        Self(row << (y as u32 * (WIDTH as u32 + 1)))
+    }
+
+    /// The set of all vertices in a particular column
+    pub const fn col_mask(x: u8) -> Self {
+        let mut result = Self::EMPTY;
+        let mut y = 0;
+        while y <= HEIGHT {
+            result.set_bit(&Vertex::new_unchecked(x, y), true);
+            y += 1;
+        }
+        result
+    }
+
+    /// The set of vertices on the edge of the grid
+    pub const fn border_mask() -> Self {
+        Self::row_mask(0)
+            .union(&Self::row_mask(HEIGHT))
+            .union(&Self::col_mask(0))
+            .union(&Self::col_mask(WIDTH))
+    }
+
+    /// The vertices at the corners of all the given tiles
+    pub fn from_tiles(tiles: impl IntoIterator<Item = Tile<WIDTH, HEIGHT>>) -> Self {
+        let mut result = Self::EMPTY;
+        for tile in tiles {
+            for corner in Corner::ALL {
+                result.set_bit(&tile.get_vertex_unchecked(corner), true);
+            }
+        }
+        result
+    }
+
+    /// The tiles whose four corners are all in this set
+    #[must_use]
+    pub fn enclosed_tiles<S: FromIterator<Tile<WIDTH, HEIGHT>>>(&self) -> S {
+        Tile::<WIDTH, HEIGHT>::iter_by_row()
+            .filter(|tile| {
+                Corner::ALL
+                    .iter()
+                    .all(|corner| self.get_bit(&tile.get_vertex_unchecked(*corner)))
+            })
+            .collect()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn iter_true_vertices(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Vertex<WIDTH, HEIGHT>>
+           + DoubleEndedIterator
+           + FusedIterator
+           + Clone
+           + fmt::Debug {
+        TrueVerticesIter::<WIDTH, HEIGHT> { inner: self.0 }
+    }
+
+    #[inline]
+    pub const fn intersect(&self, rhs: &Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+
+    #[inline]
+    pub const fn union(&self, rhs: &Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+
+    #[inline]
+    pub const fn except(&self, rhs: &Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+
+    #[inline]
+    pub const fn symmetric_difference(&self, rhs: &Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+
+    #[inline]
+    pub const fn negate(&self) -> Self {
+        Self(!self.0 & Self::ALL.0)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_subset(&self, rhs: &Self) -> bool {
+        self.intersect(rhs).0 == self.0
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_superset(&self, rhs: &Self) -> bool {
+        rhs.is_subset(self)
+    }
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> FromIterator<Vertex<WIDTH, HEIGHT>>
+    for VertexSet<WIDTH, HEIGHT, SIZE>
+{
+    fn from_iter<T: IntoIterator<Item = Vertex<WIDTH, HEIGHT>>>(iter: T) -> Self {
+        let mut result = Self::default();
+        for vertex in iter {
+            result.set_bit(&vertex, true);
+        }
+        result
+    }
+}
+
+#[derive(Clone, Debug)]
+struct TrueVerticesIter<const WIDTH: u8, const HEIGHT: u8> {
+    inner: u128,
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8> Iterator for TrueVerticesIter<WIDTH, HEIGHT> {
+    type Item = Vertex<WIDTH, HEIGHT>;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.inner == 0 {
+            return None;
+        }
+        let index = self.inner.trailing_zeros();
+        self.inner &= self.inner - 1;
+        Some(Vertex::from_inner_unchecked(index as u8))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let size = self.inner.count_ones() as usize;
+        (size, Some(size))
+    }
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8> DoubleEndedIterator for TrueVerticesIter<WIDTH, HEIGHT> {
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.inner == 0 {
+            return None;
+        }
+        let index = u128::BITS - 1 - self.inner.leading_zeros();
+        self.inner &= !(1 << index);
+        Some(Vertex::from_inner_unchecked(index as u8))
+    }
+}
+
+impl<const WIDTH: u8, const HEIGHT: u8> ExactSizeIterator for TrueVerticesIter<WIDTH, HEIGHT> {}
+
+impl<const WIDTH: u8, const HEIGHT: u8> FusedIterator for TrueVerticesIter<WIDTH, HEIGHT> {}
+
+impl<const W: u8, const H: u8, const SIZE: usize> fmt::Display for VertexSet<W, H, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for vertex in Vertex::<W, H>::iter_by_row() {
+            if vertex.x() == 0 && vertex.y() > 0 && !f.alternate() {
+                f.write_char('\n')?;
+            }
+            if self.get_bit(&vertex) {
+                f.write_char('*')?;
+            } else {
+                f.write_char('_')?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(any(test, feature = "serde"))]
+impl<'de, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> Deserialize<'de>
+    for VertexSet<WIDTH, HEIGHT, SIZE>
+{
+    /// Deserializes the inner value in the same format as it is serialized, failing if any bits beyond `SIZE` are set
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let inner: u128 = crate::serde_newtype::deserialize_newtype(deserializer, "VertexSet")?;
+        if inner & !Self::ALL.0 != 0 {
+            return Err(serde::de::Error::custom(format_args!(
+                "vertex set value {inner} has vertices outside a {WIDTH}x{HEIGHT} grid"
+            )));
+        }
+        Ok(Self(inner))
+    }
+}
+
+/// The `VertexSet` type for a grid of the given width and height, computing `SIZE`.
+/// `vertex_set!(4, 3)` is `VertexSet<4, 3, 20>`
+#[macro_export]
+macro_rules! vertex_set {
+    ($width:expr, $height:expr) => {
+        $crate::vertex_set::VertexSet<{ $width }, { $height }, { ($width as usize + 1) * ($height as usize + 1) }>
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_vertex_set_macro() {
+        let set: vertex_set!(4, 3) = VertexSet::ALL;
+        let _: VertexSet<4, 3, 20> = set;
+        assert_eq!(set.count(), 20);
+    }
+
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens, assert_de_tokens_error, Token};
+
+        // `serde_test` has no `u128` token, so these are deserialized from smaller integers
+        let set = VertexSet::<1, 1, 4>::from_iter([Vertex::NORTH_WEST, Vertex::SOUTH_EAST]);
+        assert_de_tokens(
+            &set,
+            &[Token::NewtypeStruct { name: "VertexSet" }, Token::U8(9)],
+        );
+        assert_de_tokens_error::<VertexSet<1, 1, 4>>(
+            &[Token::NewtypeStruct { name: "VertexSet" }, Token::U8(16)],
+            "vertex set value 16 has vertices outside a 1x1 grid",
+        );
+    }
+
+    #[test]
+    fn test_basics() {
+        let mut set = VertexSet::<2, 2, 9>::EMPTY;
+        assert!(set.insert(&Vertex::CENTER));
+        assert!(!set.insert(&Vertex::CENTER));
+        assert!(set.insert(&Vertex::NORTH_WEST));
+        assert_eq!(set.count(), 2);
+        assert_eq!(set.to_string(), "*__\n_*_\n___");
+        assert!(set.remove(&Vertex::NORTH_WEST));
+        assert!(!set.remove(&Vertex::NORTH_WEST));
+        assert_eq!(set.iter_true_vertices().join("|"), "(1,1)");
+
+        assert_eq!(VertexSet::<2, 2, 9>::ALL.count(), 9);
+        assert_eq!(VertexSet::<2, 2, 9>::ALL.negate(), VertexSet::EMPTY);
+    }
+
+    #[test]
+    fn test_masks() {
+        assert_eq!(
+            VertexSet::<3, 2, 12>::row_mask(1).to_string(),
+            "____\n****\n____"
+        );
+        assert_eq!(
+            VertexSet::<3, 2, 12>::col_mask(2).to_string(),
+            "__*_\n__*_\n__*_"
+        );
+        assert_eq!(
+            VertexSet::<3, 2, 12>::border_mask().to_string(),
+            "****\n*__*\n****"
+        );
+    }
+
+    #[test]
+    fn test_from_tiles() {
+        let tiles = TileSet16::<3, 3, 9>::from_fn(|tile| tile.y() == 0 || tile == Tile::CENTER);
+        let vertices = VertexSet::<3, 3, 16>::from_tiles(tiles);
+
+        assert_eq!(vertices.to_string(), "****\n****\n_**_\n____");
+
+        let enclosed: TileSet16<3, 3, 9> = vertices.enclosed_tiles();
+        assert_eq!(enclosed, tiles);
+    }
+
+    #[test]
+    fn test_iter_true_vertices() {
+        let set = VertexSet::<3, 3, 16>::from_fn(|v| v.x() == v.y());
+        let iter = set.iter_true_vertices();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.rev().join("|"), "(3,3)|(2,2)|(1,1)|(0,0)");
+        assert_eq!(
+            set.iter_true_vertices().collect::<VertexSet<3, 3, 16>>(),
+            set
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use crate::prelude::*;
+
+/// Assign each tile to its nearest source, giving the index of the source in `sources`.
+/// Ties are broken in favour of the source which comes first.
+/// If there are no sources, every tile is assigned 0.
+/// # Panics
+/// If there are more than 256 sources
+pub fn voronoi_partition<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>(
+    sources: &[Tile<WIDTH, HEIGHT>],
+    metric: DistanceMetric,
+) -> TileMap<u8, WIDTH, HEIGHT, SIZE> {
+    assert!(sources.len() <= 256, "There can be at most 256 sources");
+    TileMap::from_fn(|tile| {
+        let mut best: Option<(u8, u8)> = None;
+        for (index, source) in (0..=u8::MAX).zip(sources) {
+            let distance = metric.distance(&tile, source);
+            if best.is_none_or(|(_, d)| distance < d) {
+                best = Some((index, distance));
+            }
+        }
+        best.map_or(0, |(index, _)| index)
+    })
+}
+
+/// Assign each tile to the source with the lowest distance plus weight, giving the index of the source in `sources`.
+/// Sources with greater weights have smaller regions.
+/// Ties are broken in favour of the source which comes first.
+/// If there are no sources, every tile is assigned 0.
+/// # Panics
+/// If there are more than 256 sources
+pub fn weighted_voronoi_partition<const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>(
+    sources: &[(Tile<WIDTH, HEIGHT>, u32)],
+    metric: DistanceMetric,
+) -> TileMap<u8, WIDTH, HEIGHT, SIZE> {
+    assert!(sources.len() <= 256, "There can be at most 256 sources");
+    TileMap::from_fn(|tile| {
+        let mut best: Option<(u8, u32)> = None;
+        for (index, (source, weight)) in (0..=u8::MAX).zip(sources) {
+            let cost = u32::from(metric.distance(&tile, source)).saturating_add(*weight);
+            if best.is_none_or(|(_, c)| cost < c) {
+                best = Some((index, cost));
+            }
+        }
+        best.map_or(0, |(index, _)| index)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_voronoi_partition() {
+        let sources = [Tile::new_const::<0, 0>(), Tile::new_const::<4, 2>()];
+
+        let manhattan: TileMap<u8, 5, 3, 15> =
+            voronoi_partition(&sources, DistanceMetric::Manhattan);
+        assert_eq!(manhattan.to_string(), "0|0|0|0|1\n0|0|0|1|1\n0|0|1|1|1");
+
+        let chebyshev: TileMap<u8, 5, 3, 15> =
+            voronoi_partition(&sources, DistanceMetric::Chebyshev);
+        assert_eq!(chebyshev.to_string(), "0|0|0|1|1\n0|0|0|1|1\n0|0|0|1|1");
+
+        let empty: TileMap<u8, 5, 3, 15> = voronoi_partition(&[], DistanceMetric::Manhattan);
+        assert!(empty.iter().all(|x| *x == 0));
+    }
+
+    #[test]
+    fn test_weighted_voronoi_partition() {
+        let sources = [
+            (Tile::new_const::<0, 0>(), 2),
+            (Tile::new_const::<4, 0>(), 0),
+        ];
+
+        let map: TileMap<u8, 5, 1, 5> =
+            weighted_voronoi_partition(&sources, DistanceMetric::Manhattan);
+        // At x = 1 both sources cost 3, so the first one wins
+        assert_eq!(map.to_string(), "0|0|1|1|1");
+    }
+}
@@ -0,0 +1,150 @@
+use core::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+use crate::prelude::*;
+
+/// A Zobrist hash of a board state.
+/// Can be updated incrementally using a `ZobristTable`.
+#[must_use]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ZobristHash(pub u64);
+
+/// Random keys for each tile, used to incrementally hash a `TileMap`.
+/// The key for a value at a tile is derived from the tile's key and the value's `Hash`.
+#[must_use]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZobristTable<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> {
+    keys: [u64; SIZE],
+    phantom: PhantomData<T>,
+}
+
+impl<T, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize> ZobristTable<T, WIDTH, HEIGHT, SIZE> {
+    /// Create a table of keys determined by the seed.
+    /// Tables created from the same seed will always be identical.
+    pub const fn new(seed: u64) -> Self {
+        let mut keys = [0; SIZE];
+        let mut state = seed;
+        let mut index = 0;
+        while index < SIZE {
+            state = state.wrapping_add(GOLDEN_GAMMA);
+            keys[index] = mix(state);
+            index += 1;
+        }
+
+        Self {
+            keys,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T: Hash, const WIDTH: u8, const HEIGHT: u8, const SIZE: usize>
+    ZobristTable<T, WIDTH, HEIGHT, SIZE>
+{
+    /// The key for a particular value at a particular tile
+    #[must_use]
+    pub fn key(&self, tile: Tile<WIDTH, HEIGHT>, value: &T) -> u64 {
+        let mut hasher = KeyHasher(self.keys[usize::from(tile)]);
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Hash every value in the map
+    pub fn hash(&self, map: &TileMap<T, WIDTH, HEIGHT, SIZE>) -> ZobristHash {
+        let mut hash = ZobristHash::default();
+        for (tile, value) in map.enumerate() {
+            self.toggle(&mut hash, tile, value);
+        }
+        hash
+    }
+
+    /// Add or remove a value at a tile from the hash.
+    /// Toggling the same value twice leaves the hash unchanged.
+    pub fn toggle(&self, hash: &mut ZobristHash, tile: Tile<WIDTH, HEIGHT>, value: &T) {
+        hash.0 ^= self.key(tile, value);
+    }
+
+    /// Update the hash to reflect the value at a tile changing from `old` to `new`
+    pub fn replace(&self, hash: &mut ZobristHash, tile: Tile<WIDTH, HEIGHT>, old: &T, new: &T) {
+        self.toggle(hash, tile, old);
+        self.toggle(hash, tile, new);
+    }
+}
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// The splitmix64 finalizer
+const fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// Mixes the written bytes into the tile key
+struct KeyHasher(u64);
+
+impl Hasher for KeyHasher {
+    fn finish(&self) -> u64 {
+        mix(self.0)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = mix(self.0 ^ u64::from(*byte)).wrapping_add(GOLDEN_GAMMA);
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 = mix(self.0 ^ i).wrapping_add(GOLDEN_GAMMA);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_is_deterministic() {
+        let map: TileMap<u8, 3, 3, 9> = TileMap::from_fn(|tile| tile.inner() % 3);
+
+        let table_1 = ZobristTable::new(123);
+        let table_2 = ZobristTable::new(123);
+        let table_3 = ZobristTable::new(456);
+
+        assert_eq!(table_1.hash(&map), table_2.hash(&map));
+        assert_ne!(table_1.hash(&map), table_3.hash(&map));
+    }
+
+    #[test]
+    fn test_incremental_hash() {
+        let table: ZobristTable<Option<bool>, 3, 3, 9> = ZobristTable::new(42);
+        let mut map: TileMap<Option<bool>, 3, 3, 9> = TileMap::default();
+        let mut hash = table.hash(&map);
+
+        let moves = [
+            (Tile::CENTER, Some(true)),
+            (Tile::NORTH_WEST, Some(false)),
+            (Tile::SOUTH_EAST, Some(true)),
+            (Tile::CENTER, None),
+        ];
+
+        for (tile, value) in moves {
+            table.replace(&mut hash, tile, &map[tile], &value);
+            map[tile] = value;
+            assert_eq!(hash, table.hash(&map));
+        }
+    }
+
+    #[test]
+    fn test_keys_differ() {
+        let table: ZobristTable<u8, 4, 4, 16> = ZobristTable::new(0);
+        let keys = Tile::<4, 4>::iter_by_row()
+            .flat_map(|tile| (0..4).map(move |value| (tile, value)))
+            .map(|(tile, value)| table.key(tile, &value))
+            .collect::<std::collections::BTreeSet<_>>();
+
+        assert_eq!(keys.len(), 64);
+    }
+}
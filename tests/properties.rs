@@ -0,0 +1,185 @@
+//! Property tests, checked exhaustively on small grids.
+//! Properties over random inputs are checked with `proptest` in the `arbitrary` module.
+
+use geometrid::prelude::*;
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+
+#[test]
+fn rotating_a_tile_four_times_is_identity() {
+    for tile in Tile::<7, 7>::iter_by_row() {
+        for quarter_turns in QuarterTurns::iter() {
+            let rotated = (0..4).fold(tile, |t, _| t.rotate(quarter_turns));
+            assert_eq!(rotated, tile);
+        }
+        for axes in FlipAxes::iter() {
+            assert_eq!(tile.flip(axes).flip(axes), tile);
+        }
+    }
+}
+
+#[test]
+fn rotating_a_vector_four_times_is_identity() {
+    for (x, y) in (-4i8..=4).cartesian_product(-4i8..=4) {
+        let vector = Vector::new(x, y);
+        for quarter_turns in QuarterTurns::iter() {
+            let rotated = (0..4).fold(vector, |v, _| v.rotate(quarter_turns));
+            assert_eq!(rotated, vector);
+        }
+    }
+}
+
+#[test]
+fn rotating_square_maps_of_every_size_is_consistent() {
+    fn check<const L: u8, const L16: u16, const SIZE: usize>() {
+        let map: TileMap<u8, L, L, SIZE> = TileMap::from_fn(|tile| tile.inner());
+        #[allow(clippy::cast_possible_truncation)]
+        let map16: TileMap16<u8, L16, L16, SIZE> = TileMap16::from_fn(|tile| tile.inner() as u8);
+
+        for quarter_turns in QuarterTurns::iter() {
+            let rotated = map.with_rotate(quarter_turns);
+            for tile in Tile::<L, L>::iter_by_row() {
+                assert_eq!(
+                    rotated[tile.rotate(quarter_turns)],
+                    map[tile],
+                    "{L} {quarter_turns}"
+                );
+            }
+            assert_eq!(
+                (0..4).fold(map, |m, _| m.with_rotate(quarter_turns)),
+                map,
+                "{L} {quarter_turns}"
+            );
+            assert_eq!(
+                map16.with_rotate(quarter_turns).into_inner(),
+                rotated.into_inner(),
+                "{L} {quarter_turns}"
+            );
+        }
+    }
+
+    check::<1, 1, 1>();
+    check::<2, 2, 4>();
+    check::<3, 3, 9>();
+    check::<4, 4, 16>();
+    check::<5, 5, 25>();
+    check::<6, 6, 36>();
+    check::<7, 7, 49>();
+    check::<8, 8, 64>();
+}
+
+#[test]
+fn polyomino_outlines_are_closed_loops() {
+    fn check<const P: usize>(shape: &Polyomino<P>) {
+        let outline = shape.draw_outline().collect_vec();
+        assert!(outline.len() >= 4, "{shape}");
+        for (a, b) in outline.iter().circular_tuple_windows() {
+            assert_ne!(a, b, "{shape}");
+            assert!(a.0.x == b.0.x || a.0.y == b.0.y, "{shape}: {a} -> {b}");
+        }
+    }
+
+    Polyomino::FREE_TETROMINOS.iter().for_each(check);
+    Polyomino::ALL_PENTOMINOS.iter().for_each(check);
+    Polyomino::FREE_HEXOMINOS.iter().for_each(check);
+}
+
+#[test]
+fn rotating_vertices_is_consistent_with_rotating_tiles() {
+    fn vertices<const W: u8, const H: u8>(tile: Tile<W, H>) -> Vec<Vertex<W, H>> {
+        Corner::iter()
+            .filter_map(|c| tile.get_vertex(&c))
+            .sorted()
+            .collect()
+    }
+
+    fn check_square<const L: u8>() {
+        for tile in Tile::<L, L>::iter_by_row() {
+            for (corner, quarter_turns) in Corner::iter().cartesian_product(QuarterTurns::iter()) {
+                assert_eq!(
+                    tile.get_vertex(&corner).map(|v| v.rotate(quarter_turns)),
+                    tile.rotate(quarter_turns)
+                        .get_vertex(&corner.rotate(quarter_turns)),
+                    "{L} {tile} {corner} {quarter_turns}"
+                );
+            }
+            for (corner, axes) in Corner::iter().cartesian_product(FlipAxes::iter()) {
+                assert_eq!(
+                    tile.get_vertex(&corner).map(|v| v.flip(axes)),
+                    tile.flip(axes).get_vertex(&corner.flip(axes)),
+                    "{L} {tile} {corner} {axes}"
+                );
+            }
+            for quarter_turns in QuarterTurns::iter() {
+                let rotated = vertices(tile)
+                    .into_iter()
+                    .map(|v| v.rotate(quarter_turns))
+                    .sorted()
+                    .collect_vec();
+                assert_eq!(
+                    rotated,
+                    vertices(tile.rotate(quarter_turns)),
+                    "{L} {tile} {quarter_turns}"
+                );
+            }
+            assert_eq!(tile.rotate_clockwise(), tile.rotate(QuarterTurns::One));
+            assert_eq!(
+                tile.rotate_anticlockwise(),
+                tile.rotate(QuarterTurns::Three)
+            );
+        }
+        for vertex in Vertex::<L, L>::iter_by_row() {
+            assert_eq!(vertex.rotate_clockwise(), vertex.rotate(QuarterTurns::One));
+            assert_eq!(
+                vertex.rotate_anticlockwise(),
+                vertex.rotate(QuarterTurns::Three)
+            );
+        }
+    }
+
+    fn check_rectangular<const W: u8, const H: u8>() {
+        for tile in Tile::<W, H>::iter_by_row() {
+            let clockwise = vertices(tile)
+                .into_iter()
+                .map(|v| v.rotate_clockwise())
+                .sorted()
+                .collect_vec();
+            assert_eq!(clockwise, vertices(tile.rotate_clockwise()), "{tile}");
+
+            let anticlockwise = vertices(tile)
+                .into_iter()
+                .map(|v| v.rotate_anticlockwise())
+                .sorted()
+                .collect_vec();
+            assert_eq!(
+                anticlockwise,
+                vertices(tile.rotate_anticlockwise()),
+                "{tile}"
+            );
+
+            assert_eq!(tile.rotate_clockwise().rotate_anticlockwise(), tile);
+            assert_eq!(
+                tile.rotate_clockwise().rotate_clockwise(),
+                tile.flip(FlipAxes::Both)
+            );
+        }
+        for vertex in Vertex::<W, H>::iter_by_row() {
+            assert_eq!(vertex.rotate_clockwise().rotate_anticlockwise(), vertex);
+            assert_eq!(
+                vertex.rotate_clockwise().rotate_clockwise(),
+                vertex.flip(FlipAxes::Both)
+            );
+        }
+    }
+
+    check_square::<1>();
+    check_square::<2>();
+    check_square::<3>();
+    check_square::<4>();
+    check_square::<5>();
+
+    check_rectangular::<1, 4>();
+    check_rectangular::<2, 3>();
+    check_rectangular::<5, 3>();
+    check_rectangular::<7, 2>();
+}